@@ -30,6 +30,7 @@ pub trait AdifItem: AdifData + Debug + Clone + PartialEq + Default {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Adif {
     pub header: Header,
     pub qso: Vec<QSO>,
@@ -209,4 +210,50 @@ mod tests {
         let actual = input.serialize();
         assert_eq!(actual, expected);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_adif_serde_round_trip() {
+        let input = Adif {
+            header: Header::try_from(vec![
+                HeaderField::new(
+                    HeaderFieldName::PROGRAMID,
+                    DataValue::String("testtest".to_string()),
+                ),
+                HeaderField::new(
+                    HeaderFieldName::USERDEF(1),
+                    DataValue::String("testing".to_string()),
+                ),
+                HeaderField::end(),
+            ])
+            .unwrap(),
+            qso: vec![QSO::try_from(vec![
+                QSOField::new(QSOFieldName::CALL, DataValue::String("IS0GVH".to_string())),
+                QSOField::new(QSOFieldName::RST_SENT, DataValue::String("599".to_string())),
+                QSOField::end(),
+            ])
+            .unwrap()],
+        };
+        let json = serde_json::to_string(&input).unwrap();
+        assert_eq!(
+            json,
+            r#"{"header":{"PROGRAMID":"testtest","USERDEF1":"testing"},"qso":[{"CALL":"IS0GVH","RST_SENT":"599"}]}"#
+        );
+        let actual: Adif = serde_json::from_str(&json).unwrap();
+        assert_eq!(input, actual);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_data_value_serde() {
+        use crate::enumerations::mode::Mode;
+        use crate::enumerations::Enumeration;
+
+        let input = DataValue::Enumeration(Enumeration::Mode(Mode::FT8));
+        let json = serde_json::to_string(&input).unwrap();
+        assert_eq!(json, r#"{"Enumeration":{"Mode":"FT8"}}"#);
+        assert_eq!(serde_json::from_str::<DataValue>(&json).unwrap(), input);
+        assert_eq!(serde_json::to_string(&Mode::FT8).unwrap(), r#""FT8""#);
+        assert!(serde_json::from_str::<Mode>(r#""NOTAMODE""#).is_err());
+    }
 }
@@ -0,0 +1,305 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use crate::enumerations::band::Band;
+use crate::enumerations::dxcc_enity_code::DxccEntityCode;
+use crate::enumerations::mode_category::{ModeCategory, ModeCategoryMapping};
+use crate::enumerations::qsl_rcvd::QslRcvd;
+use crate::enumerations::Enumeration;
+use crate::fields::data::DataValue;
+use crate::fields::qso::QSOFieldName;
+use crate::qso::QSO;
+use std::collections::HashMap;
+
+/// Bands of the DXCC Challenge award.
+pub const CHALLENGE_BANDS: [Band; 10] = [
+    Band::Band160m,
+    Band::Band80m,
+    Band::Band40m,
+    Band::Band30m,
+    Band::Band20m,
+    Band::Band17m,
+    Band::Band15m,
+    Band::Band12m,
+    Band::Band10m,
+    Band::Band6m,
+];
+
+/// Source of a QSL counted as a confirmation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QslSource {
+    Lotw,
+    Card,
+    Eqsl,
+}
+
+impl QslSource {
+    pub fn field(&self) -> QSOFieldName {
+        match self {
+            QslSource::Lotw => QSOFieldName::LOTW_QSL_RCVD,
+            QslSource::Card => QSOFieldName::QSL_RCVD,
+            QslSource::Eqsl => QSOFieldName::EQSL_QSL_RCVD,
+        }
+    }
+}
+
+/// Entity, entity-band or entity-mode credit.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DxccSlot {
+    Entity(DxccEntityCode),
+    Band(DxccEntityCode, Band),
+    Mode(DxccEntityCode, ModeCategory),
+}
+
+/// Counts DXCC credits of a log; by default LoTW and paper QSLs confirm a
+/// contact, as for the ARRL award.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DxccTracker {
+    pub sources: Vec<QslSource>,
+    pub mode_mapping: ModeCategoryMapping,
+}
+
+impl Default for DxccTracker {
+    fn default() -> Self {
+        Self {
+            sources: vec![QslSource::Lotw, QslSource::Card],
+            mode_mapping: ModeCategoryMapping::default(),
+        }
+    }
+}
+
+impl DxccTracker {
+    pub fn is_confirmed(&self, qso: &QSO) -> bool {
+        self.sources.iter().any(|source| {
+            matches!(
+                qso.get_value(&source.field()),
+                Some(DataValue::Enumeration(Enumeration::QslRcvd(
+                    QslRcvd::Yes | QslRcvd::Verified
+                )))
+            )
+        })
+    }
+
+    /// Standing from the QSOs with a current entity in `DXCC`, skipping
+    /// deleted entities and contacts outside the validity of the entity.
+    pub fn standing<'a, I>(&self, qsos: I) -> DxccStanding
+    where
+        I: IntoIterator<Item = &'a QSO>,
+    {
+        let mut standing = DxccStanding::default();
+        for qso in qsos {
+            let entity = match qso.get_value(&QSOFieldName::DXCC) {
+                Some(DataValue::Enumeration(Enumeration::DxccEntityCode(entity))) => entity,
+                _ => continue,
+            };
+            let info = entity.info();
+            let in_validity = match qso.get_value(&QSOFieldName::QSO_DATE) {
+                Some(DataValue::Date(date)) => info.is_valid_on(date),
+                _ => true,
+            };
+            if info.deleted || *entity == DxccEntityCode::None || !in_validity {
+                continue;
+            }
+
+            let confirmed = self.is_confirmed(qso);
+            standing.add(DxccSlot::Entity(entity.clone()), confirmed);
+            let qso = qso.with_bands_from_frequencies();
+            if let Some(DataValue::Enumeration(Enumeration::Band(band))) =
+                qso.get_value(&QSOFieldName::BAND)
+            {
+                standing.add(DxccSlot::Band(entity.clone(), band.clone()), confirmed);
+            }
+            if let Some(category) = qso.mode_category_with(&self.mode_mapping) {
+                standing.add(DxccSlot::Mode(entity.clone(), category), confirmed);
+            }
+        }
+        standing
+    }
+}
+
+/// Worked slots in the order first worked, with whether each is
+/// confirmed.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DxccStanding {
+    slots: Vec<(DxccSlot, bool)>,
+    index: HashMap<DxccSlot, usize>,
+}
+
+impl DxccStanding {
+    pub fn slots(&self) -> &[(DxccSlot, bool)] {
+        &self.slots
+    }
+
+    pub fn worked(&self) -> usize {
+        self.count(|slot| matches!(slot, DxccSlot::Entity(_)), false)
+    }
+
+    pub fn confirmed(&self) -> usize {
+        self.count(|slot| matches!(slot, DxccSlot::Entity(_)), true)
+    }
+
+    pub fn band_worked(&self, band: &Band) -> usize {
+        self.count(
+            |slot| matches!(slot, DxccSlot::Band(_, b) if b == band),
+            false,
+        )
+    }
+
+    pub fn band_confirmed(&self, band: &Band) -> usize {
+        self.count(
+            |slot| matches!(slot, DxccSlot::Band(_, b) if b == band),
+            true,
+        )
+    }
+
+    pub fn mode_worked(&self, category: ModeCategory) -> usize {
+        self.count(
+            |slot| matches!(slot, DxccSlot::Mode(_, c) if *c == category),
+            false,
+        )
+    }
+
+    pub fn mode_confirmed(&self, category: ModeCategory) -> usize {
+        self.count(
+            |slot| matches!(slot, DxccSlot::Mode(_, c) if *c == category),
+            true,
+        )
+    }
+
+    /// Entity-band slots on the `CHALLENGE_BANDS`.
+    pub fn challenge_worked(&self) -> usize {
+        self.count(is_challenge_slot, false)
+    }
+
+    pub fn challenge_confirmed(&self) -> usize {
+        self.count(is_challenge_slot, true)
+    }
+
+    /// Slots worked but not yet confirmed.
+    pub fn unconfirmed(&self) -> Vec<&DxccSlot> {
+        self.slots
+            .iter()
+            .filter(|(_, confirmed)| !confirmed)
+            .map(|(slot, _)| slot)
+            .collect()
+    }
+
+    fn add(&mut self, slot: DxccSlot, confirmed: bool) {
+        match self.index.get(&slot) {
+            Some(&index) => self.slots[index].1 |= confirmed,
+            None => {
+                self.index.insert(slot.clone(), self.slots.len());
+                self.slots.push((slot, confirmed));
+            }
+        }
+    }
+
+    fn count(&self, filter: impl Fn(&DxccSlot) -> bool, confirmed_only: bool) -> usize {
+        self.slots
+            .iter()
+            .filter(|(slot, confirmed)| filter(slot) && (*confirmed || !confirmed_only))
+            .count()
+    }
+}
+
+fn is_challenge_slot(slot: &DxccSlot) -> bool {
+    matches!(slot, DxccSlot::Band(_, band) if CHALLENGE_BANDS.contains(band))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log() -> Vec<QSO> {
+        vec![
+            QSO::from_fields(&["<DXCC:3>225", "<BAND:3>20m", "<MODE:2>CW"]),
+            QSO::from_fields(&[
+                "<DXCC:3>225",
+                "<BAND:3>20m",
+                "<MODE:3>SSB",
+                "<LOTW_QSL_RCVD:1>Y",
+            ]),
+            QSO::from_fields(&[
+                "<DXCC:3>248",
+                "<FREQ:5>7.074",
+                "<MODE:3>FT8",
+                "<QSL_RCVD:1>V",
+            ]),
+            QSO::from_fields(&[
+                "<DXCC:3>291",
+                "<BAND:2>2m",
+                "<MODE:2>FM",
+                "<EQSL_QSL_RCVD:1>Y",
+            ]),
+            QSO::from_fields(&["<DXCC:3>291", "<BAND:3>60m", "<MODE:2>CW", "<QSL_RCVD:1>R"]),
+            QSO::from_fields(&["<DXCC:2>81", "<BAND:3>20m", "<MODE:2>CW", "<QSL_RCVD:1>Y"]),
+            QSO::from_fields(&[
+                "<DXCC:3>521",
+                "<QSO_DATE:8>20100101",
+                "<BAND:3>20m",
+                "<QSL_RCVD:1>Y",
+            ]),
+            QSO::from_fields(&["<DXCC:1>0", "<BAND:3>20m", "<QSL_RCVD:1>Y"]),
+            QSO::from_fields(&["<BAND:3>20m", "<QSL_RCVD:1>Y"]),
+        ]
+    }
+
+    #[test]
+    fn test_standing() {
+        let standing = DxccTracker::default().standing(&log());
+        assert_eq!(standing.worked(), 3);
+        assert_eq!(standing.confirmed(), 2);
+        assert_eq!(standing.band_worked(&Band::Band20m), 1);
+        assert_eq!(standing.band_confirmed(&Band::Band20m), 1);
+        assert_eq!(standing.band_confirmed(&Band::Band40m), 1);
+        assert_eq!(standing.mode_worked(ModeCategory::Cw), 2);
+        assert_eq!(standing.mode_confirmed(ModeCategory::Cw), 0);
+        assert_eq!(standing.mode_confirmed(ModeCategory::Phone), 1);
+        assert_eq!(standing.mode_confirmed(ModeCategory::Digital), 1);
+        assert_eq!(standing.challenge_worked(), 2);
+        assert_eq!(standing.challenge_confirmed(), 2);
+    }
+
+    #[test]
+    fn test_unconfirmed() {
+        let standing = DxccTracker::default().standing(&log());
+        assert_eq!(
+            standing.unconfirmed(),
+            vec![
+                &DxccSlot::Mode(DxccEntityCode::Sardinia, ModeCategory::Cw),
+                &DxccSlot::Entity(DxccEntityCode::UnitedStatesOfAmerica),
+                &DxccSlot::Band(DxccEntityCode::UnitedStatesOfAmerica, Band::Band2m),
+                &DxccSlot::Mode(DxccEntityCode::UnitedStatesOfAmerica, ModeCategory::Phone),
+                &DxccSlot::Band(DxccEntityCode::UnitedStatesOfAmerica, Band::Band60m),
+                &DxccSlot::Mode(DxccEntityCode::UnitedStatesOfAmerica, ModeCategory::Cw),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sources() {
+        let tracker = DxccTracker {
+            sources: vec![QslSource::Eqsl],
+            ..DxccTracker::default()
+        };
+        let standing = tracker.standing(&log());
+        assert_eq!(standing.worked(), 3);
+        assert_eq!(standing.confirmed(), 1);
+        assert!(standing
+            .unconfirmed()
+            .contains(&&DxccSlot::Entity(DxccEntityCode::Sardinia)));
+    }
+}
@@ -20,6 +20,7 @@ use crate::error::AdifError;
 use radif_macros::{AdifData, AutoDisplay, AutoTestEnum};
 
 #[derive(Debug, Clone, PartialEq, Eq, AutoDisplay, AdifData, AutoTestEnum)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
 pub enum AntPath {
     #[adif("G")]
     Grayline,
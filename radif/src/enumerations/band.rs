@@ -18,8 +18,48 @@
 use crate::data::AdifData;
 use crate::error::AdifError;
 use radif_macros::{AdifData, AutoDisplay, AutoTestEnum};
+use std::ops::RangeInclusive;
 
-#[derive(Debug, Clone, PartialEq, Eq, AutoDisplay, AdifData, AutoTestEnum)]
+/// Lower and upper band edges in MHz, both inclusive, from the ADIF band
+/// enumeration.
+const EDGES: [(Band, f64, f64); 33] = [
+    (Band::Band2190m, 0.1357, 0.1378),
+    (Band::Band630m, 0.472, 0.479),
+    (Band::Band560m, 0.501, 0.504),
+    (Band::Band160m, 1.8, 2.0),
+    (Band::Band80m, 3.5, 4.0),
+    (Band::Band60m, 5.06, 5.45),
+    (Band::Band40m, 7.0, 7.3),
+    (Band::Band30m, 10.1, 10.15),
+    (Band::Band20m, 14.0, 14.35),
+    (Band::Band17m, 18.068, 18.168),
+    (Band::Band15m, 21.0, 21.45),
+    (Band::Band12m, 24.890, 24.99),
+    (Band::Band10m, 28.0, 29.7),
+    (Band::Band8m, 40.0, 45.0),
+    (Band::Band6m, 50.0, 54.0),
+    (Band::Band5m, 54.000001, 69.9),
+    (Band::Band4m, 70.0, 71.0),
+    (Band::Band2m, 144.0, 148.0),
+    (Band::Band1_25m, 222.0, 225.0),
+    (Band::Band70cm, 420.0, 450.0),
+    (Band::Band33cm, 902.0, 928.0),
+    (Band::Band23cm, 1240.0, 1300.0),
+    (Band::Band13cm, 2300.0, 2450.0),
+    (Band::Band9cm, 3300.0, 3500.0),
+    (Band::Band6cm, 5650.0, 5925.0),
+    (Band::Band3cm, 10000.0, 10500.0),
+    (Band::Band1_25cm, 24000.0, 24250.0),
+    (Band::Band6mm, 47000.0, 47200.0),
+    (Band::Band4mm, 75500.0, 81000.0),
+    (Band::Band2_5mm, 119980.0, 123000.0),
+    (Band::Band2mm, 134000.0, 149000.0),
+    (Band::Band1mm, 241000.0, 250000.0),
+    (Band::Bandsubmm, 300000.0, 7500000.0),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, AutoDisplay, AdifData, AutoTestEnum)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
 pub enum Band {
     #[adif("2190M")]
     Band2190m,
@@ -88,3 +128,59 @@ pub enum Band {
     #[adif("SUBMM")]
     Bandsubmm,
 }
+
+impl Band {
+    /// Band containing the frequency in MHz, if any.
+    pub fn from_frequency(mhz: f64) -> Option<Self> {
+        EDGES
+            .iter()
+            .find(|(_, lower, upper)| (*lower..=*upper).contains(&mhz))
+            .map(|(band, _, _)| band.clone())
+    }
+
+    /// Frequency range of the band in MHz.
+    pub fn range(&self) -> RangeInclusive<f64> {
+        EDGES
+            .iter()
+            .find(|(band, _, _)| band == self)
+            .map(|(_, lower, upper)| *lower..=*upper)
+            .expect("every band has edges")
+    }
+
+    pub fn contains(&self, mhz: f64) -> bool {
+        self.range().contains(&mhz)
+    }
+}
+
+#[cfg(test)]
+mod range_tests {
+    use super::*;
+
+    #[test]
+    fn test_from_frequency() {
+        assert_eq!(Band::from_frequency(14.074), Some(Band::Band20m));
+        assert_eq!(Band::from_frequency(0.1360), Some(Band::Band2190m));
+        assert_eq!(Band::from_frequency(54.0), Some(Band::Band6m));
+        assert_eq!(Band::from_frequency(54.5), Some(Band::Band5m));
+        assert_eq!(Band::from_frequency(10368.1), Some(Band::Band3cm));
+        assert_eq!(Band::from_frequency(15.0), None);
+        assert_eq!(Band::from_frequency(-1.0), None);
+    }
+
+    #[test]
+    fn test_range() {
+        assert_eq!(Band::Band40m.range(), 7.0..=7.3);
+        assert!(Band::Band2m.contains(144.0));
+        assert!(Band::Band2m.contains(148.0));
+        assert!(!Band::Band2m.contains(148.1));
+    }
+
+    #[test]
+    fn test_edges_cover_every_band() {
+        for (band, lower, upper) in EDGES.iter() {
+            assert!(lower < upper);
+            assert_eq!(Band::from_frequency(*lower).as_ref(), Some(band));
+            assert_eq!(Band::from_frequency(*upper).as_ref(), Some(band));
+        }
+    }
+}
@@ -19,13 +19,14 @@ use crate::data::AdifData;
 use crate::error::AdifError;
 use radif_macros::{AdifData, AutoDisplay, AutoTestEnum};
 
-#[derive(Debug, Clone, PartialEq, Eq, AutoDisplay, AdifData, AutoTestEnum)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, AutoDisplay, AdifData, AutoTestEnum)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
 pub enum DxccEntityCode {
     #[adif("0")]
     None, // None (the contacted station is known to not be within a DXCC entity)
     #[adif("1")]
     Canada, // CANADA
-    #[adif("2")]
+    #[adif("2", deleted)]
     AbuAilIslandDeleted, // ABU AIL IS.
     #[adif("3")]
     Afghanistan, // AFGHANISTAN
@@ -37,7 +38,7 @@ pub enum DxccEntityCode {
     Alaska, // ALASKA
     #[adif("7")]
     Albania, // ALBANIA
-    #[adif("8")]
+    #[adif("8", deleted)]
     AldabraDeleted, // ALDABRA
     #[adif("9")]
     AmericanSamoa, // AMERICAN SAMOA
@@ -59,7 +60,7 @@ pub enum DxccEntityCode {
     AvesIsland, // AVES I.
     #[adif("18")]
     Azerbaijan, // AZERBAIJAN
-    #[adif("19")]
+    #[adif("19", deleted)]
     BajoNuevoDeleted, // BAJO NUEVO
     #[adif("20")]
     BakerAndHowlandIsland, // BAKER & HOWLAND IS.
@@ -67,21 +68,21 @@ pub enum DxccEntityCode {
     BalearicIsland, // BALEARIC IS.
     #[adif("22")]
     Palau, // PALAU
-    #[adif("23")]
+    #[adif("23", deleted)]
     BlenheimReefDeleted, // BLENHEIM REEF
     #[adif("24")]
     Bouvet, // BOUVET
-    #[adif("25")]
+    #[adif("25", deleted)]
     BritishNorthBorneoDeleted, // BRITISH NORTH BORNEO
-    #[adif("26")]
+    #[adif("26", deleted)]
     BritishSomalilandDeleted, // BRITISH SOMALILAND
     #[adif("27")]
     Belarus, // BELARUS
-    #[adif("28")]
+    #[adif("28", deleted)]
     CanalZoneDeleted, // CANAL ZONE
     #[adif("29")]
     CanaryIsland, // CANARY IS.
-    #[adif("30")]
+    #[adif("30", deleted)]
     CelebeAndMoluccaIslandDeleted, // CELEBE & MOLUCCA IS.
     #[adif("31")]
     CKiribatiBritishPhoenixIsland, // C. KIRIBATI (BRITISH PHOENIX IS.)
@@ -99,17 +100,17 @@ pub enum DxccEntityCode {
     CocosIsland, // COCOS I.
     #[adif("38")]
     CocosKeelingIsland, // COCOS (KEELING) IS.
-    #[adif("39")]
+    #[adif("39", deleted)]
     ComorosDeleted, // COMOROS
     #[adif("40")]
     Crete, // CRETE
     #[adif("41")]
     CrozetIsland, // CROZET I.
-    #[adif("42")]
+    #[adif("42", deleted)]
     DamaoDiuDeleted, // DAMAO, DIU
     #[adif("43")]
     DesecheoIsland, // DESECHEO I.
-    #[adif("44")]
+    #[adif("44", deleted)]
     DesrochesDeleted, // DESROCHES
     #[adif("45")]
     Dodecanese, // DODECANESE
@@ -131,15 +132,15 @@ pub enum DxccEntityCode {
     Ethiopia, // ETHIOPIA
     #[adif("54")]
     EuropeanRussia, // EUROPEAN RUSSIA
-    #[adif("55")]
+    #[adif("55", deleted)]
     FarquharDeleted, // FARQUHAR
     #[adif("56")]
     FernandoDeNoronha, // FERNANDO DE NORONHA
-    #[adif("57")]
+    #[adif("57", deleted)]
     FrenchEquatorialAfricaDeleted, // FRENCH EQUATORIAL AFRICA
-    #[adif("58")]
+    #[adif("58", deleted)]
     FrenchIndoChinaDeleted, // FRENCH INDO-CHINA
-    #[adif("59")]
+    #[adif("59", deleted)]
     FrenchWestAfricaDeleted, // FRENCH WEST AFRICA
     #[adif("60")]
     Bahamas, // BAHAMAS
@@ -155,9 +156,9 @@ pub enum DxccEntityCode {
     BritishVirginIsland, // BRITISH VIRGIN IS.
     #[adif("66")]
     Belize, // BELIZE
-    #[adif("67")]
+    #[adif("67", deleted)]
     FrenchIndiaDeleted, // FRENCH INDIA
-    #[adif("68")]
+    #[adif("68", deleted)]
     KuwaitSaudiArabiaNeutralZoneDeleted, // KUWAIT/SAUDI ARABIA NEUTRAL ZONE
     #[adif("69")]
     CaymanIsland, // CAYMAN IS.
@@ -181,13 +182,13 @@ pub enum DxccEntityCode {
     Guadeloupe, // GUADELOUPE
     #[adif("80")]
     Honduras, // HONDURAS
-    #[adif("81")]
+    #[adif("81", deleted)]
     GermanyDeleted, // GERMANY
     #[adif("82")]
     Jamaica, // JAMAICA
     #[adif("84")]
     Martinique, // MARTINIQUE
-    #[adif("85")]
+    #[adif("85", deleted)]
     BonaireCuracaoDeleted, // BONAIRE, CURACAO
     #[adif("86")]
     Nicaragua, // NICARAGUA
@@ -199,7 +200,7 @@ pub enum DxccEntityCode {
     TrinidadAndTobago, // TRINIDAD & TOBAGO
     #[adif("91")]
     Aruba, // ARUBA
-    #[adif("93")]
+    #[adif("93", deleted)]
     GeyserReefDeleted, // GEYSER REEF
     #[adif("94")]
     AntiguaAndBarbuda, // ANTIGUA & BARBUDA
@@ -215,9 +216,9 @@ pub enum DxccEntityCode {
     GloriosoIsland, // GLORIOSO IS.
     #[adif("100")]
     Argentina, // ARGENTINA
-    #[adif("101")]
+    #[adif("101", deleted)]
     GoaDeleted, // GOA
-    #[adif("102")]
+    #[adif("102", deleted)]
     GoldCoastTogolandDeleted, // GOLD COAST, TOGOLAND
     #[adif("103")]
     Guam, // GUAM
@@ -239,11 +240,11 @@ pub enum DxccEntityCode {
     HeardIsland, // HEARD I.
     #[adif("112")]
     Chile, // CHILE
-    #[adif("113")]
+    #[adif("113", deleted)]
     IfniDeleted, // IFNI
     #[adif("114")]
     IsleOfMan, // ISLE OF MAN
-    #[adif("115")]
+    #[adif("115", deleted)]
     ItalianSomalilandDeleted, // ITALIAN SOMALILAND
     #[adif("116")]
     Colombia, // COLOMBIA
@@ -251,7 +252,7 @@ pub enum DxccEntityCode {
     ItuHq, // ITU HQ
     #[adif("118")]
     JanMayen, // JAN MAYEN
-    #[adif("119")]
+    #[adif("119", deleted)]
     JavaDeleted, // JAVA
     #[adif("120")]
     Ecuador, // ECUADOR
@@ -265,9 +266,9 @@ pub enum DxccEntityCode {
     JuanFernandezIsland, // JUAN FERNANDEZ IS.
     #[adif("126")]
     Kaliningrad, // KALININGRAD
-    #[adif("127")]
+    #[adif("127", deleted)]
     KamaranIslandDeleted, // KAMARAN IS.
-    #[adif("128")]
+    #[adif("128", deleted)]
     KareloFinnishRepublicDeleted, // KARELO-FINNISH REPUBLIC
     #[adif("129")]
     Guyana, // GUYANA
@@ -279,7 +280,7 @@ pub enum DxccEntityCode {
     Paraguay, // PARAGUAY
     #[adif("133")]
     KermadecIsland, // KERMADEC IS.
-    #[adif("134")]
+    #[adif("134", deleted)]
     KingmanReefDeleted, // KINGMAN REEF
     #[adif("135")]
     Kyrgyzstan, // KYRGYZSTAN
@@ -289,7 +290,7 @@ pub enum DxccEntityCode {
     RepublicOfKorea, // REPUBLIC OF KOREA
     #[adif("138")]
     KureIsland, // KURE I.
-    #[adif("139")]
+    #[adif("139", deleted)]
     KuriaMuriaIslandDeleted, // KURIA MURIA I.
     #[adif("140")]
     Suriname, // SURINAME
@@ -313,15 +314,15 @@ pub enum DxccEntityCode {
     Azores, // AZORES
     #[adif("150")]
     Australia, // AUSTRALIA
-    #[adif("151")]
+    #[adif("151", deleted)]
     MalyjVysotskijIslandDeleted, // MALYJ VYSOTSKIJ I.
     #[adif("152")]
     Macao, // MACAO
     #[adif("153")]
     MacquarieIsland, // MACQUARIE I.
-    #[adif("154")]
+    #[adif("154", deleted)]
     YemenArabRepublicDeleted, // YEMEN ARAB REPUBLIC
-    #[adif("155")]
+    #[adif("155", deleted)]
     MalayaDeleted, // MALAYA
     #[adif("157")]
     Nauru, // NAURU
@@ -337,7 +338,7 @@ pub enum DxccEntityCode {
     NewCaledonia, // NEW CALEDONIA
     #[adif("163")]
     PapuaNewGuinea, // PAPUA NEW GUINEA
-    #[adif("164")]
+    #[adif("164", deleted)]
     ManchuriaDeleted, // MANCHURIA
     #[adif("165")]
     Mauritius, // MAURITIUS
@@ -365,7 +366,7 @@ pub enum DxccEntityCode {
     Fiji, // FIJI
     #[adif("177")]
     MinamiTorishima, // MINAMI TORISHIMA
-    #[adif("178")]
+    #[adif("178", deleted)]
     MinervaReefDeleted, // MINERVA REEF
     #[adif("179")]
     Moldova, // MOLDOVA
@@ -375,13 +376,13 @@ pub enum DxccEntityCode {
     Mozambique, // MOZAMBIQUE
     #[adif("182")]
     NavassaIsland, // NAVASSA I.
-    #[adif("183")]
+    #[adif("183", deleted)]
     NetherlandsBorneoDeleted, // NETHERLANDS BORNEO
-    #[adif("184")]
+    #[adif("184", deleted)]
     NetherlandsNewGuineaDeleted, // NETHERLANDS NEW GUINEA
     #[adif("185")]
     SolomonIsland, // SOLOMON IS.
-    #[adif("186")]
+    #[adif("186", deleted)]
     NewfoundlandLabradorDeleted, // NEWFOUNDLAND, LABRADOR
     #[adif("187")]
     Niger, // NIGER
@@ -395,21 +396,21 @@ pub enum DxccEntityCode {
     NorthCookIsland, // NORTH COOK IS.
     #[adif("192")]
     Ogasawara, // OGASAWARA
-    #[adif("193")]
+    #[adif("193", deleted)]
     OkinawaRyukyuIslandDeleted, // OKINAWA (RYUKYU IS.)
-    #[adif("194")]
+    #[adif("194", deleted)]
     OkinoToriShimaDeleted, // OKINO TORI-SHIMA
     #[adif("195")]
     AnnobonIsland, // ANNOBON I.
-    #[adif("196")]
+    #[adif("196", deleted)]
     PalestineDeleted, // PALESTINE
     #[adif("197")]
     PalmyraAndJarvisIsland, // PALMYRA & JARVIS IS.
-    #[adif("198")]
+    #[adif("198", deleted)]
     PapuaTerritoryDeleted, // PAPUA TERRITORY
     #[adif("199")]
     PeterIsland, // PETER 1 I.
-    #[adif("200")]
+    #[adif("200", deleted)]
     PortugueseTimorDeleted, // PORTUGUESE TIMOR
     #[adif("201")]
     PrinceEdwardAndMarionIsland, // PRINCE EDWARD & MARION IS.
@@ -425,11 +426,11 @@ pub enum DxccEntityCode {
     Austria, // AUSTRIA
     #[adif("207")]
     RodriguezIsland, // RODRIGUEZ I.
-    #[adif("208")]
+    #[adif("208", deleted)]
     RuandaUrundiDeleted, // RUANDA-URUNDI
     #[adif("209")]
     Belgium, // BELGIUM
-    #[adif("210")]
+    #[adif("210", deleted)]
     SaarDeleted, // SAAR
     #[adif("211")]
     SableIsland, // SABLE I.
@@ -445,11 +446,11 @@ pub enum DxccEntityCode {
     SanAndresAndProvidencia, // SAN ANDRES & PROVIDENCIA
     #[adif("217")]
     SanFelixAndSanAmbrosio, // SAN FELIX & SAN AMBROSIO
-    #[adif("218")]
+    #[adif("218", deleted)]
     CzechoslovakiaDeleted, // CZECHOSLOVAKIA
     #[adif("219")]
     SaoTomeAndPrincipe, // SAO TOME & PRINCIPE
-    #[adif("220")]
+    #[adif("220", deleted)]
     SarawakDeleted, // SARAWAK
     #[adif("221")]
     Denmark, // DENMARK
@@ -461,17 +462,17 @@ pub enum DxccEntityCode {
     Finland, // FINLAND
     #[adif("225")]
     Sardinia, // SARDINIA
-    #[adif("226")]
+    #[adif("226", deleted)]
     SaudiArabiaIraqNeutralZoneDeleted, // SAUDI ARABIA/IRAQ NEUTRAL ZONE
     #[adif("227")]
     France, // FRANCE
-    #[adif("228")]
+    #[adif("228", deleted)]
     SerranaBankAndRoncadorCayDeleted, // SERRANA BANK & RONCADOR CAY
-    #[adif("229")]
+    #[adif("229", deleted)]
     GermanDemocraticRepublicDeleted, // GERMAN DEMOCRATIC REPUBLIC
     #[adif("230")]
     FederalRepublicOfGermany, // FEDERAL REPUBLIC OF GERMANY
-    #[adif("231")]
+    #[adif("231", deleted)]
     SikkimDeleted, // SIKKIM
     #[adif("232")]
     Somalia, // SOMALIA
@@ -495,9 +496,9 @@ pub enum DxccEntityCode {
     SouthShetlandIsland, // SOUTH SHETLAND IS.
     #[adif("242")]
     Iceland, // ICELAND
-    #[adif("243")]
+    #[adif("243", deleted)]
     PeopleSDemocraticRepOfYemenDeleted, // PEOPLE'S DEMOCRATIC REP. OF YEMEN
-    #[adif("244")]
+    #[adif("244", deleted)]
     SouthernSudanDeleted, // SOUTHERN SUDAN
     #[adif("245")]
     Ireland, // IRELAND
@@ -519,39 +520,39 @@ pub enum DxccEntityCode {
     StPeterAndStPaulRocks, // ST. PETER & ST. PAUL ROCKS
     #[adif("254")]
     Luxembourg, // LUXEMBOURG
-    #[adif("255")]
+    #[adif("255", deleted)]
     StMaartenSabaStEustatiusDeleted, // ST. MAARTEN, SABA, ST. EUSTATIUS
     #[adif("256")]
     MadeiraIsland, // MADEIRA IS.
     #[adif("257")]
     Malta, // MALTA
-    #[adif("258")]
+    #[adif("258", deleted)]
     SumatraDeleted, // SUMATRA
     #[adif("259")]
     Svalbard, // SVALBARD
     #[adif("260")]
     Monaco, // MONACO
-    #[adif("261")]
+    #[adif("261", deleted)]
     SwanIslandDeleted, // SWAN IS.
     #[adif("262")]
     Tajikistan, // TAJIKISTAN
     #[adif("263")]
     Netherlands, // NETHERLANDS
-    #[adif("264")]
+    #[adif("264", deleted)]
     TangierDeleted, // TANGIER
     #[adif("265")]
     NorthernIreland, // NORTHERN IRELAND
     #[adif("266")]
     Norway, // NORWAY
-    #[adif("267")]
+    #[adif("267", deleted)]
     TerritoryOfNewGuineaDeleted, // TERRITORY OF NEW GUINEA
-    #[adif("268")]
+    #[adif("268", deleted)]
     TibetDeleted, // TIBET
     #[adif("269")]
     Poland, // POLAND
     #[adif("270")]
     TokelauIsland, // TOKELAU IS.
-    #[adif("271")]
+    #[adif("271", deleted)]
     TriesteDeleted, // TRIESTE
     #[adif("272")]
     Portugal, // PORTUGAL
@@ -619,7 +620,7 @@ pub enum DxccEntityCode {
     Bangladesh, // BANGLADESH
     #[adif("306")]
     Bhutan, // BHUTAN
-    #[adif("307")]
+    #[adif("307", deleted)]
     ZanzibarDeleted, // ZANZIBAR
     #[adif("308")]
     CostaRica, // COSTA RICA
@@ -769,7 +770,7 @@ pub enum DxccEntityCode {
     Zambia, // ZAMBIA
     #[adif("483")]
     Togo, // TOGO
-    #[adif("488")]
+    #[adif("488", deleted)]
     WalvisBayDeleted, // WALVIS BAY
     #[adif("489")]
     ConwayReef, // CONWAY REEF
@@ -777,7 +778,7 @@ pub enum DxccEntityCode {
     BanabaIslandOceanIsland, // BANABA I. (OCEAN I.)
     #[adif("492")]
     Yemen, // YEMEN
-    #[adif("493")]
+    #[adif("493", deleted)]
     PenguinIslandDeleted, // PENGUIN IS.
     #[adif("497")]
     Croatia, // CROATIA
@@ -828,3 +829,15 @@ pub enum DxccEntityCode {
     #[adif("522")]
     RepublicOfKosovo, // REPUBLIC OF KOSOVO
 }
+
+#[cfg(test)]
+mod flag_tests {
+    use super::*;
+
+    #[test]
+    fn test_is_deleted() {
+        assert!(DxccEntityCode::AbuAilIslandDeleted.is_deleted());
+        assert!(!DxccEntityCode::Canada.is_deleted());
+        assert!(!DxccEntityCode::Canada.is_import_only());
+    }
+}
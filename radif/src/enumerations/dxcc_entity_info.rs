@@ -0,0 +1,3049 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use crate::enumerations::continent::Continent;
+use crate::enumerations::dxcc_enity_code::DxccEntityCode;
+use chrono::NaiveDate;
+
+const NA: Continent = Continent::NorthAmerica;
+const SA: Continent = Continent::SouthAmerica;
+const EU: Continent = Continent::Europe;
+const AF: Continent = Continent::Africa;
+const OC: Continent = Continent::Oceana;
+const AS: Continent = Continent::Asia;
+const AN: Continent = Continent::Antarctica;
+
+/// Properties of a DXCC entity as published in the ARRL DXCC list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DxccEntityInfo {
+    pub entity: DxccEntityCode,
+    pub name: &'static str,
+    /// Primary prefix; entities sharing a prefix with another one use the
+    /// cty.dat notation, e.g. `KH8/S` for Swains Island.
+    pub prefix: &'static str,
+    pub continents: &'static [Continent],
+    pub cq_zones: &'static [u8],
+    pub itu_zones: &'static [u8],
+    pub deleted: bool,
+    /// First day contacts count for the entity, `None` when it has been on
+    /// the list since its start.
+    pub valid_from: Option<NaiveDate>,
+    /// Last day contacts count for a deleted entity.
+    pub valid_to: Option<NaiveDate>,
+}
+
+impl DxccEntityInfo {
+    pub fn all() -> &'static [DxccEntityInfo] {
+        &ENTITIES
+    }
+
+    pub fn is_valid_on(&self, date: &NaiveDate) -> bool {
+        self.valid_from.is_none_or(|from| from <= *date)
+            && self.valid_to.is_none_or(|to| *date <= to)
+    }
+
+    const fn since(self, year: i32, month: u32, day: u32) -> Self {
+        Self {
+            valid_from: NaiveDate::from_ymd_opt(year, month, day),
+            ..self
+        }
+    }
+
+    const fn deleted(self, year: i32, month: u32, day: u32) -> Self {
+        Self {
+            deleted: true,
+            valid_to: NaiveDate::from_ymd_opt(year, month, day),
+            ..self
+        }
+    }
+}
+
+const fn entity(
+    entity: DxccEntityCode,
+    name: &'static str,
+    prefix: &'static str,
+    continents: &'static [Continent],
+    cq_zones: &'static [u8],
+    itu_zones: &'static [u8],
+) -> DxccEntityInfo {
+    DxccEntityInfo {
+        entity,
+        name,
+        prefix,
+        continents,
+        cq_zones,
+        itu_zones,
+        deleted: false,
+        valid_from: None,
+        valid_to: None,
+    }
+}
+
+static ENTITIES: [DxccEntityInfo; 403] = [
+    entity(DxccEntityCode::None, "NONE", "", &[], &[], &[]),
+    entity(
+        DxccEntityCode::Canada,
+        "CANADA",
+        "VE",
+        &[NA],
+        &[1, 2, 3, 4, 5],
+        &[2, 3, 4, 9, 75],
+    ),
+    entity(
+        DxccEntityCode::AbuAilIslandDeleted,
+        "ABU AIL IS.",
+        "4W",
+        &[AS],
+        &[21],
+        &[39],
+    )
+    .deleted(1991, 3, 30),
+    entity(
+        DxccEntityCode::Afghanistan,
+        "AFGHANISTAN",
+        "YA",
+        &[AS],
+        &[21],
+        &[40],
+    ),
+    entity(
+        DxccEntityCode::AgalegaAndStBrandonIsland,
+        "AGALEGA & ST. BRANDON IS.",
+        "3B6",
+        &[AF],
+        &[39],
+        &[53],
+    ),
+    entity(
+        DxccEntityCode::AlandIsland,
+        "ALAND IS.",
+        "OH0",
+        &[EU],
+        &[15],
+        &[18],
+    ),
+    entity(DxccEntityCode::Alaska, "ALASKA", "KL", &[NA], &[1], &[1, 2]),
+    entity(
+        DxccEntityCode::Albania,
+        "ALBANIA",
+        "ZA",
+        &[EU],
+        &[15],
+        &[28],
+    ),
+    entity(
+        DxccEntityCode::AldabraDeleted,
+        "ALDABRA",
+        "VQ9",
+        &[AF],
+        &[39],
+        &[53],
+    )
+    .deleted(1976, 6, 28),
+    entity(
+        DxccEntityCode::AmericanSamoa,
+        "AMERICAN SAMOA",
+        "KH8",
+        &[OC],
+        &[32],
+        &[62],
+    ),
+    entity(
+        DxccEntityCode::AmsterdamAndStPaulIsland,
+        "AMSTERDAM & ST. PAUL IS.",
+        "FT5Z",
+        &[AF],
+        &[39],
+        &[68],
+    ),
+    entity(
+        DxccEntityCode::AndamanAndNicobarIsland,
+        "ANDAMAN & NICOBAR IS.",
+        "VU4",
+        &[AS],
+        &[26],
+        &[49],
+    ),
+    entity(
+        DxccEntityCode::Anguilla,
+        "ANGUILLA",
+        "VP2E",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::Antarctica,
+        "ANTARCTICA",
+        "CE9",
+        &[AN],
+        &[12, 13, 29, 30, 32, 38, 39],
+        &[67, 69, 70, 71, 72, 73, 74],
+    ),
+    entity(
+        DxccEntityCode::Armenia,
+        "ARMENIA",
+        "EK",
+        &[AS],
+        &[21],
+        &[29],
+    ),
+    entity(
+        DxccEntityCode::AsiaticRussia,
+        "ASIATIC RUSSIA",
+        "UA9",
+        &[AS],
+        &[16, 17, 18, 19, 23],
+        &[20, 21, 22, 23, 24, 25, 26, 30, 31, 32, 33, 35, 75],
+    ),
+    entity(
+        DxccEntityCode::NewZealandSubantarcticIslands,
+        "NEW ZEALAND SUBANTARCTIC ISLANDS",
+        "ZL9",
+        &[OC],
+        &[32],
+        &[60],
+    ),
+    entity(
+        DxccEntityCode::AvesIsland,
+        "AVES I.",
+        "YV0",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::Azerbaijan,
+        "AZERBAIJAN",
+        "4J",
+        &[AS],
+        &[21],
+        &[29],
+    ),
+    entity(
+        DxccEntityCode::BajoNuevoDeleted,
+        "BAJO NUEVO",
+        "KS4",
+        &[NA],
+        &[7],
+        &[11],
+    )
+    .deleted(1981, 11, 16),
+    entity(
+        DxccEntityCode::BakerAndHowlandIsland,
+        "BAKER & HOWLAND IS.",
+        "KH1",
+        &[OC],
+        &[31],
+        &[61],
+    ),
+    entity(
+        DxccEntityCode::BalearicIsland,
+        "BALEARIC IS.",
+        "EA6",
+        &[EU],
+        &[14],
+        &[37],
+    ),
+    entity(DxccEntityCode::Palau, "PALAU", "T8", &[OC], &[27], &[64]),
+    entity(
+        DxccEntityCode::BlenheimReefDeleted,
+        "BLENHEIM REEF",
+        "VQ9",
+        &[AF],
+        &[39],
+        &[41],
+    )
+    .deleted(1975, 6, 30),
+    entity(
+        DxccEntityCode::Bouvet,
+        "BOUVET",
+        "3Y/B",
+        &[AN],
+        &[38],
+        &[67],
+    ),
+    entity(
+        DxccEntityCode::BritishNorthBorneoDeleted,
+        "BRITISH NORTH BORNEO",
+        "ZC5",
+        &[OC],
+        &[28],
+        &[54],
+    )
+    .deleted(1963, 9, 15),
+    entity(
+        DxccEntityCode::BritishSomalilandDeleted,
+        "BRITISH SOMALILAND",
+        "VQ6",
+        &[AF],
+        &[37],
+        &[48],
+    )
+    .deleted(1960, 6, 30),
+    entity(
+        DxccEntityCode::Belarus,
+        "BELARUS",
+        "EU",
+        &[EU],
+        &[16],
+        &[29],
+    ),
+    entity(
+        DxccEntityCode::CanalZoneDeleted,
+        "CANAL ZONE",
+        "KZ5",
+        &[NA],
+        &[7],
+        &[11],
+    )
+    .deleted(1979, 9, 30),
+    entity(
+        DxccEntityCode::CanaryIsland,
+        "CANARY IS.",
+        "EA8",
+        &[AF],
+        &[33],
+        &[36],
+    ),
+    entity(
+        DxccEntityCode::CelebeAndMoluccaIslandDeleted,
+        "CELEBE & MOLUCCA IS.",
+        "PK6",
+        &[OC],
+        &[28],
+        &[54],
+    )
+    .deleted(1963, 4, 30),
+    entity(
+        DxccEntityCode::CKiribatiBritishPhoenixIsland,
+        "C. KIRIBATI (BRITISH PHOENIX IS.)",
+        "T31",
+        &[OC],
+        &[31],
+        &[62],
+    ),
+    entity(
+        DxccEntityCode::CeutaAndMelilla,
+        "CEUTA & MELILLA",
+        "EA9",
+        &[AF],
+        &[33],
+        &[37],
+    ),
+    entity(
+        DxccEntityCode::ChagosIsland,
+        "CHAGOS IS.",
+        "VQ9",
+        &[AF],
+        &[39],
+        &[41],
+    ),
+    entity(
+        DxccEntityCode::ChathamIsland,
+        "CHATHAM IS.",
+        "ZL7",
+        &[OC],
+        &[32],
+        &[60],
+    ),
+    entity(
+        DxccEntityCode::ChristmasIsland,
+        "CHRISTMAS I.",
+        "VK9X",
+        &[OC],
+        &[29],
+        &[54],
+    ),
+    entity(
+        DxccEntityCode::ClippertonIsland,
+        "CLIPPERTON I.",
+        "FO/C",
+        &[NA],
+        &[7],
+        &[10],
+    ),
+    entity(
+        DxccEntityCode::CocosIsland,
+        "COCOS I.",
+        "TI9",
+        &[NA],
+        &[7],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::CocosKeelingIsland,
+        "COCOS (KEELING) IS.",
+        "VK9C",
+        &[OC],
+        &[29],
+        &[54],
+    ),
+    entity(
+        DxccEntityCode::ComorosDeleted,
+        "COMOROS",
+        "FH8",
+        &[AF],
+        &[39],
+        &[53],
+    )
+    .deleted(1975, 7, 5),
+    entity(DxccEntityCode::Crete, "CRETE", "SV9", &[EU], &[20], &[28]),
+    entity(
+        DxccEntityCode::CrozetIsland,
+        "CROZET I.",
+        "FT5W",
+        &[AF],
+        &[39],
+        &[68],
+    ),
+    entity(
+        DxccEntityCode::DamaoDiuDeleted,
+        "DAMAO, DIU",
+        "CR8",
+        &[AS],
+        &[22],
+        &[41],
+    )
+    .deleted(1961, 12, 31),
+    entity(
+        DxccEntityCode::DesecheoIsland,
+        "DESECHEO I.",
+        "KP5",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::DesrochesDeleted,
+        "DESROCHES",
+        "VQ9",
+        &[AF],
+        &[39],
+        &[53],
+    )
+    .deleted(1976, 6, 28),
+    entity(
+        DxccEntityCode::Dodecanese,
+        "DODECANESE",
+        "SV5",
+        &[EU],
+        &[20],
+        &[28],
+    ),
+    entity(
+        DxccEntityCode::EastMalaysia,
+        "EAST MALAYSIA",
+        "9M6",
+        &[OC],
+        &[28],
+        &[54],
+    ),
+    entity(
+        DxccEntityCode::EasterIsland,
+        "EASTER I.",
+        "CE0Y",
+        &[SA],
+        &[12],
+        &[63],
+    ),
+    entity(
+        DxccEntityCode::EKiribatiLineIsland,
+        "E. KIRIBATI (LINE IS.)",
+        "T32",
+        &[OC],
+        &[31],
+        &[61, 63],
+    ),
+    entity(
+        DxccEntityCode::EquatorialGuinea,
+        "EQUATORIAL GUINEA",
+        "3C",
+        &[AF],
+        &[36],
+        &[47],
+    ),
+    entity(DxccEntityCode::Mexico, "MEXICO", "XE", &[NA], &[6], &[10]),
+    entity(
+        DxccEntityCode::Eritrea,
+        "ERITREA",
+        "E3",
+        &[AF],
+        &[37],
+        &[48],
+    ),
+    entity(
+        DxccEntityCode::Estonia,
+        "ESTONIA",
+        "ES",
+        &[EU],
+        &[15],
+        &[29],
+    ),
+    entity(
+        DxccEntityCode::Ethiopia,
+        "ETHIOPIA",
+        "ET",
+        &[AF],
+        &[37],
+        &[48],
+    ),
+    entity(
+        DxccEntityCode::EuropeanRussia,
+        "EUROPEAN RUSSIA",
+        "UA",
+        &[EU],
+        &[16],
+        &[19, 20, 29, 30],
+    ),
+    entity(
+        DxccEntityCode::FarquharDeleted,
+        "FARQUHAR",
+        "VQ9",
+        &[AF],
+        &[39],
+        &[53],
+    )
+    .deleted(1976, 6, 28),
+    entity(
+        DxccEntityCode::FernandoDeNoronha,
+        "FERNANDO DE NORONHA",
+        "PY0F",
+        &[SA],
+        &[11],
+        &[13],
+    ),
+    entity(
+        DxccEntityCode::FrenchEquatorialAfricaDeleted,
+        "FRENCH EQUATORIAL AFRICA",
+        "FQ8",
+        &[AF],
+        &[36],
+        &[47],
+    )
+    .deleted(1960, 8, 16),
+    entity(
+        DxccEntityCode::FrenchIndoChinaDeleted,
+        "FRENCH INDO-CHINA",
+        "FI8",
+        &[AS],
+        &[26],
+        &[49],
+    )
+    .deleted(1950, 12, 20),
+    entity(
+        DxccEntityCode::FrenchWestAfricaDeleted,
+        "FRENCH WEST AFRICA",
+        "FF8",
+        &[AF],
+        &[35],
+        &[46],
+    )
+    .deleted(1960, 8, 6),
+    entity(DxccEntityCode::Bahamas, "BAHAMAS", "C6", &[NA], &[8], &[11]),
+    entity(
+        DxccEntityCode::FranzJosefLand,
+        "FRANZ JOSEF LAND",
+        "R1FJ",
+        &[EU],
+        &[40],
+        &[75],
+    ),
+    entity(
+        DxccEntityCode::Barbados,
+        "BARBADOS",
+        "8P",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::FrenchGuiana,
+        "FRENCH GUIANA",
+        "FY",
+        &[SA],
+        &[9],
+        &[12],
+    ),
+    entity(
+        DxccEntityCode::Bermuda,
+        "BERMUDA",
+        "VP9",
+        &[NA],
+        &[5],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::BritishVirginIsland,
+        "BRITISH VIRGIN IS.",
+        "VP2V",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(DxccEntityCode::Belize, "BELIZE", "V3", &[NA], &[7], &[11]),
+    entity(
+        DxccEntityCode::FrenchIndiaDeleted,
+        "FRENCH INDIA",
+        "FN8",
+        &[AS],
+        &[22],
+        &[41],
+    )
+    .deleted(1954, 10, 31),
+    entity(
+        DxccEntityCode::KuwaitSaudiArabiaNeutralZoneDeleted,
+        "KUWAIT/SAUDI ARABIA NEUTRAL ZONE",
+        "8Z4",
+        &[AS],
+        &[21],
+        &[39],
+    )
+    .deleted(1969, 12, 14),
+    entity(
+        DxccEntityCode::CaymanIsland,
+        "CAYMAN IS.",
+        "ZF",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(DxccEntityCode::Cuba, "CUBA", "CM", &[NA], &[8], &[11]),
+    entity(
+        DxccEntityCode::GalapagosIsland,
+        "GALAPAGOS IS.",
+        "HC8",
+        &[SA],
+        &[10],
+        &[12],
+    ),
+    entity(
+        DxccEntityCode::DominicanRepublic,
+        "DOMINICAN REPUBLIC",
+        "HI",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::ElSalvador,
+        "EL SALVADOR",
+        "YS",
+        &[NA],
+        &[7],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::Georgia,
+        "GEORGIA",
+        "4L",
+        &[AS],
+        &[21],
+        &[29],
+    ),
+    entity(
+        DxccEntityCode::Guatemala,
+        "GUATEMALA",
+        "TG",
+        &[NA],
+        &[7],
+        &[11],
+    ),
+    entity(DxccEntityCode::Grenada, "GRENADA", "J3", &[NA], &[8], &[11]),
+    entity(DxccEntityCode::Haiti, "HAITI", "HH", &[NA], &[8], &[11]),
+    entity(
+        DxccEntityCode::Guadeloupe,
+        "GUADELOUPE",
+        "FG",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::Honduras,
+        "HONDURAS",
+        "HR",
+        &[NA],
+        &[7],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::GermanyDeleted,
+        "GERMANY",
+        "DL",
+        &[EU],
+        &[14],
+        &[28],
+    )
+    .deleted(1973, 9, 16),
+    entity(DxccEntityCode::Jamaica, "JAMAICA", "6Y", &[NA], &[8], &[11]),
+    entity(
+        DxccEntityCode::Martinique,
+        "MARTINIQUE",
+        "FM",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::BonaireCuracaoDeleted,
+        "BONAIRE, CURACAO",
+        "PJ2",
+        &[SA],
+        &[9],
+        &[11],
+    )
+    .deleted(2010, 10, 9),
+    entity(
+        DxccEntityCode::Nicaragua,
+        "NICARAGUA",
+        "YN",
+        &[NA],
+        &[7],
+        &[11],
+    ),
+    entity(DxccEntityCode::Panama, "PANAMA", "HP", &[NA], &[7], &[11]),
+    entity(
+        DxccEntityCode::TurksAndCaicosIsland,
+        "TURKS & CAICOS IS.",
+        "VP5",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::TrinidadAndTobago,
+        "TRINIDAD & TOBAGO",
+        "9Y",
+        &[SA],
+        &[9],
+        &[11],
+    ),
+    entity(DxccEntityCode::Aruba, "ARUBA", "P4", &[SA], &[9], &[11]),
+    entity(
+        DxccEntityCode::GeyserReefDeleted,
+        "GEYSER REEF",
+        "1G",
+        &[AF],
+        &[39],
+        &[53],
+    )
+    .deleted(1978, 2, 28),
+    entity(
+        DxccEntityCode::AntiguaAndBarbuda,
+        "ANTIGUA & BARBUDA",
+        "V2",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::Dominica,
+        "DOMINICA",
+        "J7",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::Montserrat,
+        "MONTSERRAT",
+        "VP2M",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::StLucia,
+        "ST. LUCIA",
+        "J6",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::StVincent,
+        "ST. VINCENT",
+        "J8",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::GloriosoIsland,
+        "GLORIOSO IS.",
+        "FT/G",
+        &[AF],
+        &[39],
+        &[53],
+    ),
+    entity(
+        DxccEntityCode::Argentina,
+        "ARGENTINA",
+        "LU",
+        &[SA],
+        &[13],
+        &[14, 16],
+    ),
+    entity(
+        DxccEntityCode::GoaDeleted,
+        "GOA",
+        "CR8",
+        &[AS],
+        &[22],
+        &[41],
+    )
+    .deleted(1961, 12, 31),
+    entity(
+        DxccEntityCode::GoldCoastTogolandDeleted,
+        "GOLD COAST, TOGOLAND",
+        "ZD4",
+        &[AF],
+        &[35],
+        &[46],
+    )
+    .deleted(1957, 3, 5),
+    entity(DxccEntityCode::Guam, "GUAM", "KH2", &[OC], &[27], &[64]),
+    entity(
+        DxccEntityCode::Bolivia,
+        "BOLIVIA",
+        "CP",
+        &[SA],
+        &[10],
+        &[12, 14],
+    ),
+    entity(
+        DxccEntityCode::GuantanamoBay,
+        "GUANTANAMO BAY",
+        "KG4",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::Guernsey,
+        "GUERNSEY",
+        "GU",
+        &[EU],
+        &[14],
+        &[27],
+    ),
+    entity(DxccEntityCode::Guinea, "GUINEA", "3X", &[AF], &[35], &[46]),
+    entity(
+        DxccEntityCode::Brazil,
+        "BRAZIL",
+        "PY",
+        &[SA],
+        &[11],
+        &[12, 13, 15],
+    ),
+    entity(
+        DxccEntityCode::GuineaBissau,
+        "GUINEA-BISSAU",
+        "J5",
+        &[AF],
+        &[35],
+        &[46],
+    ),
+    entity(DxccEntityCode::Hawaii, "HAWAII", "KH6", &[OC], &[31], &[61]),
+    entity(
+        DxccEntityCode::HeardIsland,
+        "HEARD I.",
+        "VK0H",
+        &[AF],
+        &[39],
+        &[68],
+    ),
+    entity(
+        DxccEntityCode::Chile,
+        "CHILE",
+        "CE",
+        &[SA],
+        &[12],
+        &[14, 16],
+    ),
+    entity(
+        DxccEntityCode::IfniDeleted,
+        "IFNI",
+        "EA9",
+        &[AF],
+        &[33],
+        &[37],
+    )
+    .deleted(1969, 5, 13),
+    entity(
+        DxccEntityCode::IsleOfMan,
+        "ISLE OF MAN",
+        "GD",
+        &[EU],
+        &[14],
+        &[27],
+    ),
+    entity(
+        DxccEntityCode::ItalianSomalilandDeleted,
+        "ITALIAN SOMALILAND",
+        "I5",
+        &[AF],
+        &[37],
+        &[48],
+    )
+    .deleted(1960, 6, 30),
+    entity(
+        DxccEntityCode::Colombia,
+        "COLOMBIA",
+        "HK",
+        &[SA],
+        &[9],
+        &[12],
+    ),
+    entity(DxccEntityCode::ItuHq, "ITU HQ", "4U1I", &[EU], &[14], &[28]),
+    entity(
+        DxccEntityCode::JanMayen,
+        "JAN MAYEN",
+        "JX",
+        &[EU],
+        &[40],
+        &[18],
+    ),
+    entity(
+        DxccEntityCode::JavaDeleted,
+        "JAVA",
+        "PK1",
+        &[OC],
+        &[28],
+        &[54],
+    )
+    .deleted(1963, 4, 30),
+    entity(
+        DxccEntityCode::Ecuador,
+        "ECUADOR",
+        "HC",
+        &[SA],
+        &[10],
+        &[12],
+    ),
+    entity(DxccEntityCode::Jersey, "JERSEY", "GJ", &[EU], &[14], &[27]),
+    entity(
+        DxccEntityCode::JohnstonIsland,
+        "JOHNSTON I.",
+        "KH3",
+        &[OC],
+        &[31],
+        &[61],
+    ),
+    entity(
+        DxccEntityCode::JuanDeNovaEuropa,
+        "JUAN DE NOVA, EUROPA",
+        "FT/J",
+        &[AF],
+        &[39],
+        &[53],
+    ),
+    entity(
+        DxccEntityCode::JuanFernandezIsland,
+        "JUAN FERNANDEZ IS.",
+        "CE0Z",
+        &[SA],
+        &[12],
+        &[14],
+    ),
+    entity(
+        DxccEntityCode::Kaliningrad,
+        "KALININGRAD",
+        "UA2",
+        &[EU],
+        &[15],
+        &[29],
+    ),
+    entity(
+        DxccEntityCode::KamaranIslandDeleted,
+        "KAMARAN IS.",
+        "VS9K",
+        &[AS],
+        &[21],
+        &[39],
+    )
+    .deleted(1982, 3, 10),
+    entity(
+        DxccEntityCode::KareloFinnishRepublicDeleted,
+        "KARELO-FINNISH REPUBLIC",
+        "UN1",
+        &[EU],
+        &[16],
+        &[19],
+    )
+    .deleted(1960, 6, 30),
+    entity(DxccEntityCode::Guyana, "GUYANA", "8R", &[SA], &[9], &[12]),
+    entity(
+        DxccEntityCode::Kazakhstan,
+        "KAZAKHSTAN",
+        "UN",
+        &[AS],
+        &[17],
+        &[29, 30, 31],
+    ),
+    entity(
+        DxccEntityCode::KerguelenIsland,
+        "KERGUELEN IS.",
+        "FT5X",
+        &[AF],
+        &[39],
+        &[68],
+    ),
+    entity(
+        DxccEntityCode::Paraguay,
+        "PARAGUAY",
+        "ZP",
+        &[SA],
+        &[11],
+        &[14],
+    ),
+    entity(
+        DxccEntityCode::KermadecIsland,
+        "KERMADEC IS.",
+        "ZL8",
+        &[OC],
+        &[32],
+        &[60],
+    ),
+    entity(
+        DxccEntityCode::KingmanReefDeleted,
+        "KINGMAN REEF",
+        "KH5K",
+        &[OC],
+        &[31],
+        &[61],
+    )
+    .deleted(2016, 6, 28),
+    entity(
+        DxccEntityCode::Kyrgyzstan,
+        "KYRGYZSTAN",
+        "EX",
+        &[AS],
+        &[17],
+        &[30, 31],
+    ),
+    entity(DxccEntityCode::Peru, "PERU", "OA", &[SA], &[10], &[12]),
+    entity(
+        DxccEntityCode::RepublicOfKorea,
+        "REPUBLIC OF KOREA",
+        "HL",
+        &[AS],
+        &[25],
+        &[44],
+    ),
+    entity(
+        DxccEntityCode::KureIsland,
+        "KURE I.",
+        "KH7K",
+        &[OC],
+        &[31],
+        &[61],
+    ),
+    entity(
+        DxccEntityCode::KuriaMuriaIslandDeleted,
+        "KURIA MURIA I.",
+        "VS9O",
+        &[AS],
+        &[21],
+        &[39],
+    )
+    .deleted(1967, 11, 29),
+    entity(
+        DxccEntityCode::Suriname,
+        "SURINAME",
+        "PZ",
+        &[SA],
+        &[9],
+        &[12],
+    ),
+    entity(
+        DxccEntityCode::FalklandIsland,
+        "FALKLAND IS.",
+        "VP8",
+        &[SA],
+        &[13],
+        &[16],
+    ),
+    entity(
+        DxccEntityCode::LakshadweepIsland,
+        "LAKSHADWEEP IS.",
+        "VU7",
+        &[AS],
+        &[22],
+        &[41],
+    ),
+    entity(DxccEntityCode::Laos, "LAOS", "XW", &[AS], &[26], &[49]),
+    entity(
+        DxccEntityCode::Uruguay,
+        "URUGUAY",
+        "CX",
+        &[SA],
+        &[13],
+        &[14],
+    ),
+    entity(DxccEntityCode::Latvia, "LATVIA", "YL", &[EU], &[15], &[29]),
+    entity(
+        DxccEntityCode::Lithuania,
+        "LITHUANIA",
+        "LY",
+        &[EU],
+        &[15],
+        &[29],
+    ),
+    entity(
+        DxccEntityCode::LordHoweIsland,
+        "LORD HOWE I.",
+        "VK9L",
+        &[OC],
+        &[30],
+        &[60],
+    ),
+    entity(
+        DxccEntityCode::Venezuela,
+        "VENEZUELA",
+        "YV",
+        &[SA],
+        &[9],
+        &[12],
+    ),
+    entity(DxccEntityCode::Azores, "AZORES", "CU", &[EU], &[14], &[36]),
+    entity(
+        DxccEntityCode::Australia,
+        "AUSTRALIA",
+        "VK",
+        &[OC],
+        &[29, 30],
+        &[55, 58, 59],
+    ),
+    entity(
+        DxccEntityCode::MalyjVysotskijIslandDeleted,
+        "MALYJ VYSOTSKIJ I.",
+        "R1MV",
+        &[EU],
+        &[16],
+        &[29],
+    )
+    .deleted(2012, 2, 28),
+    entity(DxccEntityCode::Macao, "MACAO", "XX9", &[AS], &[24], &[44]),
+    entity(
+        DxccEntityCode::MacquarieIsland,
+        "MACQUARIE I.",
+        "VK0M",
+        &[OC],
+        &[30],
+        &[60],
+    ),
+    entity(
+        DxccEntityCode::YemenArabRepublicDeleted,
+        "YEMEN ARAB REPUBLIC",
+        "4W",
+        &[AS],
+        &[21],
+        &[39],
+    )
+    .deleted(1990, 5, 21),
+    entity(
+        DxccEntityCode::MalayaDeleted,
+        "MALAYA",
+        "VS2",
+        &[AS],
+        &[28],
+        &[54],
+    )
+    .deleted(1963, 9, 15),
+    entity(DxccEntityCode::Nauru, "NAURU", "C2", &[OC], &[31], &[65]),
+    entity(
+        DxccEntityCode::Vanuatu,
+        "VANUATU",
+        "YJ",
+        &[OC],
+        &[32],
+        &[56],
+    ),
+    entity(
+        DxccEntityCode::Maldives,
+        "MALDIVES",
+        "8Q",
+        &[AS],
+        &[22],
+        &[41],
+    ),
+    entity(DxccEntityCode::Tonga, "TONGA", "A3", &[OC], &[32], &[62]),
+    entity(
+        DxccEntityCode::MalpeloIsland,
+        "MALPELO I.",
+        "HK0/M",
+        &[SA],
+        &[9],
+        &[12],
+    ),
+    entity(
+        DxccEntityCode::NewCaledonia,
+        "NEW CALEDONIA",
+        "FK",
+        &[OC],
+        &[32],
+        &[56],
+    ),
+    entity(
+        DxccEntityCode::PapuaNewGuinea,
+        "PAPUA NEW GUINEA",
+        "P2",
+        &[OC],
+        &[28],
+        &[51],
+    ),
+    entity(
+        DxccEntityCode::ManchuriaDeleted,
+        "MANCHURIA",
+        "C9",
+        &[AS],
+        &[24],
+        &[33],
+    )
+    .deleted(1963, 5, 31),
+    entity(
+        DxccEntityCode::Mauritius,
+        "MAURITIUS",
+        "3B8",
+        &[AF],
+        &[39],
+        &[53],
+    ),
+    entity(
+        DxccEntityCode::MarianaIsland,
+        "MARIANA IS.",
+        "KH0",
+        &[OC],
+        &[27],
+        &[64],
+    ),
+    entity(
+        DxccEntityCode::MarketReef,
+        "MARKET REEF",
+        "OJ0",
+        &[EU],
+        &[15],
+        &[18],
+    ),
+    entity(
+        DxccEntityCode::MarshallIsland,
+        "MARSHALL IS.",
+        "V7",
+        &[OC],
+        &[31],
+        &[65],
+    ),
+    entity(
+        DxccEntityCode::Mayotte,
+        "MAYOTTE",
+        "FH",
+        &[AF],
+        &[39],
+        &[53],
+    ),
+    entity(
+        DxccEntityCode::NewZealand,
+        "NEW ZEALAND",
+        "ZL",
+        &[OC],
+        &[32],
+        &[60],
+    ),
+    entity(
+        DxccEntityCode::MellishReef,
+        "MELLISH REEF",
+        "VK9M",
+        &[OC],
+        &[30],
+        &[56],
+    ),
+    entity(
+        DxccEntityCode::PitcairnIsland,
+        "PITCAIRN I.",
+        "VP6",
+        &[OC],
+        &[32],
+        &[63],
+    ),
+    entity(
+        DxccEntityCode::Micronesia,
+        "MICRONESIA",
+        "V6",
+        &[OC],
+        &[27],
+        &[65],
+    ),
+    entity(
+        DxccEntityCode::MidwayIsland,
+        "MIDWAY I.",
+        "KH4",
+        &[OC],
+        &[31],
+        &[61],
+    ),
+    entity(
+        DxccEntityCode::FrenchPolynesia,
+        "FRENCH POLYNESIA",
+        "FO",
+        &[OC],
+        &[32],
+        &[63],
+    ),
+    entity(DxccEntityCode::Fiji, "FIJI", "3D2", &[OC], &[32], &[56]),
+    entity(
+        DxccEntityCode::MinamiTorishima,
+        "MINAMI TORISHIMA",
+        "JD/M",
+        &[OC],
+        &[27],
+        &[90],
+    ),
+    entity(
+        DxccEntityCode::MinervaReefDeleted,
+        "MINERVA REEF",
+        "1M",
+        &[OC],
+        &[32],
+        &[62],
+    )
+    .deleted(1972, 7, 31),
+    entity(
+        DxccEntityCode::Moldova,
+        "MOLDOVA",
+        "ER",
+        &[EU],
+        &[16],
+        &[29],
+    ),
+    entity(
+        DxccEntityCode::MountAthos,
+        "MOUNT ATHOS",
+        "SV/A",
+        &[EU],
+        &[20],
+        &[28],
+    ),
+    entity(
+        DxccEntityCode::Mozambique,
+        "MOZAMBIQUE",
+        "C9",
+        &[AF],
+        &[37],
+        &[53],
+    ),
+    entity(
+        DxccEntityCode::NavassaIsland,
+        "NAVASSA I.",
+        "KP1",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::NetherlandsBorneoDeleted,
+        "NETHERLANDS BORNEO",
+        "PK5",
+        &[OC],
+        &[28],
+        &[54],
+    )
+    .deleted(1963, 4, 30),
+    entity(
+        DxccEntityCode::NetherlandsNewGuineaDeleted,
+        "NETHERLANDS NEW GUINEA",
+        "JZ0",
+        &[OC],
+        &[28],
+        &[51],
+    )
+    .deleted(1963, 4, 30),
+    entity(
+        DxccEntityCode::SolomonIsland,
+        "SOLOMON IS.",
+        "H4",
+        &[OC],
+        &[28],
+        &[51],
+    ),
+    entity(
+        DxccEntityCode::NewfoundlandLabradorDeleted,
+        "NEWFOUNDLAND, LABRADOR",
+        "VO",
+        &[NA],
+        &[2, 5],
+        &[9],
+    )
+    .deleted(1949, 3, 31),
+    entity(DxccEntityCode::Niger, "NIGER", "5U", &[AF], &[35], &[46]),
+    entity(DxccEntityCode::Niue, "NIUE", "E6", &[OC], &[32], &[62]),
+    entity(
+        DxccEntityCode::NorfolkIsland,
+        "NORFOLK I.",
+        "VK9N",
+        &[OC],
+        &[32],
+        &[60],
+    ),
+    entity(DxccEntityCode::Samoa, "SAMOA", "5W", &[OC], &[32], &[62]),
+    entity(
+        DxccEntityCode::NorthCookIsland,
+        "NORTH COOK IS.",
+        "E5/N",
+        &[OC],
+        &[32],
+        &[62],
+    ),
+    entity(
+        DxccEntityCode::Ogasawara,
+        "OGASAWARA",
+        "JD/O",
+        &[AS],
+        &[27],
+        &[45],
+    ),
+    entity(
+        DxccEntityCode::OkinawaRyukyuIslandDeleted,
+        "OKINAWA (RYUKYU IS.)",
+        "KR6",
+        &[AS],
+        &[25],
+        &[45],
+    )
+    .deleted(1972, 5, 14),
+    entity(
+        DxccEntityCode::OkinoToriShimaDeleted,
+        "OKINO TORI-SHIMA",
+        "7J1",
+        &[AS],
+        &[27],
+        &[45],
+    )
+    .deleted(1976, 5, 31),
+    entity(
+        DxccEntityCode::AnnobonIsland,
+        "ANNOBON I.",
+        "3C0",
+        &[AF],
+        &[36],
+        &[52],
+    ),
+    entity(
+        DxccEntityCode::PalestineDeleted,
+        "PALESTINE",
+        "ZC6",
+        &[AS],
+        &[20],
+        &[39],
+    )
+    .deleted(1968, 6, 30),
+    entity(
+        DxccEntityCode::PalmyraAndJarvisIsland,
+        "PALMYRA & JARVIS IS.",
+        "KH5",
+        &[OC],
+        &[31],
+        &[61, 62],
+    ),
+    entity(
+        DxccEntityCode::PapuaTerritoryDeleted,
+        "PAPUA TERRITORY",
+        "VK9",
+        &[OC],
+        &[28],
+        &[51],
+    )
+    .deleted(1975, 9, 15),
+    entity(
+        DxccEntityCode::PeterIsland,
+        "PETER 1 I.",
+        "3Y/P",
+        &[AN],
+        &[12],
+        &[72],
+    ),
+    entity(
+        DxccEntityCode::PortugueseTimorDeleted,
+        "PORTUGUESE TIMOR",
+        "CR8",
+        &[OC],
+        &[28],
+        &[54],
+    )
+    .deleted(1976, 7, 16),
+    entity(
+        DxccEntityCode::PrinceEdwardAndMarionIsland,
+        "PRINCE EDWARD & MARION IS.",
+        "ZS8",
+        &[AF],
+        &[38],
+        &[57],
+    ),
+    entity(
+        DxccEntityCode::PuertoRico,
+        "PUERTO RICO",
+        "KP4",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::Andorra,
+        "ANDORRA",
+        "C3",
+        &[EU],
+        &[14],
+        &[27],
+    ),
+    entity(
+        DxccEntityCode::Revillagigedo,
+        "REVILLAGIGEDO",
+        "XF4",
+        &[NA],
+        &[6],
+        &[10],
+    ),
+    entity(
+        DxccEntityCode::AscensionIsland,
+        "ASCENSION I.",
+        "ZD8",
+        &[AF],
+        &[36],
+        &[66],
+    ),
+    entity(
+        DxccEntityCode::Austria,
+        "AUSTRIA",
+        "OE",
+        &[EU],
+        &[15],
+        &[28],
+    ),
+    entity(
+        DxccEntityCode::RodriguezIsland,
+        "RODRIGUEZ I.",
+        "3B9",
+        &[AF],
+        &[39],
+        &[53],
+    ),
+    entity(
+        DxccEntityCode::RuandaUrundiDeleted,
+        "RUANDA-URUNDI",
+        "9U5",
+        &[AF],
+        &[36],
+        &[52],
+    )
+    .deleted(1962, 6, 30),
+    entity(
+        DxccEntityCode::Belgium,
+        "BELGIUM",
+        "ON",
+        &[EU],
+        &[14],
+        &[27],
+    ),
+    entity(
+        DxccEntityCode::SaarDeleted,
+        "SAAR",
+        "9S4",
+        &[EU],
+        &[14],
+        &[28],
+    )
+    .deleted(1957, 3, 31),
+    entity(
+        DxccEntityCode::SableIsland,
+        "SABLE I.",
+        "CY0",
+        &[NA],
+        &[5],
+        &[9],
+    ),
+    entity(
+        DxccEntityCode::Bulgaria,
+        "BULGARIA",
+        "LZ",
+        &[EU],
+        &[20],
+        &[28],
+    ),
+    entity(
+        DxccEntityCode::SaintMartin,
+        "SAINT MARTIN",
+        "FS",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::Corsica,
+        "CORSICA",
+        "TK",
+        &[EU],
+        &[15],
+        &[28],
+    ),
+    entity(DxccEntityCode::Cyprus, "CYPRUS", "5B", &[AS], &[20], &[39]),
+    entity(
+        DxccEntityCode::SanAndresAndProvidencia,
+        "SAN ANDRES & PROVIDENCIA",
+        "HK0/A",
+        &[NA],
+        &[7],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::SanFelixAndSanAmbrosio,
+        "SAN FELIX & SAN AMBROSIO",
+        "CE0X",
+        &[SA],
+        &[12],
+        &[14],
+    ),
+    entity(
+        DxccEntityCode::CzechoslovakiaDeleted,
+        "CZECHOSLOVAKIA",
+        "OK",
+        &[EU],
+        &[15],
+        &[28],
+    )
+    .deleted(1992, 12, 31),
+    entity(
+        DxccEntityCode::SaoTomeAndPrincipe,
+        "SAO TOME & PRINCIPE",
+        "S9",
+        &[AF],
+        &[36],
+        &[47],
+    ),
+    entity(
+        DxccEntityCode::SarawakDeleted,
+        "SARAWAK",
+        "VS4",
+        &[OC],
+        &[28],
+        &[54],
+    )
+    .deleted(1963, 9, 15),
+    entity(
+        DxccEntityCode::Denmark,
+        "DENMARK",
+        "OZ",
+        &[EU],
+        &[14],
+        &[18],
+    ),
+    entity(
+        DxccEntityCode::FaroeIsland,
+        "FAROE IS.",
+        "OY",
+        &[EU],
+        &[14],
+        &[18],
+    ),
+    entity(DxccEntityCode::England, "ENGLAND", "G", &[EU], &[14], &[27]),
+    entity(
+        DxccEntityCode::Finland,
+        "FINLAND",
+        "OH",
+        &[EU],
+        &[15],
+        &[18],
+    ),
+    entity(
+        DxccEntityCode::Sardinia,
+        "SARDINIA",
+        "IS",
+        &[EU],
+        &[15],
+        &[28],
+    ),
+    entity(
+        DxccEntityCode::SaudiArabiaIraqNeutralZoneDeleted,
+        "SAUDI ARABIA/IRAQ NEUTRAL ZONE",
+        "8Z5",
+        &[AS],
+        &[21],
+        &[39],
+    )
+    .deleted(1981, 12, 25),
+    entity(DxccEntityCode::France, "FRANCE", "F", &[EU], &[14], &[27]),
+    entity(
+        DxccEntityCode::SerranaBankAndRoncadorCayDeleted,
+        "SERRANA BANK & RONCADOR CAY",
+        "KS4",
+        &[NA],
+        &[7],
+        &[11],
+    )
+    .deleted(1981, 9, 16),
+    entity(
+        DxccEntityCode::GermanDemocraticRepublicDeleted,
+        "GERMAN DEMOCRATIC REPUBLIC",
+        "DM",
+        &[EU],
+        &[14],
+        &[28],
+    )
+    .since(1973, 9, 17)
+    .deleted(1990, 10, 2),
+    entity(
+        DxccEntityCode::FederalRepublicOfGermany,
+        "FEDERAL REPUBLIC OF GERMANY",
+        "DL",
+        &[EU],
+        &[14],
+        &[28],
+    )
+    .since(1973, 9, 17),
+    entity(
+        DxccEntityCode::SikkimDeleted,
+        "SIKKIM",
+        "AC3",
+        &[AS],
+        &[22],
+        &[41],
+    )
+    .deleted(1975, 4, 30),
+    entity(
+        DxccEntityCode::Somalia,
+        "SOMALIA",
+        "T5",
+        &[AF],
+        &[37],
+        &[48],
+    ),
+    entity(
+        DxccEntityCode::Gibraltar,
+        "GIBRALTAR",
+        "ZB",
+        &[EU],
+        &[14],
+        &[37],
+    ),
+    entity(
+        DxccEntityCode::SouthCookIsland,
+        "SOUTH COOK IS.",
+        "E5/S",
+        &[OC],
+        &[32],
+        &[62],
+    ),
+    entity(
+        DxccEntityCode::SouthGeorgiaIsland,
+        "SOUTH GEORGIA I.",
+        "VP8/G",
+        &[SA],
+        &[13],
+        &[73],
+    ),
+    entity(DxccEntityCode::Greece, "GREECE", "SV", &[EU], &[20], &[28]),
+    entity(
+        DxccEntityCode::Greenland,
+        "GREENLAND",
+        "OX",
+        &[NA],
+        &[40],
+        &[5, 75],
+    ),
+    entity(
+        DxccEntityCode::SouthOrkneyIsland,
+        "SOUTH ORKNEY IS.",
+        "VP8/O",
+        &[SA],
+        &[13],
+        &[73],
+    ),
+    entity(
+        DxccEntityCode::Hungary,
+        "HUNGARY",
+        "HA",
+        &[EU],
+        &[15],
+        &[28],
+    ),
+    entity(
+        DxccEntityCode::SouthSandwichIsland,
+        "SOUTH SANDWICH IS.",
+        "VP8/S",
+        &[SA],
+        &[13],
+        &[73],
+    ),
+    entity(
+        DxccEntityCode::SouthShetlandIsland,
+        "SOUTH SHETLAND IS.",
+        "VP8/H",
+        &[SA],
+        &[13],
+        &[73],
+    ),
+    entity(
+        DxccEntityCode::Iceland,
+        "ICELAND",
+        "TF",
+        &[EU],
+        &[40],
+        &[17],
+    ),
+    entity(
+        DxccEntityCode::PeopleSDemocraticRepOfYemenDeleted,
+        "PEOPLE'S DEMOCRATIC REP. OF YEMEN",
+        "7O",
+        &[AS],
+        &[21],
+        &[39],
+    )
+    .deleted(1990, 5, 21),
+    entity(
+        DxccEntityCode::SouthernSudanDeleted,
+        "SOUTHERN SUDAN",
+        "ST0",
+        &[AF],
+        &[34],
+        &[47, 48],
+    )
+    .deleted(1994, 12, 31),
+    entity(
+        DxccEntityCode::Ireland,
+        "IRELAND",
+        "EI",
+        &[EU],
+        &[14],
+        &[27],
+    ),
+    entity(
+        DxccEntityCode::SovereignMilitaryOrderOfMalta,
+        "SOVEREIGN MILITARY ORDER OF MALTA",
+        "1A",
+        &[EU],
+        &[15],
+        &[28],
+    ),
+    entity(
+        DxccEntityCode::SpratlyIsland,
+        "SPRATLY IS.",
+        "1S",
+        &[AS],
+        &[26],
+        &[50],
+    ),
+    entity(
+        DxccEntityCode::Italy,
+        "ITALY",
+        "I",
+        &[EU],
+        &[15, 33],
+        &[28, 37],
+    ),
+    entity(
+        DxccEntityCode::StKittsAndNevis,
+        "ST. KITTS & NEVIS",
+        "V4",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::StHelena,
+        "ST. HELENA",
+        "ZD7",
+        &[AF],
+        &[36],
+        &[66],
+    ),
+    entity(
+        DxccEntityCode::Liechtenstein,
+        "LIECHTENSTEIN",
+        "HB0",
+        &[EU],
+        &[14],
+        &[28],
+    ),
+    entity(
+        DxccEntityCode::StPaulIsland,
+        "ST. PAUL I.",
+        "CY9",
+        &[NA],
+        &[5],
+        &[9],
+    ),
+    entity(
+        DxccEntityCode::StPeterAndStPaulRocks,
+        "ST. PETER & ST. PAUL ROCKS",
+        "PY0S",
+        &[SA],
+        &[11],
+        &[13],
+    ),
+    entity(
+        DxccEntityCode::Luxembourg,
+        "LUXEMBOURG",
+        "LX",
+        &[EU],
+        &[14],
+        &[27],
+    ),
+    entity(
+        DxccEntityCode::StMaartenSabaStEustatiusDeleted,
+        "ST. MAARTEN, SABA, ST. EUSTATIUS",
+        "PJ7",
+        &[NA],
+        &[8],
+        &[11],
+    )
+    .deleted(2010, 10, 9),
+    entity(
+        DxccEntityCode::MadeiraIsland,
+        "MADEIRA IS.",
+        "CT3",
+        &[AF],
+        &[33],
+        &[36],
+    ),
+    entity(DxccEntityCode::Malta, "MALTA", "9H", &[EU], &[15], &[28]),
+    entity(
+        DxccEntityCode::SumatraDeleted,
+        "SUMATRA",
+        "PK4",
+        &[OC],
+        &[28],
+        &[54],
+    )
+    .deleted(1963, 4, 30),
+    entity(
+        DxccEntityCode::Svalbard,
+        "SVALBARD",
+        "JW",
+        &[EU],
+        &[40],
+        &[18],
+    ),
+    entity(DxccEntityCode::Monaco, "MONACO", "3A", &[EU], &[14], &[27]),
+    entity(
+        DxccEntityCode::SwanIslandDeleted,
+        "SWAN IS.",
+        "KS4",
+        &[NA],
+        &[7],
+        &[11],
+    )
+    .deleted(1972, 8, 31),
+    entity(
+        DxccEntityCode::Tajikistan,
+        "TAJIKISTAN",
+        "EY",
+        &[AS],
+        &[17],
+        &[30],
+    ),
+    entity(
+        DxccEntityCode::Netherlands,
+        "NETHERLANDS",
+        "PA",
+        &[EU],
+        &[14],
+        &[27],
+    ),
+    entity(
+        DxccEntityCode::TangierDeleted,
+        "TANGIER",
+        "CN2",
+        &[AF],
+        &[33],
+        &[37],
+    )
+    .deleted(1960, 6, 30),
+    entity(
+        DxccEntityCode::NorthernIreland,
+        "NORTHERN IRELAND",
+        "GI",
+        &[EU],
+        &[14],
+        &[27],
+    ),
+    entity(DxccEntityCode::Norway, "NORWAY", "LA", &[EU], &[14], &[18]),
+    entity(
+        DxccEntityCode::TerritoryOfNewGuineaDeleted,
+        "TERRITORY OF NEW GUINEA",
+        "VK9",
+        &[OC],
+        &[28],
+        &[51],
+    )
+    .deleted(1975, 9, 15),
+    entity(
+        DxccEntityCode::TibetDeleted,
+        "TIBET",
+        "AC4",
+        &[AS],
+        &[23],
+        &[41],
+    )
+    .deleted(1974, 5, 30),
+    entity(DxccEntityCode::Poland, "POLAND", "SP", &[EU], &[15], &[28]),
+    entity(
+        DxccEntityCode::TokelauIsland,
+        "TOKELAU IS.",
+        "ZK3",
+        &[OC],
+        &[31],
+        &[62],
+    ),
+    entity(
+        DxccEntityCode::TriesteDeleted,
+        "TRIESTE",
+        "I1",
+        &[EU],
+        &[15],
+        &[28],
+    )
+    .deleted(1957, 3, 31),
+    entity(
+        DxccEntityCode::Portugal,
+        "PORTUGAL",
+        "CT",
+        &[EU],
+        &[14],
+        &[37],
+    ),
+    entity(
+        DxccEntityCode::TrindadeAndMartimVazIsland,
+        "TRINDADE & MARTIM VAZ IS.",
+        "PY0T",
+        &[SA],
+        &[11],
+        &[15],
+    ),
+    entity(
+        DxccEntityCode::TristanDaCunhaAndGoughIsland,
+        "TRISTAN DA CUNHA & GOUGH I.",
+        "ZD9",
+        &[AF],
+        &[38],
+        &[66],
+    ),
+    entity(
+        DxccEntityCode::Romania,
+        "ROMANIA",
+        "YO",
+        &[EU],
+        &[20],
+        &[28],
+    ),
+    entity(
+        DxccEntityCode::TromelinIsland,
+        "TROMELIN I.",
+        "FT/T",
+        &[AF],
+        &[39],
+        &[53],
+    ),
+    entity(
+        DxccEntityCode::StPierreAndMiquelon,
+        "ST. PIERRE & MIQUELON",
+        "FP",
+        &[NA],
+        &[5],
+        &[9],
+    ),
+    entity(
+        DxccEntityCode::SanMarino,
+        "SAN MARINO",
+        "T7",
+        &[EU],
+        &[15],
+        &[28],
+    ),
+    entity(
+        DxccEntityCode::Scotland,
+        "SCOTLAND",
+        "GM",
+        &[EU],
+        &[14],
+        &[27],
+    ),
+    entity(
+        DxccEntityCode::Turkmenistan,
+        "TURKMENISTAN",
+        "EZ",
+        &[AS],
+        &[17],
+        &[30],
+    ),
+    entity(DxccEntityCode::Spain, "SPAIN", "EA", &[EU], &[14], &[37]),
+    entity(DxccEntityCode::Tuvalu, "TUVALU", "T2", &[OC], &[31], &[65]),
+    entity(
+        DxccEntityCode::UkSovereignBaseAreasOnCyprus,
+        "UK SOVEREIGN BASE AREAS ON CYPRUS",
+        "ZC4",
+        &[AS],
+        &[20],
+        &[39],
+    ),
+    entity(DxccEntityCode::Sweden, "SWEDEN", "SM", &[EU], &[14], &[18]),
+    entity(
+        DxccEntityCode::VirginIsland,
+        "VIRGIN IS.",
+        "KP2",
+        &[NA],
+        &[8],
+        &[11],
+    ),
+    entity(DxccEntityCode::Uganda, "UGANDA", "5X", &[AF], &[37], &[48]),
+    entity(
+        DxccEntityCode::Switzerland,
+        "SWITZERLAND",
+        "HB",
+        &[EU],
+        &[14],
+        &[28],
+    ),
+    entity(
+        DxccEntityCode::Ukraine,
+        "UKRAINE",
+        "UR",
+        &[EU],
+        &[16],
+        &[29],
+    ),
+    entity(
+        DxccEntityCode::UnitedNationsHq,
+        "UNITED NATIONS HQ",
+        "4U1U",
+        &[NA],
+        &[5],
+        &[8],
+    ),
+    entity(
+        DxccEntityCode::UnitedStatesOfAmerica,
+        "UNITED STATES OF AMERICA",
+        "K",
+        &[NA],
+        &[3, 4, 5],
+        &[6, 7, 8],
+    ),
+    entity(
+        DxccEntityCode::Uzbekistan,
+        "UZBEKISTAN",
+        "UK",
+        &[AS],
+        &[17],
+        &[30],
+    ),
+    entity(
+        DxccEntityCode::VietNam,
+        "VIET NAM",
+        "3W",
+        &[AS],
+        &[26],
+        &[49],
+    ),
+    entity(DxccEntityCode::Wales, "WALES", "GW", &[EU], &[14], &[27]),
+    entity(
+        DxccEntityCode::Vatican,
+        "VATICAN",
+        "HV",
+        &[EU],
+        &[15],
+        &[28],
+    ),
+    entity(DxccEntityCode::Serbia, "SERBIA", "YU", &[EU], &[15], &[28]),
+    entity(
+        DxccEntityCode::WakeIsland,
+        "WAKE I.",
+        "KH9",
+        &[OC],
+        &[31],
+        &[65],
+    ),
+    entity(
+        DxccEntityCode::WallisAndFutunaIsland,
+        "WALLIS & FUTUNA IS.",
+        "FW",
+        &[OC],
+        &[32],
+        &[62],
+    ),
+    entity(
+        DxccEntityCode::WestMalaysia,
+        "WEST MALAYSIA",
+        "9M2",
+        &[AS],
+        &[28],
+        &[54],
+    ),
+    entity(
+        DxccEntityCode::WKiribatiGilbertIsland,
+        "W. KIRIBATI (GILBERT IS.)",
+        "T30",
+        &[OC],
+        &[31],
+        &[65],
+    ),
+    entity(
+        DxccEntityCode::WesternSahara,
+        "WESTERN SAHARA",
+        "S0",
+        &[AF],
+        &[33],
+        &[46],
+    ),
+    entity(
+        DxccEntityCode::WillisIsland,
+        "WILLIS I.",
+        "VK9W",
+        &[OC],
+        &[30],
+        &[55],
+    ),
+    entity(
+        DxccEntityCode::Bahrain,
+        "BAHRAIN",
+        "A9",
+        &[AS],
+        &[21],
+        &[39],
+    ),
+    entity(
+        DxccEntityCode::Bangladesh,
+        "BANGLADESH",
+        "S2",
+        &[AS],
+        &[22],
+        &[41],
+    ),
+    entity(DxccEntityCode::Bhutan, "BHUTAN", "A5", &[AS], &[22], &[41]),
+    entity(
+        DxccEntityCode::ZanzibarDeleted,
+        "ZANZIBAR",
+        "VQ1",
+        &[AF],
+        &[37],
+        &[53],
+    )
+    .deleted(1974, 5, 31),
+    entity(
+        DxccEntityCode::CostaRica,
+        "COSTA RICA",
+        "TI",
+        &[NA],
+        &[7],
+        &[11],
+    ),
+    entity(
+        DxccEntityCode::Myanmar,
+        "MYANMAR",
+        "XZ",
+        &[AS],
+        &[26],
+        &[49],
+    ),
+    entity(
+        DxccEntityCode::Cambodia,
+        "CAMBODIA",
+        "XU",
+        &[AS],
+        &[26],
+        &[49],
+    ),
+    entity(
+        DxccEntityCode::SriLanka,
+        "SRI LANKA",
+        "4S",
+        &[AS],
+        &[22],
+        &[41],
+    ),
+    entity(
+        DxccEntityCode::China,
+        "CHINA",
+        "BY",
+        &[AS],
+        &[23, 24],
+        &[33, 42, 43, 44],
+    ),
+    entity(
+        DxccEntityCode::HongKong,
+        "HONG KONG",
+        "VR",
+        &[AS],
+        &[24],
+        &[44],
+    ),
+    entity(DxccEntityCode::India, "INDIA", "VU", &[AS], &[22], &[41]),
+    entity(
+        DxccEntityCode::Indonesia,
+        "INDONESIA",
+        "YB",
+        &[OC],
+        &[28],
+        &[51, 54],
+    ),
+    entity(DxccEntityCode::Iran, "IRAN", "EP", &[AS], &[21], &[40]),
+    entity(DxccEntityCode::Iraq, "IRAQ", "YI", &[AS], &[21], &[39]),
+    entity(DxccEntityCode::Israel, "ISRAEL", "4X", &[AS], &[20], &[39]),
+    entity(DxccEntityCode::Japan, "JAPAN", "JA", &[AS], &[25], &[45]),
+    entity(DxccEntityCode::Jordan, "JORDAN", "JY", &[AS], &[20], &[39]),
+    entity(
+        DxccEntityCode::DemocraticPeopleSRepOfKorea,
+        "DEMOCRATIC PEOPLE'S REP. OF KOREA",
+        "P5",
+        &[AS],
+        &[25],
+        &[44],
+    ),
+    entity(
+        DxccEntityCode::BruneiDarussalam,
+        "BRUNEI DARUSSALAM",
+        "V8",
+        &[OC],
+        &[28],
+        &[54],
+    ),
+    entity(DxccEntityCode::Kuwait, "KUWAIT", "9K", &[AS], &[21], &[39]),
+    entity(
+        DxccEntityCode::Lebanon,
+        "LEBANON",
+        "OD",
+        &[AS],
+        &[20],
+        &[39],
+    ),
+    entity(
+        DxccEntityCode::Mongolia,
+        "MONGOLIA",
+        "JT",
+        &[AS],
+        &[23],
+        &[32, 33],
+    ),
+    entity(DxccEntityCode::Nepal, "NEPAL", "9N", &[AS], &[22], &[42]),
+    entity(DxccEntityCode::Oman, "OMAN", "A4", &[AS], &[21], &[39]),
+    entity(
+        DxccEntityCode::Pakistan,
+        "PAKISTAN",
+        "AP",
+        &[AS],
+        &[21],
+        &[41],
+    ),
+    entity(
+        DxccEntityCode::Philippines,
+        "PHILIPPINES",
+        "DU",
+        &[OC],
+        &[27],
+        &[50],
+    ),
+    entity(DxccEntityCode::Qatar, "QATAR", "A7", &[AS], &[21], &[39]),
+    entity(
+        DxccEntityCode::SaudiArabia,
+        "SAUDI ARABIA",
+        "HZ",
+        &[AS],
+        &[21],
+        &[39],
+    ),
+    entity(
+        DxccEntityCode::Seychelles,
+        "SEYCHELLES",
+        "S7",
+        &[AF],
+        &[39],
+        &[53],
+    ),
+    entity(
+        DxccEntityCode::Singapore,
+        "SINGAPORE",
+        "9V",
+        &[AS],
+        &[28],
+        &[54],
+    ),
+    entity(
+        DxccEntityCode::Djibouti,
+        "DJIBOUTI",
+        "J2",
+        &[AF],
+        &[37],
+        &[48],
+    ),
+    entity(DxccEntityCode::Syria, "SYRIA", "YK", &[AS], &[20], &[39]),
+    entity(DxccEntityCode::Taiwan, "TAIWAN", "BV", &[AS], &[24], &[44]),
+    entity(
+        DxccEntityCode::Thailand,
+        "THAILAND",
+        "HS",
+        &[AS],
+        &[26],
+        &[49],
+    ),
+    entity(
+        DxccEntityCode::Turkey,
+        "TURKEY",
+        "TA",
+        &[AS, EU],
+        &[20],
+        &[39],
+    ),
+    entity(
+        DxccEntityCode::UnitedArabEmirates,
+        "UNITED ARAB EMIRATES",
+        "A6",
+        &[AS],
+        &[21],
+        &[39],
+    ),
+    entity(
+        DxccEntityCode::Algeria,
+        "ALGERIA",
+        "7X",
+        &[AF],
+        &[33],
+        &[37],
+    ),
+    entity(DxccEntityCode::Angola, "ANGOLA", "D2", &[AF], &[36], &[52]),
+    entity(
+        DxccEntityCode::Botswana,
+        "BOTSWANA",
+        "A2",
+        &[AF],
+        &[38],
+        &[57],
+    ),
+    entity(
+        DxccEntityCode::Burundi,
+        "BURUNDI",
+        "9U",
+        &[AF],
+        &[36],
+        &[52],
+    ),
+    entity(
+        DxccEntityCode::Cameroon,
+        "CAMEROON",
+        "TJ",
+        &[AF],
+        &[36],
+        &[47],
+    ),
+    entity(
+        DxccEntityCode::CentralAfrica,
+        "CENTRAL AFRICA",
+        "TL",
+        &[AF],
+        &[36],
+        &[47],
+    ),
+    entity(
+        DxccEntityCode::CapeVerde,
+        "CAPE VERDE",
+        "D4",
+        &[AF],
+        &[35],
+        &[46],
+    ),
+    entity(DxccEntityCode::Chad, "CHAD", "TT", &[AF], &[36], &[47]),
+    entity(
+        DxccEntityCode::Comoros,
+        "COMOROS",
+        "D6",
+        &[AF],
+        &[39],
+        &[53],
+    ),
+    entity(
+        DxccEntityCode::RepublicOfTheCongo,
+        "REPUBLIC OF THE CONGO",
+        "TN",
+        &[AF],
+        &[36],
+        &[52],
+    ),
+    entity(
+        DxccEntityCode::DemocraticRepublicOfTheCongo,
+        "DEMOCRATIC REPUBLIC OF THE CONGO",
+        "9Q",
+        &[AF],
+        &[36],
+        &[52],
+    ),
+    entity(DxccEntityCode::Benin, "BENIN", "TY", &[AF], &[35], &[46]),
+    entity(DxccEntityCode::Gabon, "GABON", "TR", &[AF], &[36], &[52]),
+    entity(
+        DxccEntityCode::TheGambia,
+        "THE GAMBIA",
+        "C5",
+        &[AF],
+        &[35],
+        &[46],
+    ),
+    entity(DxccEntityCode::Ghana, "GHANA", "9G", &[AF], &[35], &[46]),
+    entity(
+        DxccEntityCode::CoteDIvoire,
+        "COTE D'IVOIRE",
+        "TU",
+        &[AF],
+        &[35],
+        &[46],
+    ),
+    entity(DxccEntityCode::Kenya, "KENYA", "5Z", &[AF], &[37], &[48]),
+    entity(
+        DxccEntityCode::Lesotho,
+        "LESOTHO",
+        "7P",
+        &[AF],
+        &[38],
+        &[57],
+    ),
+    entity(
+        DxccEntityCode::Liberia,
+        "LIBERIA",
+        "EL",
+        &[AF],
+        &[35],
+        &[46],
+    ),
+    entity(DxccEntityCode::Libya, "LIBYA", "5A", &[AF], &[34], &[38]),
+    entity(
+        DxccEntityCode::Madagascar,
+        "MADAGASCAR",
+        "5R",
+        &[AF],
+        &[39],
+        &[53],
+    ),
+    entity(DxccEntityCode::Malawi, "MALAWI", "7Q", &[AF], &[37], &[53]),
+    entity(DxccEntityCode::Mali, "MALI", "TZ", &[AF], &[35], &[46]),
+    entity(
+        DxccEntityCode::Mauritania,
+        "MAURITANIA",
+        "5T",
+        &[AF],
+        &[35],
+        &[46],
+    ),
+    entity(
+        DxccEntityCode::Morocco,
+        "MOROCCO",
+        "CN",
+        &[AF],
+        &[33],
+        &[37],
+    ),
+    entity(
+        DxccEntityCode::Nigeria,
+        "NIGERIA",
+        "5N",
+        &[AF],
+        &[35],
+        &[46],
+    ),
+    entity(
+        DxccEntityCode::Zimbabwe,
+        "ZIMBABWE",
+        "Z2",
+        &[AF],
+        &[38],
+        &[53],
+    ),
+    entity(
+        DxccEntityCode::ReunionIsland,
+        "REUNION I.",
+        "FR",
+        &[AF],
+        &[39],
+        &[53],
+    ),
+    entity(DxccEntityCode::Rwanda, "RWANDA", "9X", &[AF], &[36], &[52]),
+    entity(
+        DxccEntityCode::Senegal,
+        "SENEGAL",
+        "6W",
+        &[AF],
+        &[35],
+        &[46],
+    ),
+    entity(
+        DxccEntityCode::SierraLeone,
+        "SIERRA LEONE",
+        "9L",
+        &[AF],
+        &[35],
+        &[46],
+    ),
+    entity(
+        DxccEntityCode::RotumaIsland,
+        "ROTUMA I.",
+        "3D2/R",
+        &[OC],
+        &[32],
+        &[56],
+    ),
+    entity(
+        DxccEntityCode::SouthAfrica,
+        "SOUTH AFRICA",
+        "ZS",
+        &[AF],
+        &[38],
+        &[57],
+    ),
+    entity(
+        DxccEntityCode::Namibia,
+        "NAMIBIA",
+        "V5",
+        &[AF],
+        &[38],
+        &[57],
+    ),
+    entity(
+        DxccEntityCode::Sudan,
+        "SUDAN",
+        "ST",
+        &[AF],
+        &[34],
+        &[47, 48],
+    ),
+    entity(
+        DxccEntityCode::Swaziland,
+        "SWAZILAND",
+        "3DA",
+        &[AF],
+        &[38],
+        &[57],
+    ),
+    entity(
+        DxccEntityCode::Tanzania,
+        "TANZANIA",
+        "5H",
+        &[AF],
+        &[37],
+        &[53],
+    ),
+    entity(
+        DxccEntityCode::Tunisia,
+        "TUNISIA",
+        "3V",
+        &[AF],
+        &[33],
+        &[37],
+    ),
+    entity(DxccEntityCode::Egypt, "EGYPT", "SU", &[AF], &[34], &[38]),
+    entity(
+        DxccEntityCode::BurkinaFaso,
+        "BURKINA FASO",
+        "XT",
+        &[AF],
+        &[35],
+        &[46],
+    ),
+    entity(DxccEntityCode::Zambia, "ZAMBIA", "9J", &[AF], &[36], &[53]),
+    entity(DxccEntityCode::Togo, "TOGO", "5V", &[AF], &[35], &[46]),
+    entity(
+        DxccEntityCode::WalvisBayDeleted,
+        "WALVIS BAY",
+        "ZS9",
+        &[AF],
+        &[38],
+        &[57],
+    )
+    .deleted(1994, 2, 28),
+    entity(
+        DxccEntityCode::ConwayReef,
+        "CONWAY REEF",
+        "3D2/C",
+        &[OC],
+        &[32],
+        &[56],
+    ),
+    entity(
+        DxccEntityCode::BanabaIslandOceanIsland,
+        "BANABA I. (OCEAN I.)",
+        "T33",
+        &[OC],
+        &[31],
+        &[65],
+    ),
+    entity(DxccEntityCode::Yemen, "YEMEN", "7O", &[AS], &[21], &[39]).since(1990, 5, 22),
+    entity(
+        DxccEntityCode::PenguinIslandDeleted,
+        "PENGUIN IS.",
+        "ZS0",
+        &[AF],
+        &[38],
+        &[57],
+    )
+    .deleted(1994, 2, 28),
+    entity(
+        DxccEntityCode::Croatia,
+        "CROATIA",
+        "9A",
+        &[EU],
+        &[15],
+        &[28],
+    )
+    .since(1991, 6, 26),
+    entity(
+        DxccEntityCode::Slovenia,
+        "SLOVENIA",
+        "S5",
+        &[EU],
+        &[15],
+        &[28],
+    )
+    .since(1991, 6, 26),
+    entity(
+        DxccEntityCode::BosniaHerzegovina,
+        "BOSNIA-HERZEGOVINA",
+        "E7",
+        &[EU],
+        &[15],
+        &[28],
+    )
+    .since(1991, 10, 15),
+    entity(
+        DxccEntityCode::Macedonia,
+        "MACEDONIA",
+        "Z3",
+        &[EU],
+        &[15],
+        &[28],
+    )
+    .since(1991, 9, 8),
+    entity(
+        DxccEntityCode::CzechRepublic,
+        "CZECH REPUBLIC",
+        "OK",
+        &[EU],
+        &[15],
+        &[28],
+    )
+    .since(1993, 1, 1),
+    entity(
+        DxccEntityCode::SlovakRepublic,
+        "SLOVAK REPUBLIC",
+        "OM",
+        &[EU],
+        &[15],
+        &[28],
+    )
+    .since(1993, 1, 1),
+    entity(
+        DxccEntityCode::PratasIsland,
+        "PRATAS I.",
+        "BV9P",
+        &[AS],
+        &[24],
+        &[44],
+    )
+    .since(1994, 1, 1),
+    entity(
+        DxccEntityCode::ScarboroughReef,
+        "SCARBOROUGH REEF",
+        "BS7H",
+        &[AS],
+        &[27],
+        &[50],
+    )
+    .since(1995, 1, 1),
+    entity(
+        DxccEntityCode::TemotuProvince,
+        "TEMOTU PROVINCE",
+        "H40",
+        &[OC],
+        &[32],
+        &[51],
+    )
+    .since(1998, 4, 1),
+    entity(
+        DxccEntityCode::AustralIsland,
+        "AUSTRAL I.",
+        "FO/A",
+        &[OC],
+        &[32],
+        &[63],
+    )
+    .since(1998, 3, 1),
+    entity(
+        DxccEntityCode::MarquesasIsland,
+        "MARQUESAS IS.",
+        "FO/M",
+        &[OC],
+        &[31],
+        &[63],
+    )
+    .since(1998, 3, 1),
+    entity(
+        DxccEntityCode::Palestine,
+        "PALESTINE",
+        "E4",
+        &[AS],
+        &[20],
+        &[39],
+    )
+    .since(1999, 2, 1),
+    entity(
+        DxccEntityCode::TimorLeste,
+        "TIMOR-LESTE",
+        "4W",
+        &[OC],
+        &[28],
+        &[54],
+    )
+    .since(2000, 3, 1),
+    entity(
+        DxccEntityCode::ChesterfieldIsland,
+        "CHESTERFIELD IS.",
+        "FK/C",
+        &[OC],
+        &[30],
+        &[56],
+    )
+    .since(2000, 3, 23),
+    entity(
+        DxccEntityCode::DucieIsland,
+        "DUCIE I.",
+        "VP6/D",
+        &[OC],
+        &[32],
+        &[63],
+    )
+    .since(2001, 11, 16),
+    entity(
+        DxccEntityCode::Montenegro,
+        "MONTENEGRO",
+        "4O",
+        &[EU],
+        &[15],
+        &[28],
+    )
+    .since(2006, 6, 28),
+    entity(
+        DxccEntityCode::SwainsIsland,
+        "SWAINS I.",
+        "KH8/S",
+        &[OC],
+        &[32],
+        &[62],
+    )
+    .since(2006, 9, 1),
+    entity(
+        DxccEntityCode::SaintBarthelemy,
+        "SAINT BARTHELEMY",
+        "FJ",
+        &[NA],
+        &[8],
+        &[11],
+    )
+    .since(2007, 12, 14),
+    entity(
+        DxccEntityCode::Curacao,
+        "CURACAO",
+        "PJ2",
+        &[SA],
+        &[9],
+        &[11],
+    )
+    .since(2010, 10, 10),
+    entity(
+        DxccEntityCode::StMaarten,
+        "ST MAARTEN",
+        "PJ7",
+        &[NA],
+        &[8],
+        &[11],
+    )
+    .since(2010, 10, 10),
+    entity(
+        DxccEntityCode::SabaAndStEustatius,
+        "SABA & ST. EUSTATIUS",
+        "PJ5",
+        &[NA],
+        &[8],
+        &[11],
+    )
+    .since(2010, 10, 10),
+    entity(
+        DxccEntityCode::Bonaire,
+        "BONAIRE",
+        "PJ4",
+        &[SA],
+        &[9],
+        &[11],
+    )
+    .since(2010, 10, 10),
+    entity(
+        DxccEntityCode::SouthSudanRepublicOf,
+        "SOUTH SUDAN (REPUBLIC OF)",
+        "Z8",
+        &[AF],
+        &[34],
+        &[48],
+    )
+    .since(2011, 7, 14),
+    entity(
+        DxccEntityCode::RepublicOfKosovo,
+        "REPUBLIC OF KOSOVO",
+        "Z6",
+        &[EU],
+        &[15],
+        &[28],
+    )
+    .since(2018, 1, 21),
+];
+
+impl DxccEntityCode {
+    pub fn info(&self) -> &'static DxccEntityInfo {
+        ENTITIES
+            .iter()
+            .find(|info| info.entity == *self)
+            .expect("every DXCC entity has metadata")
+    }
+
+    /// Current entity whose primary prefix is the longest one starting the
+    /// given value. Only the primary prefix of each entity is known here, so
+    /// most callsigns (e.g. `W1AW` or `VA3ABC`) are not matched; resolve them
+    /// with `CtyResolver`.
+    pub fn from_primary_prefix(value: &str) -> Option<Self> {
+        let value = value.trim().to_uppercase();
+        ENTITIES
+            .iter()
+            .filter(|info| !info.deleted && !info.prefix.is_empty())
+            .filter(|info| value.starts_with(info.prefix))
+            .max_by_key(|info| info.prefix.len())
+            .map(|info| info.entity.clone())
+    }
+
+    /// Entity with the given ARRL name, ignoring case; current entities win
+    /// over deleted ones with the same name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        ENTITIES
+            .iter()
+            .filter(|info| info.name.eq_ignore_ascii_case(name))
+            .min_by_key(|info| info.deleted)
+            .map(|info| info.entity.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::AdifData;
+
+    #[test]
+    fn test_table_covers_every_entity() {
+        for code in 0..=522 {
+            if let Ok(entity) = DxccEntityCode::deserialize(&code.to_string()) {
+                assert_eq!(entity.info().entity, entity);
+            }
+        }
+        for info in ENTITIES.iter() {
+            assert_eq!(info.deleted, info.entity.is_deleted());
+            assert_eq!(info.deleted, info.valid_to.is_some());
+            assert!(info.cq_zones.iter().all(|zone| (1..=40).contains(zone)));
+            assert!(info.itu_zones.iter().all(|zone| (1..=90).contains(zone)));
+        }
+    }
+
+    #[test]
+    fn test_current_prefixes_are_unique() {
+        let current = ENTITIES
+            .iter()
+            .filter(|info| !info.deleted && !info.prefix.is_empty())
+            .collect::<Vec<_>>();
+        for info in current.iter() {
+            assert_eq!(
+                current
+                    .iter()
+                    .filter(|other| other.prefix == info.prefix)
+                    .count(),
+                1,
+                "{}",
+                info.prefix
+            );
+        }
+    }
+
+    #[test]
+    fn test_info() {
+        let info = DxccEntityCode::Sardinia.info();
+        assert_eq!(info.name, "SARDINIA");
+        assert_eq!(info.prefix, "IS");
+        assert_eq!(info.continents, &[Continent::Europe]);
+        assert_eq!(info.cq_zones, &[15]);
+        assert_eq!(info.itu_zones, &[28]);
+        assert!(!info.deleted);
+
+        let info = DxccEntityCode::CzechoslovakiaDeleted.info();
+        assert!(info.deleted);
+        assert_eq!(info.valid_to, NaiveDate::from_ymd_opt(1992, 12, 31));
+        assert!(info.is_valid_on(&NaiveDate::from_ymd_opt(1980, 1, 1).unwrap()));
+        assert!(!info.is_valid_on(&NaiveDate::from_ymd_opt(1993, 1, 1).unwrap()));
+
+        let info = DxccEntityCode::RepublicOfKosovo.info();
+        assert!(!info.is_valid_on(&NaiveDate::from_ymd_opt(2018, 1, 20).unwrap()));
+        assert!(info.is_valid_on(&NaiveDate::from_ymd_opt(2018, 1, 21).unwrap()));
+    }
+
+    #[test]
+    fn test_from_primary_prefix() {
+        assert_eq!(
+            DxccEntityCode::from_primary_prefix("IS0GVH"),
+            Some(DxccEntityCode::Sardinia)
+        );
+        assert_eq!(
+            DxccEntityCode::from_primary_prefix("i2abc"),
+            Some(DxccEntityCode::Italy)
+        );
+        assert_eq!(
+            DxccEntityCode::from_primary_prefix("KH6"),
+            Some(DxccEntityCode::Hawaii)
+        );
+        assert_eq!(
+            DxccEntityCode::from_primary_prefix("K1ABC"),
+            Some(DxccEntityCode::UnitedStatesOfAmerica)
+        );
+        assert_eq!(
+            DxccEntityCode::from_primary_prefix("DL1ABC"),
+            Some(DxccEntityCode::FederalRepublicOfGermany)
+        );
+        assert_eq!(DxccEntityCode::from_primary_prefix("QQ1"), None);
+        assert_eq!(DxccEntityCode::from_primary_prefix("W1AW"), None);
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(
+            DxccEntityCode::from_name("sardinia"),
+            Some(DxccEntityCode::Sardinia)
+        );
+        assert_eq!(
+            DxccEntityCode::from_name("Palestine"),
+            Some(DxccEntityCode::Palestine)
+        );
+        assert_eq!(
+            DxccEntityCode::from_name("ABU AIL IS."),
+            Some(DxccEntityCode::AbuAilIslandDeleted)
+        );
+        assert_eq!(DxccEntityCode::from_name("Atlantis"), None);
+    }
+}
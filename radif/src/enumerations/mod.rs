@@ -25,6 +25,7 @@ use crate::enumerations::credit::Credit;
 use crate::enumerations::dxcc_enity_code::DxccEntityCode;
 use crate::enumerations::mode::Mode;
 use crate::enumerations::morse_key_type::MorseKeyType;
+use crate::enumerations::primary_administrative_subdivision::PrimaryAdministrativeSubdivision;
 use crate::enumerations::propagation_mode::PropagationMode;
 use crate::enumerations::qsl_medium::QslMedium;
 use crate::enumerations::qsl_rcvd::QslRcvd;
@@ -46,8 +47,11 @@ pub mod contest_id;
 pub mod continent;
 pub mod credit;
 pub mod dxcc_enity_code;
+pub mod dxcc_entity_info;
 pub mod mode;
+pub mod mode_category;
 pub mod morse_key_type;
+pub mod primary_administrative_subdivision;
 pub mod propagation_mode;
 pub mod qsl_medium;
 pub mod qsl_rcvd;
@@ -59,6 +63,7 @@ pub mod region;
 pub mod submode;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EnumerationType {
     AntPath,
     ArrlSection,
@@ -70,6 +75,7 @@ pub enum EnumerationType {
     DxccEntityCode,
     Mode,
     MorseKeyType,
+    PrimaryAdministrativeSubdivision,
     PropagationMode,
     QslMedium,
     QslRcvd,
@@ -82,6 +88,7 @@ pub enum EnumerationType {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Enumeration {
     AntPath(AntPath),
     ArrlSection(ArrlSection),
@@ -93,6 +100,7 @@ pub enum Enumeration {
     DxccEntityCode(DxccEntityCode),
     Mode(Mode),
     MorseKeyType(MorseKeyType),
+    PrimaryAdministrativeSubdivision(PrimaryAdministrativeSubdivision),
     PropagationMode(PropagationMode),
     QslMedium(QslMedium),
     QslRcvd(QslRcvd),
@@ -123,6 +131,10 @@ impl Enumeration {
             EnumerationType::MorseKeyType => {
                 MorseKeyType::deserialize(value).map(Enumeration::MorseKeyType)
             }
+            EnumerationType::PrimaryAdministrativeSubdivision => {
+                PrimaryAdministrativeSubdivision::deserialize(value)
+                    .map(Enumeration::PrimaryAdministrativeSubdivision)
+            }
             EnumerationType::PropagationMode => {
                 PropagationMode::deserialize(value).map(Enumeration::PropagationMode)
             }
@@ -145,25 +157,26 @@ impl Enumeration {
 impl Display for Enumeration {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Enumeration::AntPath(e) => write!(f, "{}", e),
-            Enumeration::ArrlSection(e) => write!(f, "{}", e),
-            Enumeration::Award(e) => write!(f, "{}", e),
-            Enumeration::Band(e) => write!(f, "{}", e),
-            Enumeration::ContestId(e) => write!(f, "{}", e),
-            Enumeration::Continent(e) => write!(f, "{}", e),
-            Enumeration::Credit(e) => write!(f, "{}", e),
-            Enumeration::DxccEntityCode(e) => write!(f, "{}", e),
-            Enumeration::Mode(e) => write!(f, "{}", e),
-            Enumeration::MorseKeyType(e) => write!(f, "{}", e),
-            Enumeration::PropagationMode(e) => write!(f, "{}", e),
-            Enumeration::QslMedium(e) => write!(f, "{}", e),
-            Enumeration::QslRcvd(e) => write!(f, "{}", e),
-            Enumeration::QslSent(e) => write!(f, "{}", e),
-            Enumeration::QslVia(e) => write!(f, "{}", e),
-            Enumeration::QsoComplete(e) => write!(f, "{}", e),
-            Enumeration::QsoUploadStatus(e) => write!(f, "{}", e),
-            Enumeration::Region(e) => write!(f, "{}", e),
-            Enumeration::SubMode(e) => write!(f, "{}", e),
+            Enumeration::AntPath(e) => write!(f, "{}", e.serialize()),
+            Enumeration::ArrlSection(e) => write!(f, "{}", e.serialize()),
+            Enumeration::Award(e) => write!(f, "{}", e.serialize()),
+            Enumeration::Band(e) => write!(f, "{}", e.serialize()),
+            Enumeration::ContestId(e) => write!(f, "{}", e.serialize()),
+            Enumeration::Continent(e) => write!(f, "{}", e.serialize()),
+            Enumeration::Credit(e) => write!(f, "{}", e.serialize()),
+            Enumeration::DxccEntityCode(e) => write!(f, "{}", e.serialize()),
+            Enumeration::Mode(e) => write!(f, "{}", e.serialize()),
+            Enumeration::MorseKeyType(e) => write!(f, "{}", e.serialize()),
+            Enumeration::PrimaryAdministrativeSubdivision(e) => write!(f, "{}", e.serialize()),
+            Enumeration::PropagationMode(e) => write!(f, "{}", e.serialize()),
+            Enumeration::QslMedium(e) => write!(f, "{}", e.serialize()),
+            Enumeration::QslRcvd(e) => write!(f, "{}", e.serialize()),
+            Enumeration::QslSent(e) => write!(f, "{}", e.serialize()),
+            Enumeration::QslVia(e) => write!(f, "{}", e.serialize()),
+            Enumeration::QsoComplete(e) => write!(f, "{}", e.serialize()),
+            Enumeration::QsoUploadStatus(e) => write!(f, "{}", e.serialize()),
+            Enumeration::Region(e) => write!(f, "{}", e.serialize()),
+            Enumeration::SubMode(e) => write!(f, "{}", e.serialize()),
         }
     }
 }
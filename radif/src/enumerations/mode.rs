@@ -16,10 +16,12 @@
  */
 
 use crate::data::AdifData;
+use crate::enumerations::submode::{SubMode, MODE_SUBMODES};
 use crate::error::AdifError;
 use radif_macros::{AdifData, AutoDisplay, AutoTestEnum};
 
 #[derive(Debug, Clone, PartialEq, Eq, AutoDisplay, AdifData, AutoTestEnum)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
 pub enum Mode {
     #[adif("AM")]
     AM,
@@ -113,88 +115,152 @@ pub enum Mode {
     WINMOR,
     #[adif("WSPR")]
     WSPR,
-    #[adif("AMTORFEC")]
+    #[adif("AMTORFEC", import_only)]
     AMTORFEC,
-    #[adif("ASCI")]
+    #[adif("ASCI", import_only)]
     ASCI,
-    #[adif("C4FM")]
+    #[adif("C4FM", import_only)]
     C4FM,
-    #[adif("CHIP64")]
+    #[adif("CHIP64", import_only)]
     CHIP64,
-    #[adif("CHIP128")]
+    #[adif("CHIP128", import_only)]
     CHIP128,
-    #[adif("DOMINOF")]
+    #[adif("DOMINOF", import_only)]
     DOMINOF,
-    #[adif("DSTAR")]
+    #[adif("DSTAR", import_only)]
     DSTAR,
-    #[adif("FMHELL")]
+    #[adif("FMHELL", import_only)]
     FMHELL,
-    #[adif("FSK31")]
+    #[adif("FSK31", import_only)]
     FSK31,
-    #[adif("GTOR")]
+    #[adif("GTOR", import_only)]
     GTOR,
-    #[adif("HELL80")]
+    #[adif("HELL80", import_only)]
     HELL80,
-    #[adif("HFSK")]
+    #[adif("HFSK", import_only)]
     HFSK,
-    #[adif("JT4A")]
+    #[adif("JT4A", import_only)]
     JT4A,
-    #[adif("JT4B")]
+    #[adif("JT4B", import_only)]
     JT4B,
-    #[adif("JT4C")]
+    #[adif("JT4C", import_only)]
     JT4C,
-    #[adif("JT4D")]
+    #[adif("JT4D", import_only)]
     JT4D,
-    #[adif("JT4E")]
+    #[adif("JT4E", import_only)]
     JT4E,
-    #[adif("JT4F")]
+    #[adif("JT4F", import_only)]
     JT4F,
-    #[adif("JT4G")]
+    #[adif("JT4G", import_only)]
     JT4G,
-    #[adif("JT65A")]
+    #[adif("JT65A", import_only)]
     JT65A,
-    #[adif("JT65B")]
+    #[adif("JT65B", import_only)]
     JT65B,
-    #[adif("JT65C")]
+    #[adif("JT65C", import_only)]
     JT65C,
-    #[adif("MFSK8")]
+    #[adif("MFSK8", import_only)]
     MFSK8,
-    #[adif("MFSK16")]
+    #[adif("MFSK16", import_only)]
     MFSK16,
-    #[adif("PAC2")]
+    #[adif("PAC2", import_only)]
     PAC2,
-    #[adif("PAC3")]
+    #[adif("PAC3", import_only)]
     PAC3,
-    #[adif("PAX2")]
+    #[adif("PAX2", import_only)]
     PAX2,
-    #[adif("PCW")]
+    #[adif("PCW", import_only)]
     PCW,
-    #[adif("PSK10")]
+    #[adif("PSK10", import_only)]
     PSK10,
-    #[adif("PSK31")]
+    #[adif("PSK31", import_only)]
     PSK31,
-    #[adif("PSK63")]
+    #[adif("PSK63", import_only)]
     PSK63,
-    #[adif("PSK63F")]
+    #[adif("PSK63F", import_only)]
     PSK63F,
-    #[adif("PSK125")]
+    #[adif("PSK125", import_only)]
     PSK125,
-    #[adif("PSKAM10")]
+    #[adif("PSKAM10", import_only)]
     PSKAM10,
-    #[adif("PSKAM31")]
+    #[adif("PSKAM31", import_only)]
     PSKAM31,
-    #[adif("PSKAM50")]
+    #[adif("PSKAM50", import_only)]
     PSKAM50,
-    #[adif("PSKFEC31")]
+    #[adif("PSKFEC31", import_only)]
     PSKFEC31,
-    #[adif("PSKHELL")]
+    #[adif("PSKHELL", import_only)]
     PSKHELL,
-    #[adif("QPSK31")]
+    #[adif("QPSK31", import_only)]
     QPSK31,
-    #[adif("QPSK63")]
+    #[adif("QPSK63", import_only)]
     QPSK63,
-    #[adif("QPSK125")]
+    #[adif("QPSK125", import_only)]
     QPSK125,
-    #[adif("THRBX")]
+    #[adif("THRBX", import_only)]
     THRBX,
 }
+
+impl Mode {
+    /// Submodes belonging to this mode, empty when it has none.
+    pub fn submodes(&self) -> &'static [SubMode] {
+        MODE_SUBMODES
+            .iter()
+            .find(|(mode, _)| mode == self)
+            .map(|(_, submodes)| *submodes)
+            .unwrap_or_default()
+    }
+
+    /// Current `MODE` and `SUBMODE` pair replacing an import-only mode.
+    pub fn upgrade(&self) -> Option<(Mode, SubMode)> {
+        if !self.is_import_only() {
+            return None;
+        }
+        SubMode::deserialize(&self.serialize())
+            .ok()
+            .map(|submode| (submode.parent_mode(), submode))
+    }
+}
+
+#[cfg(test)]
+mod hierarchy_tests {
+    use super::*;
+
+    #[test]
+    fn test_submodes() {
+        assert_eq!(Mode::SSB.submodes(), &[SubMode::LSB, SubMode::USB]);
+        assert!(Mode::MFSK.submodes().contains(&SubMode::FT4));
+        assert!(Mode::FT8.submodes().is_empty());
+    }
+
+    #[test]
+    fn test_import_only() {
+        assert!(Mode::PSK31.is_import_only());
+        assert!(!Mode::PSK.is_import_only());
+        assert!(!Mode::PSK.is_deleted());
+    }
+
+    #[test]
+    fn test_upgrade() {
+        assert_eq!(Mode::PSK31.upgrade(), Some((Mode::PSK, SubMode::PSK31)));
+        assert_eq!(
+            Mode::C4FM.upgrade(),
+            Some((Mode::DIGITALVOICE, SubMode::C4FM))
+        );
+        assert_eq!(Mode::JT65A.upgrade(), Some((Mode::JT65, SubMode::JT65A)));
+        assert_eq!(Mode::FT8.upgrade(), None);
+    }
+
+    #[test]
+    fn test_every_import_only_mode_upgrades() {
+        for value in MODE_SUBMODES
+            .iter()
+            .flat_map(|(_, submodes)| submodes.iter())
+        {
+            if let Ok(mode) = Mode::deserialize(&value.serialize()) {
+                assert!(mode.is_import_only());
+                assert!(mode.upgrade().is_some());
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::enumerations::mode::Mode;
+use crate::enumerations::submode::SubMode;
+use radif_macros::AutoDisplay;
+
+/// Mode groups used by award and contest rules to split credit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, AutoDisplay)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ModeCategory {
+    Cw,
+    Phone,
+    Digital,
+}
+
+impl Mode {
+    /// Category of the mode as counted by ARRL awards, where image modes
+    /// and digital voice are phone.
+    pub fn category(&self) -> ModeCategory {
+        match self {
+            Mode::CW | Mode::PCW => ModeCategory::Cw,
+            Mode::AM
+            | Mode::ATV
+            | Mode::C4FM
+            | Mode::DIGITALVOICE
+            | Mode::DSTAR
+            | Mode::FAX
+            | Mode::FM
+            | Mode::SSB
+            | Mode::SSTV => ModeCategory::Phone,
+            _ => ModeCategory::Digital,
+        }
+    }
+}
+
+impl SubMode {
+    pub fn category(&self) -> ModeCategory {
+        self.parent_mode().category()
+    }
+}
+
+/// Sponsor-specific overrides of the default mode categories; a submode
+/// entry wins over a mode entry.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ModeCategoryMapping {
+    pub modes: Vec<(Mode, ModeCategory)>,
+    pub submodes: Vec<(SubMode, ModeCategory)>,
+}
+
+impl ModeCategoryMapping {
+    pub fn category(&self, mode: &Mode, submode: Option<&SubMode>) -> ModeCategory {
+        submode
+            .and_then(|submode| {
+                self.submodes
+                    .iter()
+                    .find(|(item, _)| item == submode)
+                    .map(|(_, category)| *category)
+            })
+            .or_else(|| {
+                self.modes
+                    .iter()
+                    .find(|(item, _)| item == mode)
+                    .map(|(_, category)| *category)
+            })
+            .unwrap_or_else(|| mode.category())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_category() {
+        assert_eq!(Mode::CW.category(), ModeCategory::Cw);
+        assert_eq!(Mode::SSB.category(), ModeCategory::Phone);
+        assert_eq!(Mode::SSTV.category(), ModeCategory::Phone);
+        assert_eq!(Mode::DIGITALVOICE.category(), ModeCategory::Phone);
+        assert_eq!(Mode::FT8.category(), ModeCategory::Digital);
+        assert_eq!(Mode::RTTY.category(), ModeCategory::Digital);
+    }
+
+    #[test]
+    fn test_submode_category() {
+        assert_eq!(SubMode::USB.category(), ModeCategory::Phone);
+        assert_eq!(SubMode::FT4.category(), ModeCategory::Digital);
+        assert_eq!(SubMode::DMR.category(), ModeCategory::Phone);
+    }
+
+    #[test]
+    fn test_mapping() {
+        let mapping = ModeCategoryMapping {
+            modes: vec![(Mode::DIGITALVOICE, ModeCategory::Digital)],
+            submodes: vec![(SubMode::FREEDV, ModeCategory::Phone)],
+        };
+        assert_eq!(
+            mapping.category(&Mode::DIGITALVOICE, None),
+            ModeCategory::Digital
+        );
+        assert_eq!(
+            mapping.category(&Mode::DIGITALVOICE, Some(&SubMode::DMR)),
+            ModeCategory::Digital
+        );
+        assert_eq!(
+            mapping.category(&Mode::DIGITALVOICE, Some(&SubMode::FREEDV)),
+            ModeCategory::Phone
+        );
+        assert_eq!(mapping.category(&Mode::CW, None), ModeCategory::Cw);
+    }
+}
@@ -0,0 +1,587 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use crate::data::AdifData;
+use crate::enumerations::dxcc_enity_code::DxccEntityCode;
+use crate::error::AdifError::DeserializeError;
+use crate::result;
+use std::fmt::{Display, Formatter};
+
+const ALASKA: [(&str, &str); 1] = [("AK", "Alaska")];
+
+const ARGENTINA: [(&str, &str); 24] = [
+    ("C", "Capital federal"),
+    ("B", "Buenos Aires"),
+    ("S", "Santa Fe"),
+    ("H", "Chaco"),
+    ("P", "Formosa"),
+    ("X", "Córdoba"),
+    ("N", "Misiones"),
+    ("E", "Entre Ríos"),
+    ("T", "Tucumán"),
+    ("W", "Corrientes"),
+    ("M", "Mendoza"),
+    ("G", "Santiago del Estero"),
+    ("A", "Salta"),
+    ("J", "San Juan"),
+    ("D", "San Luis"),
+    ("K", "Catamarca"),
+    ("F", "La Rioja"),
+    ("Y", "Jujuy"),
+    ("L", "La Pampa"),
+    ("R", "Río Negro"),
+    ("U", "Chubut"),
+    ("Z", "Santa Cruz"),
+    ("V", "Tierra del Fuego"),
+    ("Q", "Neuquén"),
+];
+
+const BALEARIC_ISLANDS: [(&str, &str); 1] = [("IB", "Baleares")];
+
+const BRAZIL: [(&str, &str); 27] = [
+    ("ES", "Espírito Santo"),
+    ("GO", "Goiás"),
+    ("SC", "Santa Catarina"),
+    ("SE", "Sergipe"),
+    ("AL", "Alagoas"),
+    ("AM", "Amazonas"),
+    ("TO", "Tocantins"),
+    ("AP", "Amapá"),
+    ("PB", "Paraíba"),
+    ("MA", "Maranhão"),
+    ("RN", "Rio Grande do Norte"),
+    ("PI", "Piauí"),
+    ("DF", "Distrito Federal"),
+    ("CE", "Ceará"),
+    ("AC", "Acre"),
+    ("MS", "Mato Grosso do Sul"),
+    ("RR", "Roraima"),
+    ("RO", "Rondônia"),
+    ("RJ", "Rio de Janeiro"),
+    ("SP", "São Paulo"),
+    ("RS", "Rio Grande do Sul"),
+    ("MG", "Minas Gerais"),
+    ("PR", "Paraná"),
+    ("BA", "Bahia"),
+    ("PE", "Pernambuco"),
+    ("PA", "Pará"),
+    ("MT", "Mato Grosso"),
+];
+
+const CANADA: [(&str, &str); 13] = [
+    ("NS", "Nova Scotia"),
+    ("QC", "Québec"),
+    ("ON", "Ontario"),
+    ("MB", "Manitoba"),
+    ("SK", "Saskatchewan"),
+    ("AB", "Alberta"),
+    ("BC", "British Columbia"),
+    ("NT", "Northwest Territories"),
+    ("NB", "New Brunswick"),
+    ("NL", "Newfoundland and Labrador"),
+    ("YT", "Yukon"),
+    ("PE", "Prince Edward Island"),
+    ("NU", "Nunavut"),
+];
+
+const CANARY_ISLANDS: [(&str, &str); 2] = [("GC", "Las Palmas"), ("TF", "Tenerife")];
+
+const CEUTA_AND_MELILLA: [(&str, &str); 2] = [("CE", "Ceuta"), ("ML", "Melilla")];
+
+const CHINA: [(&str, &str); 31] = [
+    ("AH", "Anhui"),
+    ("BJ", "Beijing"),
+    ("CQ", "Chongqing"),
+    ("FJ", "Fujian"),
+    ("GD", "Guangdong"),
+    ("GS", "Gansu"),
+    ("GX", "Guangxi"),
+    ("GZ", "Guizhou"),
+    ("HA", "Henan"),
+    ("HB", "Hubei"),
+    ("HE", "Hebei"),
+    ("HI", "Hainan"),
+    ("HL", "Heilongjiang"),
+    ("HN", "Hunan"),
+    ("JL", "Jilin"),
+    ("JS", "Jiangsu"),
+    ("JX", "Jiangxi"),
+    ("LN", "Liaoning"),
+    ("NM", "Nei Mongol"),
+    ("NX", "Ningxia"),
+    ("QH", "Qinghai"),
+    ("SC", "Sichuan"),
+    ("SD", "Shandong"),
+    ("SH", "Shanghai"),
+    ("SN", "Shaanxi"),
+    ("SX", "Shanxi"),
+    ("TJ", "Tianjin"),
+    ("XJ", "Xinjiang"),
+    ("XZ", "Xizang"),
+    ("YN", "Yunnan"),
+    ("ZJ", "Zhejiang"),
+];
+
+const GERMANY: [(&str, &str); 16] = [
+    ("BB", "Brandenburg"),
+    ("BE", "Berlin"),
+    ("BW", "Baden-Württemberg"),
+    ("BY", "Bayern"),
+    ("HB", "Bremen"),
+    ("HE", "Hessen"),
+    ("HH", "Hamburg"),
+    ("MV", "Mecklenburg-Vorpommern"),
+    ("NI", "Niedersachsen"),
+    ("NW", "Nordrhein-Westfalen"),
+    ("RP", "Rheinland-Pfalz"),
+    ("SL", "Saarland"),
+    ("SH", "Schleswig-Holstein"),
+    ("SN", "Sachsen"),
+    ("ST", "Sachsen-Anhalt"),
+    ("TH", "Thüringen"),
+];
+
+const HAWAII: [(&str, &str); 1] = [("HI", "Hawaii")];
+
+const ITALY: [(&str, &str); 101] = [
+    ("GE", "Genova"),
+    ("IM", "Imperia"),
+    ("SP", "La Spezia"),
+    ("SV", "Savona"),
+    ("AL", "Alessandria"),
+    ("AT", "Asti"),
+    ("BI", "Biella"),
+    ("CN", "Cuneo"),
+    ("NO", "Novara"),
+    ("TO", "Torino"),
+    ("VB", "Verbano Cusio Ossola"),
+    ("VC", "Vercelli"),
+    ("AO", "Aosta"),
+    ("BG", "Bergamo"),
+    ("BS", "Brescia"),
+    ("CO", "Como"),
+    ("CR", "Cremona"),
+    ("LC", "Lecco"),
+    ("LO", "Lodi"),
+    ("MB", "Monza e Brianza"),
+    ("MN", "Mantova"),
+    ("MI", "Milano"),
+    ("PV", "Pavia"),
+    ("SO", "Sondrio"),
+    ("VA", "Varese"),
+    ("BL", "Belluno"),
+    ("PD", "Padova"),
+    ("RO", "Rovigo"),
+    ("TV", "Treviso"),
+    ("VE", "Venezia"),
+    ("VR", "Verona"),
+    ("VI", "Vicenza"),
+    ("BZ", "Bolzano"),
+    ("TN", "Trento"),
+    ("GO", "Gorizia"),
+    ("PN", "Pordenone"),
+    ("TS", "Trieste"),
+    ("UD", "Udine"),
+    ("BO", "Bologna"),
+    ("FE", "Ferrara"),
+    ("FC", "Forlì-Cesena"),
+    ("MO", "Modena"),
+    ("PR", "Parma"),
+    ("PC", "Piacenza"),
+    ("RA", "Ravenna"),
+    ("RE", "Reggio Emilia"),
+    ("RN", "Rimini"),
+    ("AR", "Arezzo"),
+    ("FI", "Firenze"),
+    ("GR", "Grosseto"),
+    ("LI", "Livorno"),
+    ("LU", "Lucca"),
+    ("MS", "Massa Carrara"),
+    ("PT", "Pistoia"),
+    ("PI", "Pisa"),
+    ("PO", "Prato"),
+    ("SI", "Siena"),
+    ("CH", "Chieti"),
+    ("AQ", "L'Aquila"),
+    ("PE", "Pescara"),
+    ("TE", "Teramo"),
+    ("AN", "Ancona"),
+    ("AP", "Ascoli Piceno"),
+    ("FM", "Fermo"),
+    ("MC", "Macerata"),
+    ("PU", "Pesaro e Urbino"),
+    ("MT", "Matera"),
+    ("BA", "Bari"),
+    ("BT", "Barletta-Andria-Trani"),
+    ("BR", "Brindisi"),
+    ("FG", "Foggia"),
+    ("LE", "Lecce"),
+    ("TA", "Taranto"),
+    ("PZ", "Potenza"),
+    ("CZ", "Catanzaro"),
+    ("CS", "Cosenza"),
+    ("KR", "Crotone"),
+    ("RC", "Reggio Calabria"),
+    ("VV", "Vibo Valentia"),
+    ("AV", "Avellino"),
+    ("BN", "Benevento"),
+    ("CE", "Caserta"),
+    ("NA", "Napoli"),
+    ("SA", "Salerno"),
+    ("CB", "Campobasso"),
+    ("IS", "Isernia"),
+    ("FR", "Frosinone"),
+    ("LT", "Latina"),
+    ("RI", "Rieti"),
+    ("RM", "Roma"),
+    ("VT", "Viterbo"),
+    ("PG", "Perugia"),
+    ("TR", "Terni"),
+    ("AG", "Agrigento"),
+    ("CL", "Caltanissetta"),
+    ("CT", "Catania"),
+    ("EN", "Enna"),
+    ("ME", "Messina"),
+    ("PA", "Palermo"),
+    ("RG", "Ragusa"),
+    ("SR", "Siracusa"),
+];
+
+const JAPAN: [(&str, &str); 47] = [
+    ("01", "Hokkaido"),
+    ("02", "Aomori"),
+    ("03", "Iwate"),
+    ("04", "Akita"),
+    ("05", "Yamagata"),
+    ("06", "Miyagi"),
+    ("07", "Fukushima"),
+    ("08", "Niigata"),
+    ("09", "Nagano"),
+    ("10", "Tokyo"),
+    ("11", "Kanagawa"),
+    ("12", "Chiba"),
+    ("13", "Saitama"),
+    ("14", "Ibaraki"),
+    ("15", "Tochigi"),
+    ("16", "Gunma"),
+    ("17", "Yamanashi"),
+    ("18", "Shizuoka"),
+    ("19", "Gifu"),
+    ("20", "Aichi"),
+    ("21", "Mie"),
+    ("22", "Kyoto"),
+    ("23", "Shiga"),
+    ("24", "Nara"),
+    ("25", "Osaka"),
+    ("26", "Wakayama"),
+    ("27", "Hyogo"),
+    ("28", "Toyama"),
+    ("29", "Fukui"),
+    ("30", "Ishikawa"),
+    ("31", "Okayama"),
+    ("32", "Shimane"),
+    ("33", "Yamaguchi"),
+    ("34", "Tottori"),
+    ("35", "Hiroshima"),
+    ("36", "Kagawa"),
+    ("37", "Tokushima"),
+    ("38", "Ehime"),
+    ("39", "Kochi"),
+    ("40", "Fukuoka"),
+    ("41", "Saga"),
+    ("42", "Nagasaki"),
+    ("43", "Kumamoto"),
+    ("44", "Oita"),
+    ("45", "Miyazaki"),
+    ("46", "Kagoshima"),
+    ("47", "Okinawa"),
+];
+
+const SARDINIA: [(&str, &str); 9] = [
+    ("CA", "Cagliari"),
+    ("CI", "Carbonia-Iglesias"),
+    ("NU", "Nuoro"),
+    ("OG", "Ogliastra"),
+    ("OR", "Oristano"),
+    ("OT", "Olbia-Tempio"),
+    ("SS", "Sassari"),
+    ("SU", "Sud Sardegna"),
+    ("VS", "Medio Campidano"),
+];
+
+const SPAIN: [(&str, &str); 47] = [
+    ("AV", "Ávila"),
+    ("BU", "Burgos"),
+    ("C", "A Coruña"),
+    ("LE", "León"),
+    ("LO", "La Rioja"),
+    ("LU", "Lugo"),
+    ("O", "Asturias"),
+    ("OU", "Ourense"),
+    ("P", "Palencia"),
+    ("PO", "Pontevedra"),
+    ("S", "Cantabria"),
+    ("SA", "Salamanca"),
+    ("SG", "Segovia"),
+    ("SO", "Soria"),
+    ("VA", "Valladolid"),
+    ("ZA", "Zamora"),
+    ("BI", "Vizcaya"),
+    ("SS", "Guipúzcoa"),
+    ("VI", "Álava"),
+    ("NA", "Navarra"),
+    ("HU", "Huesca"),
+    ("TE", "Teruel"),
+    ("Z", "Zaragoza"),
+    ("B", "Barcelona"),
+    ("GI", "Girona"),
+    ("L", "Lleida"),
+    ("T", "Tarragona"),
+    ("BA", "Badajoz"),
+    ("CC", "Cáceres"),
+    ("CR", "Ciudad Real"),
+    ("CU", "Cuenca"),
+    ("GU", "Guadalajara"),
+    ("M", "Madrid"),
+    ("TO", "Toledo"),
+    ("A", "Alicante"),
+    ("AB", "Albacete"),
+    ("CS", "Castellón"),
+    ("MU", "Murcia"),
+    ("V", "Valencia"),
+    ("AL", "Almería"),
+    ("CA", "Cádiz"),
+    ("CO", "Córdoba"),
+    ("GR", "Granada"),
+    ("H", "Huelva"),
+    ("J", "Jaén"),
+    ("MA", "Málaga"),
+    ("SE", "Sevilla"),
+];
+
+const UNITED_STATES: [(&str, &str); 49] = [
+    ("AL", "Alabama"),
+    ("AZ", "Arizona"),
+    ("AR", "Arkansas"),
+    ("CA", "California"),
+    ("CO", "Colorado"),
+    ("CT", "Connecticut"),
+    ("DE", "Delaware"),
+    ("DC", "District of Columbia"),
+    ("FL", "Florida"),
+    ("GA", "Georgia"),
+    ("ID", "Idaho"),
+    ("IL", "Illinois"),
+    ("IN", "Indiana"),
+    ("IA", "Iowa"),
+    ("KS", "Kansas"),
+    ("KY", "Kentucky"),
+    ("LA", "Louisiana"),
+    ("ME", "Maine"),
+    ("MD", "Maryland"),
+    ("MA", "Massachusetts"),
+    ("MI", "Michigan"),
+    ("MN", "Minnesota"),
+    ("MS", "Mississippi"),
+    ("MO", "Missouri"),
+    ("MT", "Montana"),
+    ("NE", "Nebraska"),
+    ("NV", "Nevada"),
+    ("NH", "New Hampshire"),
+    ("NJ", "New Jersey"),
+    ("NM", "New Mexico"),
+    ("NY", "New York"),
+    ("NC", "North Carolina"),
+    ("ND", "North Dakota"),
+    ("OH", "Ohio"),
+    ("OK", "Oklahoma"),
+    ("OR", "Oregon"),
+    ("PA", "Pennsylvania"),
+    ("RI", "Rhode Island"),
+    ("SC", "South Carolina"),
+    ("SD", "South Dakota"),
+    ("TN", "Tennessee"),
+    ("TX", "Texas"),
+    ("UT", "Utah"),
+    ("VT", "Vermont"),
+    ("VA", "Virginia"),
+    ("WA", "Washington"),
+    ("WV", "West Virginia"),
+    ("WI", "Wisconsin"),
+    ("WY", "Wyoming"),
+];
+
+/// Codes and names of the subdivisions of each DXCC entity with a table:
+/// Alaska, Argentina, Balearic Is., Brazil, Canada, Canary Is., Ceuta &
+/// Melilla, China, Federal Republic of Germany, Hawaii, Italy, Japan,
+/// Sardinia, Spain and the United States. The other entities listed by the
+/// ADIF specification (Russia, Poland, Ukraine, ...) are not covered yet.
+const SUBDIVISIONS: [(DxccEntityCode, &[(&str, &str)]); 15] = [
+    (DxccEntityCode::Canada, &CANADA),
+    (DxccEntityCode::Alaska, &ALASKA),
+    (DxccEntityCode::Hawaii, &HAWAII),
+    (DxccEntityCode::Sardinia, &SARDINIA),
+    (DxccEntityCode::Italy, &ITALY),
+    (DxccEntityCode::UnitedStatesOfAmerica, &UNITED_STATES),
+    (DxccEntityCode::Japan, &JAPAN),
+    (DxccEntityCode::Argentina, &ARGENTINA),
+    (DxccEntityCode::BalearicIsland, &BALEARIC_ISLANDS),
+    (DxccEntityCode::Brazil, &BRAZIL),
+    (DxccEntityCode::CanaryIsland, &CANARY_ISLANDS),
+    (DxccEntityCode::CeutaAndMelilla, &CEUTA_AND_MELILLA),
+    (DxccEntityCode::China, &CHINA),
+    (DxccEntityCode::FederalRepublicOfGermany, &GERMANY),
+    (DxccEntityCode::Spain, &SPAIN),
+];
+
+/// Code of a primary administrative subdivision (state, province,
+/// prefecture, ...), stored in upper case. The same code means different
+/// subdivisions in different DXCC entities, so it is only checked against
+/// a table together with the entity. Values that are not codes, such as
+/// `New York`, are kept as read so that logs still load; see `is_code`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
+pub struct PrimaryAdministrativeSubdivision(String);
+
+impl PrimaryAdministrativeSubdivision {
+    /// Subdivision codes and names of the entity, when known.
+    pub fn for_entity(dxcc: &DxccEntityCode) -> Option<&'static [(&'static str, &'static str)]> {
+        SUBDIVISIONS
+            .iter()
+            .find(|(entity, _)| entity == dxcc)
+            .map(|(_, subdivisions)| *subdivisions)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn name(&self, dxcc: &DxccEntityCode) -> Option<&'static str> {
+        Self::for_entity(dxcc)?
+            .iter()
+            .find(|(code, _)| *code == self.0)
+            .map(|(_, name)| *name)
+    }
+
+    /// Whether the value has the form of a subdivision code.
+    pub fn is_code(&self) -> bool {
+        self.0.chars().all(|c| c.is_ascii_alphanumeric())
+    }
+
+    /// Whether the code exists in the entity, or `None` when the entity has
+    /// no table (see `SUBDIVISIONS`) and the code cannot be checked.
+    pub fn is_valid_for(&self, dxcc: &DxccEntityCode) -> Option<bool> {
+        Self::for_entity(dxcc).map(|_| self.name(dxcc).is_some())
+    }
+}
+
+impl AdifData for PrimaryAdministrativeSubdivision {
+    fn serialize(&self) -> String {
+        self.0.clone()
+    }
+
+    fn deserialize(value: &str) -> result::Result<Self>
+    where
+        Self: Sized,
+    {
+        let value = value.trim();
+        match value.is_empty() {
+            true => Err(DeserializeError(
+                "Empty primary administrative subdivision".to_string(),
+            )),
+            false if value.chars().all(|c| c.is_ascii_alphanumeric()) => {
+                Ok(Self(value.to_ascii_uppercase()))
+            }
+            false => Ok(Self(value.to_string())),
+        }
+    }
+}
+
+impl Display for PrimaryAdministrativeSubdivision {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.serialize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        assert_eq!(
+            PrimaryAdministrativeSubdivision::deserialize("ca")
+                .unwrap()
+                .as_str(),
+            "CA"
+        );
+        assert!(PrimaryAdministrativeSubdivision::deserialize("").is_err());
+        let name = PrimaryAdministrativeSubdivision::deserialize("New York").unwrap();
+        assert_eq!(name.as_str(), "New York");
+        assert!(!name.is_code());
+        assert_eq!(
+            name.is_valid_for(&DxccEntityCode::UnitedStatesOfAmerica),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_keyed_by_entity() {
+        let ca = PrimaryAdministrativeSubdivision::deserialize("CA").unwrap();
+        assert_eq!(
+            ca.name(&DxccEntityCode::UnitedStatesOfAmerica),
+            Some("California")
+        );
+        assert_eq!(ca.name(&DxccEntityCode::Sardinia), Some("Cagliari"));
+        assert!(ca.is_code());
+        assert_eq!(
+            ca.is_valid_for(&DxccEntityCode::UnitedStatesOfAmerica),
+            Some(true)
+        );
+        assert_eq!(ca.is_valid_for(&DxccEntityCode::Canada), Some(false));
+        assert_eq!(ca.is_valid_for(&DxccEntityCode::Alaska), Some(false));
+        assert_eq!(ca.is_valid_for(&DxccEntityCode::Spain), Some(true));
+        assert_eq!(ca.name(&DxccEntityCode::Spain), Some("Cádiz"));
+        assert_eq!(ca.is_valid_for(&DxccEntityCode::EuropeanRussia), None);
+
+        let bavaria = PrimaryAdministrativeSubdivision::deserialize("BY").unwrap();
+        assert_eq!(
+            bavaria.name(&DxccEntityCode::FederalRepublicOfGermany),
+            Some("Bayern")
+        );
+        assert_eq!(bavaria.is_valid_for(&DxccEntityCode::Brazil), Some(false));
+
+        let tokyo = PrimaryAdministrativeSubdivision::deserialize("10").unwrap();
+        assert_eq!(tokyo.name(&DxccEntityCode::Japan), Some("Tokyo"));
+    }
+
+    #[test]
+    fn test_tables_have_unique_codes() {
+        for (_, subdivisions) in SUBDIVISIONS.iter() {
+            for (code, _) in subdivisions.iter() {
+                assert_eq!(
+                    subdivisions
+                        .iter()
+                        .filter(|(other, _)| other == code)
+                        .count(),
+                    1,
+                    "{}",
+                    code
+                );
+            }
+        }
+    }
+}
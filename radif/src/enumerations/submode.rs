@@ -16,10 +16,257 @@
  */
 
 use crate::data::AdifData;
+use crate::enumerations::mode::Mode;
 use crate::error::AdifError;
 use radif_macros::{AdifData, AutoDisplay, AutoTestEnum};
 
+/// Submodes of each mode, from the ADIF mode enumeration.
+pub(crate) const MODE_SUBMODES: [(Mode, &[SubMode]); 23] = [
+    (Mode::CHIP, &[SubMode::CHIP64, SubMode::CHIP128]),
+    (Mode::CW, &[SubMode::PCW]),
+    (
+        Mode::DIGITALVOICE,
+        &[
+            SubMode::C4FM,
+            SubMode::DMR,
+            SubMode::DSTAR,
+            SubMode::FREEDV,
+            SubMode::M17,
+        ],
+    ),
+    (
+        Mode::DOMINO,
+        &[
+            SubMode::DOMM,
+            SubMode::DOM4,
+            SubMode::DOM5,
+            SubMode::DOM8,
+            SubMode::DOM11,
+            SubMode::DOM16,
+            SubMode::DOM22,
+            SubMode::DOM44,
+            SubMode::DOM88,
+            SubMode::DOMINOEX,
+            SubMode::DOMINOF,
+        ],
+    ),
+    (
+        Mode::DYNAMIC,
+        &[
+            SubMode::VARAHF,
+            SubMode::VARASATELLITE,
+            SubMode::VARAFM1200,
+            SubMode::VARAFM9600,
+        ],
+    ),
+    (
+        Mode::HELL,
+        &[
+            SubMode::FMHELL,
+            SubMode::FSKHELL,
+            SubMode::HELL80,
+            SubMode::HELLX5,
+            SubMode::HELLX9,
+            SubMode::HFSK,
+            SubMode::PSKHELL,
+            SubMode::SLOWHELL,
+        ],
+    ),
+    (Mode::ISCAT, &[SubMode::ISCATA, SubMode::ISCATB]),
+    (
+        Mode::JT4,
+        &[
+            SubMode::JT4A,
+            SubMode::JT4B,
+            SubMode::JT4C,
+            SubMode::JT4D,
+            SubMode::JT4E,
+            SubMode::JT4F,
+            SubMode::JT4G,
+        ],
+    ),
+    (
+        Mode::JT9,
+        &[
+            SubMode::JT9_1,
+            SubMode::JT9_2,
+            SubMode::JT9_5,
+            SubMode::JT9_10,
+            SubMode::JT9_30,
+            SubMode::JT9A,
+            SubMode::JT9B,
+            SubMode::JT9C,
+            SubMode::JT9D,
+            SubMode::JT9E,
+            SubMode::JT9EFAST,
+            SubMode::JT9F,
+            SubMode::JT9FFAST,
+            SubMode::JT9G,
+            SubMode::JT9GFAST,
+            SubMode::JT9H,
+            SubMode::JT9HFAST,
+        ],
+    ),
+    (
+        Mode::JT65,
+        &[
+            SubMode::JT65A,
+            SubMode::JT65B,
+            SubMode::JT65B2,
+            SubMode::JT65C,
+            SubMode::JT65C2,
+        ],
+    ),
+    (
+        Mode::MFSK,
+        &[
+            SubMode::FSQCALL,
+            SubMode::FST4,
+            SubMode::FST4W,
+            SubMode::FT4,
+            SubMode::JS8,
+            SubMode::JTMS,
+            SubMode::MFSK4,
+            SubMode::MFSK8,
+            SubMode::MFSK11,
+            SubMode::MFSK16,
+            SubMode::MFSK22,
+            SubMode::MFSK31,
+            SubMode::MFSK32,
+            SubMode::MFSK64,
+            SubMode::MFSK64L,
+            SubMode::MFSK128,
+            SubMode::MFSK128L,
+            SubMode::Q65,
+        ],
+    ),
+    (
+        Mode::OLIVIA,
+        &[
+            SubMode::OLIVIA4_125,
+            SubMode::OLIVIA4_250,
+            SubMode::OLIVIA8_250,
+            SubMode::OLIVIA8_500,
+            SubMode::OLIVIA16_500,
+            SubMode::OLIVIA16_1000,
+            SubMode::OLIVIA32_1000,
+        ],
+    ),
+    (Mode::OPERA, &[SubMode::OPERABEACON, SubMode::OPERAQSO]),
+    (Mode::PAC, &[SubMode::PAC2, SubMode::PAC3, SubMode::PAC4]),
+    (Mode::PAX, &[SubMode::PAX2]),
+    (
+        Mode::PSK,
+        &[
+            SubMode::_8PSK125,
+            SubMode::_8PSK125F,
+            SubMode::_8PSK125FL,
+            SubMode::_8PSK250,
+            SubMode::_8PSK250F,
+            SubMode::_8PSK250FL,
+            SubMode::_8PSK500,
+            SubMode::_8PSK500F,
+            SubMode::_8PSK1000,
+            SubMode::_8PSK1000F,
+            SubMode::_8PSK1200F,
+            SubMode::FSK31,
+            SubMode::PSK10,
+            SubMode::PSK31,
+            SubMode::PSK63,
+            SubMode::PSK63F,
+            SubMode::PSK63RC10,
+            SubMode::PSK63RC20,
+            SubMode::PSK63RC32,
+            SubMode::PSK63RC4,
+            SubMode::PSK63RC5,
+            SubMode::PSK125,
+            SubMode::PSK125RC10,
+            SubMode::PSK125RC12,
+            SubMode::PSK125RC16,
+            SubMode::PSK125RC4,
+            SubMode::PSK125RC5,
+            SubMode::PSK250,
+            SubMode::PSK250RC2,
+            SubMode::PSK250RC3,
+            SubMode::PSK250RC5,
+            SubMode::PSK250RC6,
+            SubMode::PSK250RC7,
+            SubMode::PSK500,
+            SubMode::PSK500RC2,
+            SubMode::PSK500RC3,
+            SubMode::PSK500RC4,
+            SubMode::PSK800RC2,
+            SubMode::PSK1000,
+            SubMode::PSK1000RC2,
+            SubMode::PSKAM10,
+            SubMode::PSKAM31,
+            SubMode::PSKAM50,
+            SubMode::PSKFEC31,
+            SubMode::QPSK31,
+            SubMode::QPSK63,
+            SubMode::QPSK125,
+            SubMode::QPSK250,
+            SubMode::QPSK500,
+            SubMode::SIM31,
+        ],
+    ),
+    (
+        Mode::QRA64,
+        &[
+            SubMode::QRA64A,
+            SubMode::QRA64B,
+            SubMode::QRA64C,
+            SubMode::QRA64D,
+            SubMode::QRA64E,
+        ],
+    ),
+    (
+        Mode::ROS,
+        &[SubMode::ROSEME, SubMode::ROSHF, SubMode::ROSMF],
+    ),
+    (Mode::RTTY, &[SubMode::ASCI]),
+    (Mode::SSB, &[SubMode::LSB, SubMode::USB]),
+    (
+        Mode::THOR,
+        &[
+            SubMode::THORM,
+            SubMode::THOR4,
+            SubMode::THOR5,
+            SubMode::THOR8,
+            SubMode::THOR11,
+            SubMode::THOR16,
+            SubMode::THOR22,
+            SubMode::THOR25X4,
+            SubMode::THOR50X1,
+            SubMode::THOR50X2,
+            SubMode::THOR100,
+        ],
+    ),
+    (
+        Mode::THRB,
+        &[
+            SubMode::THRBX,
+            SubMode::THRBX1,
+            SubMode::THRBX2,
+            SubMode::THRBX4,
+            SubMode::THROB1,
+            SubMode::THROB2,
+            SubMode::THROB4,
+        ],
+    ),
+    (
+        Mode::TOR,
+        &[
+            SubMode::AMTORFEC,
+            SubMode::GTOR,
+            SubMode::NAVTEX,
+            SubMode::SITORB,
+        ],
+    ),
+];
+
 #[derive(Debug, Clone, PartialEq, Eq, AutoDisplay, AdifData, AutoTestEnum)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
 pub enum SubMode {
     #[adif("8PSK125")]
     _8PSK125,
@@ -374,3 +621,38 @@ pub enum SubMode {
     #[adif("VARAFM9600")]
     VARAFM9600,
 }
+
+impl SubMode {
+    pub fn parent_mode(&self) -> Mode {
+        MODE_SUBMODES
+            .iter()
+            .find(|(_, submodes)| submodes.contains(self))
+            .map(|(mode, _)| mode.clone())
+            .expect("every submode has a parent mode")
+    }
+}
+
+#[cfg(test)]
+mod hierarchy_tests {
+    use super::*;
+
+    #[test]
+    fn test_parent_mode() {
+        assert_eq!(SubMode::FT4.parent_mode(), Mode::MFSK);
+        assert_eq!(SubMode::USB.parent_mode(), Mode::SSB);
+        assert_eq!(SubMode::PSK31.parent_mode(), Mode::PSK);
+        assert_eq!(SubMode::VARAFM9600.parent_mode(), Mode::DYNAMIC);
+    }
+
+    #[test]
+    fn test_every_submode_has_one_parent() {
+        let submodes: Vec<&SubMode> = MODE_SUBMODES
+            .iter()
+            .flat_map(|(_, submodes)| submodes.iter())
+            .collect();
+        assert_eq!(submodes.len(), 176);
+        for submode in &submodes {
+            assert_eq!(submodes.iter().filter(|item| item == &submode).count(), 1);
+        }
+    }
+}
@@ -25,6 +25,17 @@ pub trait FieldName: AdifData + PartialEq {
     fn get_value_type_char(&self) -> Option<String> {
         None
     }
+
+    /// Checks constraints of the field beyond those of its data type.
+    fn check_value(&self, _value: &DataValue) -> crate::result::Result<()> {
+        Ok(())
+    }
+
+    fn parse_value(&self, value: &str) -> crate::result::Result<DataValue> {
+        let value = DataValue::str_to_enum(self.get_data_type(), value)?;
+        self.check_value(&value)?;
+        Ok(value)
+    }
 }
 
 pub trait Field: AdifData + PartialEq + Default {
@@ -35,8 +46,17 @@ pub trait Field: AdifData + PartialEq + Default {
 
     fn get_name_end(&self) -> &Self::FN;
 
+    /// Builds the field without checking the value against the constraints
+    /// of the field, see `try_new`.
     fn new(name: Self::FN, value: DataValue) -> Self;
 
+    /// Like `new`, but fails when the value breaks the constraints of the
+    /// field.
+    fn try_new(name: Self::FN, value: DataValue) -> crate::result::Result<Self> {
+        name.check_value(&value)?;
+        Ok(Self::new(name, value))
+    }
+
     fn end() -> Self;
 
     fn is_end(&self) -> bool {
@@ -99,8 +119,106 @@ impl<T: Field> AdifData for T {
             )));
         }
 
-        let value = DataValue::str_to_enum(name.get_data_type(), value_str)?;
+        let value = name.parse_value(value_str)?;
 
         Ok(Self::new(name, value))
     }
 }
+
+#[cfg(feature = "serde")]
+pub(crate) fn serialize_fields<F, S>(fields: &[F], serializer: S) -> Result<S::Ok, S::Error>
+where
+    F: Field,
+    S: serde::Serializer,
+{
+    use serde::ser::SerializeMap;
+
+    let fields: Vec<&F> = fields.iter().filter(|field| !field.is_end()).collect();
+    let mut map = serializer.serialize_map(Some(fields.len()))?;
+    for field in fields {
+        map.serialize_entry(
+            &field.get_name().serialize(),
+            &field.get_value().serialize(),
+        )?;
+    }
+    map.end()
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_fields<'de, F, D>(deserializer: D) -> Result<Vec<F>, D::Error>
+where
+    F: Field,
+    D: serde::Deserializer<'de>,
+{
+    deserializer.deserialize_map(FieldsVisitor(std::marker::PhantomData))
+}
+
+#[cfg(feature = "serde")]
+struct FieldsVisitor<F>(std::marker::PhantomData<F>);
+
+#[cfg(feature = "serde")]
+impl<'de, F: Field> serde::de::Visitor<'de> for FieldsVisitor<F> {
+    type Value = Vec<F>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map of ADIF field names to values")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let mut fields = Vec::with_capacity(access.size_hint().unwrap_or(0));
+        while let Some((key, FieldText(value))) = access.next_entry::<String, FieldText>()? {
+            let name = F::FN::deserialize(&key).map_err(A::Error::custom)?;
+            let value = name.parse_value(&value).map_err(A::Error::custom)?;
+            fields.push(F::new(name, value));
+        }
+        Ok(fields)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct FieldText(String);
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FieldText {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct FieldTextVisitor;
+
+        impl serde::de::Visitor<'_> for FieldTextVisitor {
+            type Value = FieldText;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("an ADIF field value as string, number or boolean")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(FieldText(value.to_string()))
+            }
+
+            fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E> {
+                Ok(FieldText(if value { "Y" } else { "N" }.to_string()))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E> {
+                Ok(FieldText(value.to_string()))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E> {
+                Ok(FieldText(value.to_string()))
+            }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E> {
+                Ok(FieldText(value.to_string()))
+            }
+        }
+
+        deserializer.deserialize_any(FieldTextVisitor)
+    }
+}
@@ -0,0 +1,236 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use crate::data::AdifData;
+use crate::error::AdifError::DeserializeError;
+use crate::result;
+use std::fmt::{Display, Formatter};
+
+/// Trailing designators that never replace the prefix of the home call.
+const SUFFIXES: [&str; 11] = [
+    "P", "M", "MM", "AM", "A", "B", "E", "J", "LH", "QRP", "QRPP",
+];
+
+/// Callsign split into the home call, an optional prefix override and the
+/// portable suffixes, as in `EA8/IS0GVH/P`; stored in upper case.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
+pub struct Callsign {
+    value: String,
+    prefix: Option<String>,
+    base: String,
+    suffixes: Vec<String>,
+}
+
+impl Callsign {
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Home call without prefix override or suffixes, used to spot dupes
+    /// across portable operations.
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    /// Prefix override, as `EA8` in `EA8/IS0GVH` or `KH6` in `W1ABC/KH6`.
+    pub fn prefix(&self) -> Option<&str> {
+        self.prefix.as_deref()
+    }
+
+    pub fn suffixes(&self) -> &[String] {
+        &self.suffixes
+    }
+
+    /// Call area moved to by a single digit suffix, as in `W1ABC/4`.
+    pub fn area(&self) -> Option<char> {
+        self.suffixes
+            .iter()
+            .find(|suffix| is_area(suffix))
+            .and_then(|suffix| suffix.chars().next())
+    }
+
+    pub fn is_maritime_mobile(&self) -> bool {
+        self.suffixes.iter().any(|suffix| suffix == "MM")
+    }
+
+    pub fn is_aeronautical_mobile(&self) -> bool {
+        self.suffixes.iter().any(|suffix| suffix == "AM")
+    }
+
+    /// Call whose prefix gives the location of the station: the prefix
+    /// override if any, or else the home call moved to the new call area.
+    pub fn location_call(&self) -> String {
+        match (&self.prefix, self.area()) {
+            (Some(prefix), _) => prefix.clone(),
+            (None, Some(area)) => match self.base.find(|c: char| c.is_ascii_digit()) {
+                Some(index) if index > 0 => {
+                    format!("{}{}{}", &self.base[..index], area, &self.base[index + 1..])
+                }
+                _ => self.base.clone(),
+            },
+            (None, None) => self.base.clone(),
+        }
+    }
+
+    /// Prefix as counted by the CQ WPX contest: everything up to the last
+    /// digit, with a `0` added after a prefix override without digits, as
+    /// `PA0` for `PA/N8BJQ`, or after the first two letters of a call without
+    /// digits.
+    pub fn wpx_prefix(&self) -> String {
+        let call = self.location_call();
+        match call.rfind(|c: char| c.is_ascii_digit()) {
+            Some(index) => call[..index + 1].to_string(),
+            None if self.prefix.is_some() => format!("{}0", call),
+            None => format!("{}0", call.chars().take(2).collect::<String>()),
+        }
+    }
+}
+
+impl AdifData for Callsign {
+    fn serialize(&self) -> String {
+        self.value.clone()
+    }
+
+    fn deserialize(value: &str) -> result::Result<Self>
+    where
+        Self: Sized,
+    {
+        let value = value.trim().to_ascii_uppercase();
+        let invalid = || DeserializeError(format!("Invalid callsign '{}'", value));
+
+        let mut parts = value.split('/').collect::<Vec<_>>();
+        if parts
+            .iter()
+            .any(|part| part.is_empty() || !part.chars().all(|c| c.is_ascii_alphanumeric()))
+        {
+            return Err(invalid());
+        }
+
+        let mut suffixes = vec![];
+        while parts.len() > 1 && is_suffix(parts[parts.len() - 1]) {
+            suffixes.insert(0, parts[parts.len() - 1].to_string());
+            parts.pop();
+        }
+
+        let (prefix, base) = match parts.as_slice() {
+            [base] => (None, *base),
+            [first, second] if second.len() < first.len() => (Some(*second), *first),
+            [first, second] => (Some(*first), *second),
+            _ => return Err(invalid()),
+        };
+        if base.len() < 3 || !base.chars().any(|c| c.is_ascii_alphabetic()) {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            value: value.clone(),
+            prefix: prefix.map(str::to_string),
+            base: base.to_string(),
+            suffixes,
+        })
+    }
+}
+
+impl Display for Callsign {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.serialize())
+    }
+}
+
+fn is_suffix(part: &str) -> bool {
+    SUFFIXES.contains(&part) || is_area(part)
+}
+
+fn is_area(part: &str) -> bool {
+    part.len() == 1 && part.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::AdifData;
+    use crate::fields::callsign::Callsign;
+
+    #[test]
+    fn test_deserialize() {
+        let actual = Callsign::deserialize("ea8/is0gvh/p").unwrap();
+        assert_eq!(actual.as_str(), "EA8/IS0GVH/P");
+        assert_eq!(actual.base(), "IS0GVH");
+        assert_eq!(actual.prefix(), Some("EA8"));
+        assert_eq!(actual.suffixes(), &["P".to_string()]);
+        assert_eq!(actual.serialize(), "EA8/IS0GVH/P");
+
+        let actual = Callsign::deserialize("IS0GVH").unwrap();
+        assert_eq!(actual.base(), "IS0GVH");
+        assert_eq!(actual.prefix(), None);
+        assert!(actual.suffixes().is_empty());
+
+        let actual = Callsign::deserialize("W1ABC/KH6").unwrap();
+        assert_eq!(actual.base(), "W1ABC");
+        assert_eq!(actual.prefix(), Some("KH6"));
+
+        let actual = Callsign::deserialize("IS0GVH/QRP/P").unwrap();
+        assert_eq!(actual.base(), "IS0GVH");
+        assert_eq!(actual.suffixes(), &["QRP".to_string(), "P".to_string()]);
+
+        let actual = Callsign::deserialize("IS0GVH/MM").unwrap();
+        assert!(actual.is_maritime_mobile());
+        assert!(!actual.is_aeronautical_mobile());
+        assert!(Callsign::deserialize("IS0GVH/AM")
+            .unwrap()
+            .is_aeronautical_mobile());
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        assert!(Callsign::deserialize("").is_err());
+        assert!(Callsign::deserialize("IS0 GVH").is_err());
+        assert!(Callsign::deserialize("IS0GVH//P").is_err());
+        assert!(Callsign::deserialize("EA8/DL/IS0GVH").is_err());
+        assert!(Callsign::deserialize("I0").is_err());
+        assert!(Callsign::deserialize("1234").is_err());
+    }
+
+    #[test]
+    fn test_base_ignores_portable_designators() {
+        let home = Callsign::deserialize("IS0GVH").unwrap();
+        for value in ["EA8/IS0GVH", "IS0GVH/P", "EA8/IS0GVH/P", "IS0GVH/3"] {
+            assert_eq!(Callsign::deserialize(value).unwrap().base(), home.base());
+        }
+    }
+
+    #[test]
+    fn test_wpx_prefix() {
+        for (call, prefix) in [
+            ("IS0GVH", "IS0"),
+            ("N8BJQ", "N8"),
+            ("WN8BJQ", "WN8"),
+            ("9A1A", "9A1"),
+            ("HG1995X", "HG1995"),
+            ("2E0ABC", "2E0"),
+            ("RAEM", "RA0"),
+            ("IS0GVH/P", "IS0"),
+            ("IS0GVH/MM", "IS0"),
+            ("W1ABC/4", "W4"),
+            ("KH6/W1ABC", "KH6"),
+            ("W1ABC/KH6", "KH6"),
+            ("PA/N8BJQ", "PA0"),
+            ("EA8/IS0GVH/P", "EA8"),
+        ] {
+            assert_eq!(Callsign::deserialize(call).unwrap().wpx_prefix(), prefix);
+        }
+    }
+}
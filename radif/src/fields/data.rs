@@ -20,13 +20,19 @@ use crate::enumerations::award::Award;
 use crate::enumerations::credit::Credit;
 use crate::enumerations::{Enumeration, EnumerationType};
 use crate::error::AdifError::DeserializeError;
+use crate::fields::grid_square::GridSquare;
 use crate::fields::iota_ref_no::IotaRefNo;
 use crate::fields::location::Location;
+use crate::fields::pota_ref::PotaRef;
+use crate::fields::secondary_subdivision::{SecondarySubdivision, SecondarySubdivisionAlt};
+use crate::fields::sota_ref::SotaRef;
 use crate::fields::sponsored_award::SponsoredAward;
+use crate::fields::wwff_ref::WwffRef;
 use crate::result;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataType {
     AwardList,
     CreditList,
@@ -52,6 +58,7 @@ pub enum DataType {
     Location,
     PotaRef,
     PotaRefList,
+    SecondarySubdivision,
     SecondarySubdivisionList,
     SecondaryAdministrativeSubdivisionListAlt,
     SotaRef,
@@ -60,6 +67,7 @@ pub enum DataType {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataValue {
     AwardList(Vec<Award>),
     CreditList(Vec<Credit>),
@@ -79,24 +87,25 @@ pub enum DataValue {
     MultilineString(String),
     IntlMultilineString(String),
     Enumeration(Enumeration),
-    GridSquare(String),
+    GridSquare(GridSquare),
     GridSquareExt(String),
-    GridSquareList(Vec<String>),
+    GridSquareList(Vec<GridSquare>),
     Location(Location),
-    PotaRef(String),
-    PotaRefList(Vec<String>),
-    SecondarySubdivisionList(Vec<String>),
-    SecondaryAdministrativeSubdivisionListAlt(Vec<String>),
-    SotaRef(String),
-    WwffRef(String),
+    PotaRef(PotaRef),
+    PotaRefList(Vec<PotaRef>),
+    SecondarySubdivision(SecondarySubdivision),
+    SecondarySubdivisionList(Vec<SecondarySubdivision>),
+    SecondaryAdministrativeSubdivisionListAlt(Vec<SecondarySubdivisionAlt>),
+    SotaRef(SotaRef),
+    WwffRef(WwffRef),
     Null(),
 }
 
 impl AdifData for DataValue {
     fn serialize(&self) -> String {
         match self {
-            DataValue::AwardList(v) => format!("{}", join_vec(v, ",")),
-            DataValue::CreditList(v) => format!("{}", join_vec(v, ",")),
+            DataValue::AwardList(v) => join_data_vec(v, ","),
+            DataValue::CreditList(v) => join_data_vec(v, ","),
             DataValue::SponsoredAwardList(v) => format!("{}", join_vec(v, ",")),
             DataValue::Boolean(v) => format!("{}", if *v { "Y" } else { "N" }),
             DataValue::Digit(v) => format!("{}", *v),
@@ -115,14 +124,13 @@ impl AdifData for DataValue {
             DataValue::Enumeration(v) => format!("{}", *v),
             DataValue::GridSquare(v) => format!("{}", *v),
             DataValue::GridSquareExt(v) => format!("{}", *v),
-            DataValue::GridSquareList(v) => format!("{}", join_vec(v, ",")),
+            DataValue::GridSquareList(v) => join_data_vec(v, ","),
             DataValue::Location(v) => format!("{}", *v),
             DataValue::PotaRef(v) => format!("{}", *v),
-            DataValue::PotaRefList(v) => format!("{}", join_vec(v, ",")),
-            DataValue::SecondarySubdivisionList(v) => format!("{}", join_vec(v, ",")),
-            DataValue::SecondaryAdministrativeSubdivisionListAlt(v) => {
-                format!("{}", join_vec(v, ","))
-            }
+            DataValue::PotaRefList(v) => join_data_vec(v, ","),
+            DataValue::SecondarySubdivision(v) => format!("{}", *v),
+            DataValue::SecondarySubdivisionList(v) => join_data_vec(v, ":"),
+            DataValue::SecondaryAdministrativeSubdivisionListAlt(v) => join_data_vec(v, ";"),
             DataValue::SotaRef(v) => format!("{}", *v),
             DataValue::WwffRef(v) => format!("{}", *v),
             DataValue::Null() => "".to_string(),
@@ -164,6 +172,17 @@ impl DataValue {
         }
     }
 
+    /// Numeric value of the integer and number variants.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            DataValue::Digit(v) => Some(*v as f64),
+            DataValue::Integer(v) => Some(*v as f64),
+            DataValue::Number(v) => Some(*v),
+            DataValue::PositiveInteger(v) => Some(*v as f64),
+            _ => None,
+        }
+    }
+
     pub fn str_to_enum(data_type: DataType, value: &str) -> result::Result<Self> {
         match data_type {
             DataType::AwardList => Ok(DataValue::AwardList(split_to_vec::<Award>(value)?)),
@@ -171,7 +190,11 @@ impl DataValue {
             DataType::SponsoredAwardList => Ok(DataValue::SponsoredAwardList(split_to_vec::<
                 SponsoredAward,
             >(value)?)),
-            DataType::Boolean => Ok(DataValue::Boolean(value == "Y")),
+            DataType::Boolean => match value {
+                "Y" | "y" => Ok(DataValue::Boolean(true)),
+                "N" | "n" => Ok(DataValue::Boolean(false)),
+                _ => Err(DeserializeError(format!("Invalid boolean '{}'", value))),
+            },
             DataType::Digit => value
                 .parse::<u8>()
                 .map(DataValue::Digit)
@@ -214,28 +237,39 @@ impl DataValue {
             DataType::Enumeration(e) => {
                 Ok(Enumeration::str_to_enum(e, value).map(DataValue::Enumeration)?)
             }
-            DataType::GridSquare => Ok(DataValue::GridSquare(value.to_string())),
-            DataType::GridSquareExt => Ok(DataValue::GridSquareExt(value.to_string())),
-            DataType::GridSquareList => Ok(DataValue::GridSquareList(
-                value.split(',').map(String::from).collect(),
-            )),
+            DataType::GridSquare => Ok(DataValue::GridSquare(GridSquare::deserialize(value)?)),
+            DataType::GridSquareExt => match GridSquare::is_valid_extension(value) {
+                true => Ok(DataValue::GridSquareExt(value.to_ascii_uppercase())),
+                false => Err(DeserializeError(format!(
+                    "Invalid grid square extension '{}'",
+                    value
+                ))),
+            },
+            DataType::GridSquareList => Ok(DataValue::GridSquareList(split_to_vec::<GridSquare>(
+                value,
+            )?)),
             DataType::Location => Ok(DataValue::Location(
                 Location::deserialize(value).map_err(|e| DeserializeError(e.to_string()))?,
             )),
-            DataType::PotaRef => Ok(DataValue::PotaRef(value.to_string())),
-            DataType::PotaRefList => Ok(DataValue::PotaRefList(
-                value.split(',').map(String::from).collect(),
-            )),
-            DataType::SecondarySubdivisionList => Ok(DataValue::SecondarySubdivisionList(
-                value.split(',').map(String::from).collect(),
+            DataType::PotaRef => Ok(DataValue::PotaRef(PotaRef::deserialize(value)?)),
+            DataType::PotaRefList => Ok(DataValue::PotaRefList(split_to_vec::<PotaRef>(value)?)),
+            DataType::SecondarySubdivision => Ok(DataValue::SecondarySubdivision(
+                SecondarySubdivision::deserialize(value)?,
             )),
+            DataType::SecondarySubdivisionList => {
+                Ok(DataValue::SecondarySubdivisionList(split_to_vec_by::<
+                    SecondarySubdivision,
+                >(
+                    value, ':'
+                )?))
+            }
             DataType::SecondaryAdministrativeSubdivisionListAlt => {
                 Ok(DataValue::SecondaryAdministrativeSubdivisionListAlt(
-                    value.split(',').map(String::from).collect(),
+                    split_to_vec_by::<SecondarySubdivisionAlt>(value, ';')?,
                 ))
             }
-            DataType::SotaRef => Ok(DataValue::SotaRef(value.to_string())),
-            DataType::WwffRef => Ok(DataValue::WwffRef(value.to_string())),
+            DataType::SotaRef => Ok(DataValue::SotaRef(SotaRef::deserialize(value)?)),
+            DataType::WwffRef => Ok(DataValue::WwffRef(WwffRef::deserialize(value)?)),
             DataType::Null => Ok(DataValue::Null()),
         }
     }
@@ -248,9 +282,100 @@ fn join_vec<T: ToString>(vec: &Vec<T>, s: &str) -> String {
         .join(s)
 }
 
+fn join_data_vec<T: AdifData>(vec: &[T], s: &str) -> String {
+    vec.iter()
+        .map(|item| item.serialize())
+        .collect::<Vec<String>>()
+        .join(s)
+}
+
 fn split_to_vec<T: AdifData>(value: &str) -> result::Result<Vec<T>> {
+    split_to_vec_by(value, ',')
+}
+
+fn split_to_vec_by<T: AdifData>(value: &str, separator: char) -> result::Result<Vec<T>> {
     value
-        .split(',')
+        .split(separator)
         .map(|s| T::deserialize(s))
         .collect::<result::Result<Vec<T>>>()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_enumeration_uses_adif_value() {
+        let input =
+            DataValue::str_to_enum(DataType::Enumeration(EnumerationType::Band), "1.25m").unwrap();
+        assert_eq!(input.serialize(), "1.25M");
+    }
+
+    #[test]
+    fn test_serialize_credit_list_uses_adif_value() {
+        let input =
+            DataValue::CreditList(vec![Credit::CQMagazineDXMixed, Credit::CQMagazineDXBand]);
+        assert_eq!(input.serialize(), "CQDX,CQDX_BAND");
+    }
+
+    #[test]
+    fn test_boolean_accepts_only_y_and_n() {
+        assert_eq!(
+            DataValue::str_to_enum(DataType::Boolean, "y").unwrap(),
+            DataValue::Boolean(true)
+        );
+        assert_eq!(
+            DataValue::str_to_enum(DataType::Boolean, "N").unwrap(),
+            DataValue::Boolean(false)
+        );
+        assert!(DataValue::str_to_enum(DataType::Boolean, "YES").is_err());
+        assert!(DataValue::str_to_enum(DataType::Boolean, "").is_err());
+    }
+
+    #[test]
+    fn test_references() {
+        let input = DataValue::str_to_enum(DataType::PotaRefList, "k-0817@us-ca,VE-0001").unwrap();
+        assert_eq!(input.serialize(), "K-0817@US-CA,VE-0001");
+        assert!(DataValue::str_to_enum(DataType::PotaRefList, "K-0817,").is_err());
+        assert_eq!(
+            DataValue::str_to_enum(DataType::SotaRef, "w6/ct-006").unwrap(),
+            DataValue::SotaRef(SotaRef::deserialize("W6/CT-006").unwrap())
+        );
+        assert!(DataValue::str_to_enum(DataType::WwffRef, "IFF-123").is_err());
+    }
+
+    #[test]
+    fn test_secondary_subdivision_list_keeps_state_and_county() {
+        let input = DataValue::str_to_enum(
+            DataType::SecondarySubdivisionList,
+            "MA,Franklin:MA,Hampshire",
+        )
+        .unwrap();
+        assert_eq!(
+            input,
+            DataValue::SecondarySubdivisionList(vec![
+                SecondarySubdivision::deserialize("MA,Franklin").unwrap(),
+                SecondarySubdivision::deserialize("MA,Hampshire").unwrap(),
+            ])
+        );
+        assert_eq!(input.serialize(), "MA,Franklin:MA,Hampshire");
+    }
+
+    #[test]
+    fn test_secondary_subdivision_list_alt() {
+        let input = DataValue::str_to_enum(
+            DataType::SecondaryAdministrativeSubdivisionListAlt,
+            "NZ_Regions:Auckland;NZ_Regions:Canterbury",
+        )
+        .unwrap();
+        assert_eq!(
+            input.serialize(),
+            "NZ_Regions:Auckland;NZ_Regions:Canterbury"
+        );
+        assert!(DataValue::str_to_enum(
+            DataType::SecondaryAdministrativeSubdivisionListAlt,
+            "Auckland"
+        )
+        .is_err());
+    }
+}
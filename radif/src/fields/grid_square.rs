@@ -0,0 +1,288 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::data::AdifData;
+use crate::error::AdifError::DeserializeError;
+use crate::result;
+use crate::utilities::maidenhead_locator::{
+    cell_count, coordinates_to_locator, indices_to_locator, locator_to_coordinates,
+    locator_to_indices, Point,
+};
+use std::fmt::{Display, Formatter};
+
+/// Validated Maidenhead locator of 2 to 12 characters, stored in upper case.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
+pub struct GridSquare(String);
+
+impl GridSquare {
+    pub fn from_point(point: Point, precision: usize) -> result::Result<Self> {
+        coordinates_to_locator(point, precision).map(Self)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Number of characters of the locator.
+    pub fn precision(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn center(&self) -> Point {
+        locator_to_coordinates(&self.0).expect("grid square is validated on creation")
+    }
+
+    /// South-west and north-east corners of the square.
+    pub fn bounds(&self) -> (Point, Point) {
+        let count = cell_count(self.precision() / 2);
+        let (long, lat) = self.indices();
+        let (width, height) = (360.0 / count.0 as f64, 180.0 / count.1 as f64);
+
+        (
+            Point::new(long as f64 * width - 180.0, lat as f64 * height - 90.0),
+            Point::new(
+                (long + 1) as f64 * width - 180.0,
+                (lat + 1) as f64 * height - 90.0,
+            ),
+        )
+    }
+
+    /// Joins a `GRIDSQUARE` of 8 characters with its `GRIDSQUARE_EXT`.
+    pub fn merge(gridsquare: &GridSquare, ext: &str) -> result::Result<Self> {
+        if gridsquare.precision() != 8 {
+            return Err(DeserializeError(format!(
+                "Grid square extension needs an 8-character grid square, got '{}'",
+                gridsquare
+            )));
+        }
+        Self::deserialize(&format!("{}{}", gridsquare, ext))
+    }
+
+    /// Whether the value can extend an 8-character locator, as required for
+    /// `GRIDSQUARE_EXT`: one or two more character pairs.
+    pub fn is_valid_extension(ext: &str) -> bool {
+        (ext.len() == 2 || ext.len() == 4)
+            && locator_to_indices(&format!("AA00AA00{}", ext)).is_ok()
+    }
+
+    /// Splits the locator into the first 8 characters, as stored in
+    /// `GRIDSQUARE`, and the rest, as stored in `GRIDSQUARE_EXT`.
+    pub fn split(&self) -> (Self, Option<String>) {
+        if self.precision() <= 8 {
+            return (self.clone(), None);
+        }
+        (Self(self.0[..8].to_string()), Some(self.0[8..].to_string()))
+    }
+
+    /// Enclosing square with one character pair less, if any.
+    pub fn parent(&self) -> Option<Self> {
+        (self.precision() > 2).then(|| Self(self.0[..self.precision() - 2].to_string()))
+    }
+
+    /// Squares with one character pair more, from south-west to north-east.
+    pub fn children(&self) -> Vec<Self> {
+        if self.precision() >= 12 {
+            return vec![];
+        }
+
+        let count = cell_count(self.precision() / 2);
+        let child_count = cell_count(self.precision() / 2 + 1);
+        let (long_size, lat_size) = (child_count.0 / count.0, child_count.1 / count.1);
+        let (long, lat) = self.indices();
+
+        (0..lat_size)
+            .flat_map(|lat_offset| {
+                (0..long_size).map(move |long_offset| {
+                    (long * long_size + long_offset, lat * lat_size + lat_offset)
+                })
+            })
+            .map(|indices| Self(indices_to_locator(indices, self.precision() + 2)))
+            .collect()
+    }
+
+    /// Square of the same precision shifted by the given number of columns
+    /// (east positive) and rows (north positive). Longitude wraps around the
+    /// antimeridian, while rows beyond the poles do not exist.
+    pub fn neighbour(&self, long_offset: i64, lat_offset: i64) -> Option<Self> {
+        let count = cell_count(self.precision() / 2);
+        let (long, lat) = self.indices();
+
+        let lat = lat as i64 + lat_offset;
+        if lat < 0 || lat >= count.1 as i64 {
+            return None;
+        }
+        let long = (long as i64 + long_offset).rem_euclid(count.0 as i64);
+
+        Some(Self(indices_to_locator(
+            (long as u64, lat as u64),
+            self.precision(),
+        )))
+    }
+
+    /// Up to eight surrounding squares, fewer along the poles.
+    pub fn neighbours(&self) -> Vec<Self> {
+        (-1..=1)
+            .flat_map(|lat_offset| (-1..=1).map(move |long_offset| (long_offset, lat_offset)))
+            .filter(|offsets| *offsets != (0, 0))
+            .filter_map(|(long_offset, lat_offset)| self.neighbour(long_offset, lat_offset))
+            .collect()
+    }
+
+    fn indices(&self) -> (u64, u64) {
+        locator_to_indices(&self.0).expect("grid square is validated on creation")
+    }
+}
+
+impl AdifData for GridSquare {
+    fn serialize(&self) -> String {
+        self.0.clone()
+    }
+
+    fn deserialize(value: &str) -> result::Result<Self>
+    where
+        Self: Sized,
+    {
+        locator_to_indices(value)
+            .map(|_| Self(value.to_ascii_uppercase()))
+            .map_err(|e| DeserializeError(format!("Invalid grid square '{}': {}", value, e)))
+    }
+}
+
+impl Display for GridSquare {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.serialize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::AdifData;
+    use crate::fields::grid_square::GridSquare;
+    use crate::utilities::maidenhead_locator::Point;
+
+    fn grid(value: &str) -> GridSquare {
+        GridSquare::deserialize(value).unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_valid() {
+        let actual = GridSquare::deserialize("jm49sk").unwrap();
+        assert_eq!(actual.serialize(), "JM49SK");
+        assert_eq!(actual.precision(), 6);
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        assert!(GridSquare::deserialize("").is_err());
+        assert!(GridSquare::deserialize("JM4").is_err());
+        assert!(GridSquare::deserialize("ZZ00").is_err());
+        assert!(GridSquare::deserialize("JM49SK46XV94AA").is_err());
+    }
+
+    #[test]
+    fn test_from_point() {
+        let actual = GridSquare::from_point(Point::new(9.54, 39.44), 6).unwrap();
+        assert_eq!(actual, grid("JM49SK"));
+    }
+
+    #[test]
+    fn test_center() {
+        assert_eq!(grid("JM49").center(), Point::new(9.0, 39.5));
+    }
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!(
+            grid("JM49").bounds(),
+            (Point::new(8.0, 39.0), Point::new(10.0, 40.0))
+        );
+        assert_eq!(
+            grid("AA").bounds(),
+            (Point::new(-180.0, -90.0), Point::new(-160.0, -80.0))
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let actual = GridSquare::merge(&grid("JM49SK46"), "xv94").unwrap();
+        assert_eq!(actual, grid("JM49SK46XV94"));
+        assert!(GridSquare::merge(&grid("JM49SK"), "46").is_err());
+        assert!(GridSquare::merge(&grid("JM49SK46"), "X").is_err());
+        assert!(GridSquare::merge(&grid("JM49SK46"), "99").is_err());
+    }
+
+    #[test]
+    fn test_is_valid_extension() {
+        assert!(GridSquare::is_valid_extension("XV"));
+        assert!(GridSquare::is_valid_extension("xv94"));
+        assert!(!GridSquare::is_valid_extension("9Z"));
+        assert!(!GridSquare::is_valid_extension("X"));
+        assert!(!GridSquare::is_valid_extension("XV94AA"));
+    }
+
+    #[test]
+    fn test_split() {
+        assert_eq!(
+            grid("JM49SK46XV").split(),
+            (grid("JM49SK46"), Some("XV".to_string()))
+        );
+        assert_eq!(grid("JM49SK").split(), (grid("JM49SK"), None));
+    }
+
+    #[test]
+    fn test_parent() {
+        assert_eq!(grid("JM49SK").parent(), Some(grid("JM49")));
+        assert_eq!(grid("JM").parent(), None);
+    }
+
+    #[test]
+    fn test_children() {
+        let actual = grid("JM49").children();
+        assert_eq!(actual.len(), 24 * 24);
+        assert_eq!(actual.first(), Some(&grid("JM49AA")));
+        assert_eq!(actual.last(), Some(&grid("JM49XX")));
+        assert!(actual
+            .iter()
+            .all(|child| child.parent() == Some(grid("JM49"))));
+        assert!(grid("JM49SK46XV94").children().is_empty());
+    }
+
+    #[test]
+    fn test_neighbour() {
+        assert_eq!(grid("JM49").neighbour(1, 0), Some(grid("JM59")));
+        assert_eq!(grid("JM49").neighbour(0, 1), Some(grid("JN40")));
+        assert_eq!(grid("JM49SK").neighbour(-1, -1), Some(grid("JM49RJ")));
+    }
+
+    #[test]
+    fn test_neighbour_edges() {
+        assert_eq!(grid("RJ90").neighbour(1, 0), Some(grid("AJ00")));
+        assert_eq!(grid("AJ00").neighbour(-1, 0), Some(grid("RJ90")));
+        assert_eq!(grid("JR09").neighbour(0, 1), None);
+        assert_eq!(grid("JA00").neighbour(0, -1), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let actual = grid("JM49").neighbours();
+        assert_eq!(actual.len(), 8);
+        assert!(actual.contains(&grid("JM38")));
+        assert!(actual.contains(&grid("JN50")));
+        assert_eq!(grid("JR09").neighbours().len(), 5);
+    }
+}
@@ -23,6 +23,7 @@ use crate::result;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
 #[allow(non_camel_case_types)]
 pub enum HeaderFieldName {
     ADIF_VER,
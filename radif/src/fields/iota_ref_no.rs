@@ -21,6 +21,7 @@ use crate::error::AdifError;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
 pub struct IotaRefNo {
     pub continent: Continent,
     pub number: u16,
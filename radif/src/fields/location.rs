@@ -16,7 +16,9 @@
  */
 
 use crate::data::AdifData;
-use crate::error::AdifError::DeserializeError;
+use crate::error::AdifError::{DeserializeError, GenericError};
+use crate::result;
+use crate::utilities::maidenhead_locator::Point;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -38,26 +40,111 @@ impl Display for LocationDirection {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocationAxis {
+    Latitude,
+    Longitude,
+}
+
+impl LocationAxis {
+    fn limit(&self) -> f64 {
+        match self {
+            LocationAxis::Latitude => 90.0,
+            LocationAxis::Longitude => 180.0,
+        }
+    }
+}
+
+/// Latitude or longitude in signed decimal degrees, north and east being
+/// positive, serialized in the ADIF `XDDD MM.MMM` format.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
 pub struct Location {
-    pub direction: LocationDirection,
-    pub value: f64,
+    axis: LocationAxis,
+    degrees: f64,
+}
+
+impl Location {
+    pub fn new(axis: LocationAxis, degrees: f64) -> result::Result<Self> {
+        if !(-axis.limit()..=axis.limit()).contains(&degrees) {
+            return Err(GenericError(format!(
+                "{:?} must be between -{} and {} degrees",
+                axis,
+                axis.limit(),
+                axis.limit()
+            )));
+        }
+        Ok(Self { axis, degrees })
+    }
+
+    pub fn latitude(degrees: f64) -> result::Result<Self> {
+        Self::new(LocationAxis::Latitude, degrees)
+    }
+
+    pub fn longitude(degrees: f64) -> result::Result<Self> {
+        Self::new(LocationAxis::Longitude, degrees)
+    }
+
+    /// Latitude and longitude of the point.
+    pub fn from_point(point: &Point) -> result::Result<(Self, Self)> {
+        Ok((
+            Self::latitude(point.latitude)?,
+            Self::longitude(point.longitude)?,
+        ))
+    }
+
+    pub fn to_point(latitude: &Location, longitude: &Location) -> result::Result<Point> {
+        if !latitude.is_latitude() || longitude.is_latitude() {
+            return Err(GenericError(
+                "A point needs a latitude and a longitude".to_string(),
+            ));
+        }
+        Ok(Point::new(longitude.degrees, latitude.degrees))
+    }
+
+    pub fn axis(&self) -> LocationAxis {
+        self.axis
+    }
+
+    pub fn is_latitude(&self) -> bool {
+        self.axis == LocationAxis::Latitude
+    }
+
+    pub fn direction(&self) -> LocationDirection {
+        match (self.axis, self.degrees < 0.0) {
+            (LocationAxis::Latitude, false) => LocationDirection::North,
+            (LocationAxis::Latitude, true) => LocationDirection::South,
+            (LocationAxis::Longitude, false) => LocationDirection::East,
+            (LocationAxis::Longitude, true) => LocationDirection::West,
+        }
+    }
+
+    pub fn to_degrees(&self) -> f64 {
+        self.degrees
+    }
 }
 
 impl AdifData for Location {
     fn serialize(&self) -> String {
-        format!("{}{} {:06.3}", self.direction, self.value as u8, self.value)
+        let thousandths = (self.degrees.abs() * 60_000.0).round() as u64;
+        format!(
+            "{}{:03} {:02}.{:03}",
+            self.direction(),
+            thousandths / 60_000,
+            thousandths % 60_000 / 1000,
+            thousandths % 1000
+        )
     }
 
-    fn deserialize(value: &str) -> crate::result::Result<Self>
+    fn deserialize(value: &str) -> result::Result<Self>
     where
         Self: Sized,
     {
-        let direction = match value.chars().next() {
-            Some('N') => LocationDirection::North,
-            Some('S') => LocationDirection::South,
-            Some('E') => LocationDirection::East,
-            Some('W') => LocationDirection::West,
+        let (axis, sign) = match value.chars().next() {
+            Some('N') => (LocationAxis::Latitude, 1.0),
+            Some('S') => (LocationAxis::Latitude, -1.0),
+            Some('E') => (LocationAxis::Longitude, 1.0),
+            Some('W') => (LocationAxis::Longitude, -1.0),
             _ => return Err(DeserializeError("Invalid location direction".to_string())),
         };
 
@@ -67,45 +154,34 @@ impl AdifData for Location {
                 "Location value must have exactly two parts".to_string(),
             ));
         };
-        if items[0].len() != 3 || !items[0].chars().all(|c| c.is_digit(10)) {
+        if items[0].len() != 3 || !items[0].chars().all(|c| c.is_ascii_digit()) {
             return Err(DeserializeError(
                 "Location value must have a 3-digit number for degrees".to_string(),
             ));
         };
-        if items[1].len() != 6 || !items[1].chars().all(|c| c.is_digit(10) || c == '.') {
+        if items[1].len() != 6
+            || items[1].as_bytes()[2] != b'.'
+            || !items[1].chars().all(|c| c.is_ascii_digit() || c == '.')
+        {
             return Err(DeserializeError(
-                "Location value must have a 6-digit number for minutes with optional decimal"
-                    .to_string(),
+                "Location value must have minutes in the MM.MMM format".to_string(),
             ));
         };
 
-        let degrees: f64 = items[0]
-            .parse::<u8>()
+        let degrees = items[0]
+            .parse::<u16>()
             .map_err(|e| DeserializeError(e.to_string()))? as f64;
-        if direction == LocationDirection::East || direction == LocationDirection::West {
-            if degrees < 0.0 || degrees > 180.0 {
-                return Err(DeserializeError(
-                    "Degrees for East/West must be between 0 and 180".to_string(),
-                ));
-            }
-        }
-        if direction == LocationDirection::North || direction == LocationDirection::South {
-            if degrees < 0.0 || degrees > 90.0 {
-                return Err(DeserializeError(
-                    "Degrees for North/South must be between 0 and 90".to_string(),
-                ));
-            }
-        }
-
-        let minutes: f64 = items[1]
+        let minutes = items[1]
             .parse::<f64>()
-            .map_err(|e| DeserializeError(e.to_string()))?
-            / 60.0;
+            .map_err(|e| DeserializeError(e.to_string()))?;
+        if minutes >= 60.0 {
+            return Err(DeserializeError(
+                "Location minutes must be less than 60".to_string(),
+            ));
+        }
 
-        Ok(Self {
-            direction,
-            value: degrees + minutes,
-        })
+        Self::new(axis, sign * (degrees + minutes / 60.0))
+            .map_err(|e| DeserializeError(format!("Invalid location '{}': {}", value, e)))
     }
 }
 
@@ -118,19 +194,82 @@ impl Display for Location {
 #[cfg(test)]
 mod tests {
     use crate::data::AdifData;
-    use crate::fields::location::Location;
+    use crate::fields::location::{Location, LocationAxis, LocationDirection};
+    use crate::utilities::maidenhead_locator::Point;
 
     #[test]
     fn test_valid() {
         let input = "N045 12.456";
-        let expected = Location {
-            direction: super::LocationDirection::North,
-            value: 45.207600f64,
-        };
+        let expected = Location::latitude(45.207600f64).unwrap();
         let actual = Location::deserialize(input).unwrap();
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn test_to_degrees() {
+        let input = Location::deserialize("W012 30.000").unwrap();
+        assert_eq!(input.to_degrees(), -12.5);
+        assert_eq!(input.direction(), LocationDirection::West);
+        assert_eq!(input.axis(), LocationAxis::Longitude);
+        let input = Location::deserialize("N045 12.456").unwrap();
+        assert_eq!(input.to_degrees(), 45.2076);
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            Location::latitude(45.2076).unwrap().serialize(),
+            "N045 12.456"
+        );
+        assert_eq!(
+            Location::longitude(-9.5).unwrap().serialize(),
+            "W009 30.000"
+        );
+        assert_eq!(
+            Location::longitude(-180.0).unwrap().serialize(),
+            "W180 00.000"
+        );
+        assert_eq!(Location::latitude(0.0).unwrap().serialize(), "N000 00.000");
+        assert_eq!(
+            Location::latitude(-12.99999999).unwrap().serialize(),
+            "S013 00.000"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for input in ["N045 12.456", "S000 00.001", "E179 59.999", "W123 04.500"] {
+            assert_eq!(Location::deserialize(input).unwrap().serialize(), input);
+        }
+    }
+
+    #[test]
+    fn test_point() {
+        let (latitude, longitude) = Location::from_point(&Point::new(9.5, -39.25)).unwrap();
+        assert_eq!(latitude.serialize(), "S039 15.000");
+        assert_eq!(longitude.serialize(), "E009 30.000");
+        assert_eq!(
+            Location::to_point(&latitude, &longitude).unwrap(),
+            Point::new(9.5, -39.25)
+        );
+        assert!(Location::to_point(&longitude, &latitude).is_err());
+    }
+
+    #[test]
+    fn test_invalid_range() {
+        assert!(Location::latitude(90.5).is_err());
+        assert!(Location::longitude(-180.5).is_err());
+        assert!(Location::latitude(f64::NAN).is_err());
+        assert!(Location::from_point(&Point::new(0.0, 91.0)).is_err());
+    }
+
+    #[test]
+    fn test_invalid_minutes() {
+        assert!(Location::deserialize("N045 60.000").is_err());
+        assert!(Location::deserialize("N045 1.2345").is_err());
+        assert!(Location::deserialize("N090 00.001").is_err());
+    }
+
     #[test]
     fn test_invalid_no_space() {
         let input = "N04512.456";
@@ -15,9 +15,15 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+pub mod callsign;
 pub mod data;
+pub mod grid_square;
 pub mod qso;
+pub mod secondary_subdivision;
 pub mod iota_ref_no;
 pub mod location;
+pub mod pota_ref;
+pub mod sota_ref;
 pub mod sponsored_award;
+pub mod wwff_ref;
 pub mod header;
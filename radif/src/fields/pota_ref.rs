@@ -0,0 +1,135 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use crate::data::AdifData;
+use crate::error::AdifError::DeserializeError;
+use crate::result;
+use std::fmt::{Display, Formatter};
+
+/// Parks on the Air reference such as `K-0817`, optionally followed by the
+/// ISO 3166-2 location of the activation, as in `K-0817@US-CA`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
+pub struct PotaRef {
+    program: String,
+    /// Digits as written, since `K-01234` and `K-1234` are different parks.
+    number: String,
+    location: Option<String>,
+}
+
+impl PotaRef {
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    /// The reference without its location.
+    pub fn park(&self) -> String {
+        format!("{}-{}", self.program, self.number)
+    }
+}
+
+impl AdifData for PotaRef {
+    fn serialize(&self) -> String {
+        match &self.location {
+            Some(location) => format!("{}@{}", self.park(), location),
+            None => self.park(),
+        }
+    }
+
+    fn deserialize(value: &str) -> result::Result<Self>
+    where
+        Self: Sized,
+    {
+        let invalid = || DeserializeError(format!("Invalid POTA reference '{}'", value));
+        let value_upper = value.trim().to_ascii_uppercase();
+        let (park, location) = match value_upper.split_once('@') {
+            Some((park, location)) => (park, Some(location)),
+            None => (value_upper.as_str(), None),
+        };
+        let (program, number) = park.split_once('-').ok_or_else(invalid)?;
+        if program.is_empty()
+            || program.len() > 4
+            || !program.chars().all(|c| c.is_ascii_alphanumeric())
+            || !(4..=5).contains(&number.len())
+            || !number.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+        if let Some(location) = location {
+            match location.split_once('-') {
+                Some((country, subdivision))
+                    if country.len() == 2
+                        && country.chars().all(|c| c.is_ascii_alphabetic())
+                        && (1..=3).contains(&subdivision.len())
+                        && subdivision.chars().all(|c| c.is_ascii_alphanumeric()) => {}
+                _ => return Err(invalid()),
+            }
+        }
+        Ok(Self {
+            program: program.to_string(),
+            number: number.to_string(),
+            location: location.map(String::from),
+        })
+    }
+}
+
+impl Display for PotaRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.serialize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let actual = PotaRef::deserialize("k-0817@us-ca").unwrap();
+        assert_eq!(actual.program(), "K");
+        assert_eq!(actual.number(), "0817");
+        assert_eq!(actual.location(), Some("US-CA"));
+        assert_eq!(actual.park(), "K-0817");
+        assert_eq!(actual.serialize(), "K-0817@US-CA");
+
+        let actual = PotaRef::deserialize("VE-10001").unwrap();
+        assert_eq!(actual.location(), None);
+        assert_eq!(actual.serialize(), "VE-10001");
+
+        let actual = PotaRef::deserialize("K-01234").unwrap();
+        assert_eq!(actual.park(), "K-01234");
+        assert_ne!(actual, PotaRef::deserialize("K-1234").unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        assert!(PotaRef::deserialize("").is_err());
+        assert!(PotaRef::deserialize("K0817").is_err());
+        assert!(PotaRef::deserialize("K-817").is_err());
+        assert!(PotaRef::deserialize("K-100001").is_err());
+        assert!(PotaRef::deserialize("K-08A7").is_err());
+        assert!(PotaRef::deserialize("K-0817@").is_err());
+        assert!(PotaRef::deserialize("K-0817@USCA").is_err());
+    }
+}
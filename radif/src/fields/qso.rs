@@ -18,15 +18,18 @@
 use crate::data::AdifData;
 use crate::enumerations::EnumerationType::{
     AntPath, ArrlSection, Band, ContestId, Continent, DxccEntityCode, Mode, MorseKeyType,
-    PropagationMode, QslRcvd, QslSent, QslVia, QsoComplete, QsoUploadStatus, Region, SubMode,
+    PrimaryAdministrativeSubdivision, PropagationMode, QslRcvd, QslSent, QslVia, QsoComplete,
+    QsoUploadStatus, Region, SubMode,
 };
 use crate::error::AdifError;
 use crate::field::FieldName;
-use crate::fields::data::DataType;
+use crate::fields::data::{DataType, DataValue};
+use crate::fields::grid_square::GridSquare;
 use radif_macros::{AdifData, AutoDisplay};
 use std::string::String;
 
 #[derive(Debug, Clone, PartialEq, Eq, AutoDisplay, AdifData)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
 #[allow(non_camel_case_types)]
 pub enum QSOFieldName {
     ADDRESS,
@@ -233,7 +236,7 @@ impl FieldName for QSOFieldName {
             QSOFieldName::CLASS => DataType::String,
             QSOFieldName::CLUBLOG_QSO_UPLOAD_DATE => DataType::Date,
             QSOFieldName::CLUBLOG_QSO_UPLOAD_STATUS => DataType::Enumeration(QsoUploadStatus),
-            QSOFieldName::CNTY => DataType::SecondarySubdivisionList,
+            QSOFieldName::CNTY => DataType::SecondarySubdivision,
             QSOFieldName::CNTY_ALT => DataType::SecondaryAdministrativeSubdivisionListAlt,
             QSOFieldName::COMMENT => DataType::String,
             QSOFieldName::COMMENT_INTL => DataType::IntlString,
@@ -293,7 +296,7 @@ impl FieldName for QSOFieldName {
             QSOFieldName::MY_ARRL_SECT => DataType::Enumeration(ArrlSection),
             QSOFieldName::MY_CITY => DataType::String,
             QSOFieldName::MY_CITY_INTL => DataType::IntlString,
-            QSOFieldName::MY_CNTY => DataType::SecondarySubdivisionList,
+            QSOFieldName::MY_CNTY => DataType::SecondarySubdivision,
             QSOFieldName::MY_CNTY_ALT => DataType::SecondaryAdministrativeSubdivisionListAlt,
             QSOFieldName::MY_COUNTRY => DataType::String,
             QSOFieldName::MY_COUNTRY_INTL => DataType::IntlString,
@@ -322,7 +325,7 @@ impl FieldName for QSOFieldName {
             QSOFieldName::MY_SIG_INFO => DataType::String,
             QSOFieldName::MY_SIG_INFO_INTL => DataType::IntlString,
             QSOFieldName::MY_SOTA_REF => DataType::SotaRef,
-            QSOFieldName::MY_STATE => DataType::String,
+            QSOFieldName::MY_STATE => DataType::Enumeration(PrimaryAdministrativeSubdivision),
             QSOFieldName::MY_STREET => DataType::String,
             QSOFieldName::MY_STREET_INTL => DataType::IntlString,
             QSOFieldName::MY_USACA_COUNTIES => DataType::SecondarySubdivisionList,
@@ -337,7 +340,7 @@ impl FieldName for QSOFieldName {
             QSOFieldName::OPERATOR => DataType::String,
             QSOFieldName::OWNER_CALLSIGN => DataType::String,
             QSOFieldName::PFX => DataType::String,
-            QSOFieldName::POTA_REF => DataType::PotaRef,
+            QSOFieldName::POTA_REF => DataType::PotaRefList,
             QSOFieldName::PRECEDENCE => DataType::String,
             QSOFieldName::PROP_MODE => DataType::Enumeration(PropagationMode),
             QSOFieldName::PUBLIC_KEY => DataType::String,
@@ -379,7 +382,7 @@ impl FieldName for QSOFieldName {
             QSOFieldName::SOTA_REF => DataType::SotaRef,
             QSOFieldName::SRX => DataType::Integer,
             QSOFieldName::SRX_STRING => DataType::String,
-            QSOFieldName::STATE => DataType::String,
+            QSOFieldName::STATE => DataType::Enumeration(PrimaryAdministrativeSubdivision),
             QSOFieldName::STATION_CALLSIGN => DataType::String,
             QSOFieldName::STX => DataType::Integer,
             QSOFieldName::STX_STRING => DataType::String,
@@ -398,4 +401,141 @@ impl FieldName for QSOFieldName {
             QSOFieldName::EOR => DataType::Null,
         }
     }
+
+    /// Out-of-range `ANT_AZ` and `ANT_EL` values are normalized on import,
+    /// as the specification asks, rather than rejected.
+    fn parse_value(&self, value: &str) -> crate::result::Result<DataValue> {
+        let value = match (self, DataValue::str_to_enum(self.get_data_type(), value)?) {
+            (QSOFieldName::ANT_AZ, DataValue::Number(azimuth))
+                if !(0.0..=360.0).contains(&azimuth) =>
+            {
+                DataValue::Number(azimuth.rem_euclid(360.0))
+            }
+            (QSOFieldName::ANT_EL, DataValue::Number(elevation))
+                if !(-90.0..=90.0).contains(&elevation) =>
+            {
+                DataValue::Number(elevation % 90.0)
+            }
+            (_, value) => value,
+        };
+        self.check_value(&value)?;
+        Ok(value)
+    }
+
+    fn check_value(&self, value: &DataValue) -> crate::result::Result<()> {
+        if let (Some((min, max)), Some(number)) = (self.value_range(), value.as_f64()) {
+            if number < min || max.is_some_and(|max| number > max) {
+                let bounds = match max {
+                    Some(max) => format!("between {} and {}", min, max),
+                    None => format!("at least {}", min),
+                };
+                return Err(AdifError::DeserializeError(format!(
+                    "{} must be {}, got '{}'",
+                    self, bounds, value
+                )));
+            }
+        }
+        match (self, value) {
+            (
+                QSOFieldName::GRIDSQUARE | QSOFieldName::MY_GRIDSQUARE,
+                DataValue::GridSquare(grid),
+            ) if grid.precision() > 8 => Err(AdifError::DeserializeError(format!(
+                "{} must have at most 8 characters, got '{}'",
+                self, grid
+            ))),
+            (
+                QSOFieldName::GRIDSQUARE_EXT | QSOFieldName::MY_GRIDSQUARE_EXT,
+                DataValue::GridSquareExt(ext),
+            ) if !GridSquare::is_valid_extension(ext) => Err(AdifError::DeserializeError(format!(
+                "{} must be a grid square extension, got '{}'",
+                self, ext
+            ))),
+            (QSOFieldName::LAT | QSOFieldName::MY_LAT, DataValue::Location(location))
+                if !location.is_latitude() =>
+            {
+                Err(AdifError::DeserializeError(format!(
+                    "{} must be a latitude, got '{}'",
+                    self, location
+                )))
+            }
+            (QSOFieldName::LON | QSOFieldName::MY_LON, DataValue::Location(location))
+                if location.is_latitude() =>
+            {
+                Err(AdifError::DeserializeError(format!(
+                    "{} must be a longitude, got '{}'",
+                    self, location
+                )))
+            }
+            (QSOFieldName::EMAIL, DataValue::String(email)) if !is_email(email) => {
+                Err(AdifError::DeserializeError(format!(
+                    "{} must be an email address, got '{}'",
+                    self, email
+                )))
+            }
+            (QSOFieldName::WEB, DataValue::String(web)) if !is_url(web) => Err(
+                AdifError::DeserializeError(format!("{} must be a URL, got '{}'", self, web)),
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl QSOFieldName {
+    /// Minimum and, when bounded, maximum allowed by the specification for
+    /// numeric fields.
+    pub fn value_range(&self) -> Option<(f64, Option<f64>)> {
+        match self {
+            QSOFieldName::AGE => Some((0.0, Some(120.0))),
+            QSOFieldName::ANT_AZ => Some((0.0, Some(360.0))),
+            QSOFieldName::ANT_EL => Some((-90.0, Some(90.0))),
+            QSOFieldName::A_INDEX => Some((0.0, Some(400.0))),
+            QSOFieldName::CQZ | QSOFieldName::MY_CQ_ZONE => Some((1.0, Some(40.0))),
+            QSOFieldName::ITUZ | QSOFieldName::MY_ITU_ZONE => Some((1.0, Some(90.0))),
+            QSOFieldName::IOTA_ISLAND_ID | QSOFieldName::MY_IOTA_ISLAND_ID => {
+                Some((1.0, Some(99999999.0)))
+            }
+            QSOFieldName::K_INDEX => Some((0.0, Some(9.0))),
+            QSOFieldName::SFI => Some((0.0, Some(300.0))),
+            QSOFieldName::FISTS
+            | QSOFieldName::FISTS_CC
+            | QSOFieldName::MY_FISTS
+            | QSOFieldName::TEN_TEN
+            | QSOFieldName::UKSMG => Some((1.0, None)),
+            QSOFieldName::DISTANCE
+            | QSOFieldName::MAX_BURSTS
+            | QSOFieldName::NR_BURSTS
+            | QSOFieldName::NR_PINGS
+            | QSOFieldName::RX_PWR
+            | QSOFieldName::TX_PWR => Some((0.0, None)),
+            _ => None,
+        }
+    }
+}
+
+fn is_email(value: &str) -> bool {
+    match value.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.is_empty()
+                && !domain.contains('@')
+                && !value.chars().any(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
+/// Absolute URL with a scheme and a host, such as `https://example.org/`.
+fn is_url(value: &str) -> bool {
+    match value.split_once("://") {
+        Some((scheme, rest)) => {
+            let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+                && !host.is_empty()
+                && !value.chars().any(char::is_whitespace)
+        }
+        None => false,
+    }
 }
@@ -0,0 +1,418 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use crate::data::AdifData;
+use crate::enumerations::dxcc_enity_code::DxccEntityCode;
+use crate::enumerations::primary_administrative_subdivision::PrimaryAdministrativeSubdivision;
+use crate::error::AdifError::DeserializeError;
+use crate::result;
+use std::fmt::{Display, Formatter};
+
+/// Entities whose counties are written after the code of their state.
+const STATE_PREFIXED: [DxccEntityCode; 3] = [
+    DxccEntityCode::UnitedStatesOfAmerica,
+    DxccEntityCode::Alaska,
+    DxccEntityCode::Hawaii,
+];
+
+/// Built-in counties: Hawaii and the US states AZ, CT, DE, MA, ME, NH, NJ,
+/// NV, RI, VT and DC. The complete ADIF tables, including the other states,
+/// Alaska boroughs, Russian districts and Japanese cities and guns, are
+/// loaded with `CountyTable`.
+const COUNTIES: [(DxccEntityCode, &str, &[&str]); 12] = [
+    (
+        DxccEntityCode::Hawaii,
+        "HI",
+        &["Hawaii", "Honolulu", "Kalawao", "Kauai", "Maui"],
+    ),
+    (
+        DxccEntityCode::UnitedStatesOfAmerica,
+        "AZ",
+        &[
+            "Apache",
+            "Cochise",
+            "Coconino",
+            "Gila",
+            "Graham",
+            "Greenlee",
+            "La Paz",
+            "Maricopa",
+            "Mohave",
+            "Navajo",
+            "Pima",
+            "Pinal",
+            "Santa Cruz",
+            "Yavapai",
+            "Yuma",
+        ],
+    ),
+    (
+        DxccEntityCode::UnitedStatesOfAmerica,
+        "CT",
+        &[
+            "Fairfield",
+            "Hartford",
+            "Litchfield",
+            "Middlesex",
+            "New Haven",
+            "New London",
+            "Tolland",
+            "Windham",
+        ],
+    ),
+    (
+        DxccEntityCode::UnitedStatesOfAmerica,
+        "DE",
+        &["Kent", "New Castle", "Sussex"],
+    ),
+    (
+        DxccEntityCode::UnitedStatesOfAmerica,
+        "MA",
+        &[
+            "Barnstable",
+            "Berkshire",
+            "Bristol",
+            "Dukes",
+            "Essex",
+            "Franklin",
+            "Hampden",
+            "Hampshire",
+            "Middlesex",
+            "Nantucket",
+            "Norfolk",
+            "Plymouth",
+            "Suffolk",
+            "Worcester",
+        ],
+    ),
+    (
+        DxccEntityCode::UnitedStatesOfAmerica,
+        "ME",
+        &[
+            "Androscoggin",
+            "Aroostook",
+            "Cumberland",
+            "Franklin",
+            "Hancock",
+            "Kennebec",
+            "Knox",
+            "Lincoln",
+            "Oxford",
+            "Penobscot",
+            "Piscataquis",
+            "Sagadahoc",
+            "Somerset",
+            "Waldo",
+            "Washington",
+            "York",
+        ],
+    ),
+    (
+        DxccEntityCode::UnitedStatesOfAmerica,
+        "NH",
+        &[
+            "Belknap",
+            "Carroll",
+            "Cheshire",
+            "Coos",
+            "Grafton",
+            "Hillsborough",
+            "Merrimack",
+            "Rockingham",
+            "Strafford",
+            "Sullivan",
+        ],
+    ),
+    (
+        DxccEntityCode::UnitedStatesOfAmerica,
+        "NJ",
+        &[
+            "Atlantic",
+            "Bergen",
+            "Burlington",
+            "Camden",
+            "Cape May",
+            "Cumberland",
+            "Essex",
+            "Gloucester",
+            "Hudson",
+            "Hunterdon",
+            "Mercer",
+            "Middlesex",
+            "Monmouth",
+            "Morris",
+            "Ocean",
+            "Passaic",
+            "Salem",
+            "Somerset",
+            "Sussex",
+            "Union",
+            "Warren",
+        ],
+    ),
+    (
+        DxccEntityCode::UnitedStatesOfAmerica,
+        "NV",
+        &[
+            "Carson City",
+            "Churchill",
+            "Clark",
+            "Douglas",
+            "Elko",
+            "Esmeralda",
+            "Eureka",
+            "Humboldt",
+            "Lander",
+            "Lincoln",
+            "Lyon",
+            "Mineral",
+            "Nye",
+            "Pershing",
+            "Storey",
+            "Washoe",
+            "White Pine",
+        ],
+    ),
+    (
+        DxccEntityCode::UnitedStatesOfAmerica,
+        "RI",
+        &["Bristol", "Kent", "Newport", "Providence", "Washington"],
+    ),
+    (
+        DxccEntityCode::UnitedStatesOfAmerica,
+        "VT",
+        &[
+            "Addison",
+            "Bennington",
+            "Caledonia",
+            "Chittenden",
+            "Essex",
+            "Franklin",
+            "Grand Isle",
+            "Lamoille",
+            "Orange",
+            "Orleans",
+            "Rutland",
+            "Washington",
+            "Windham",
+            "Windsor",
+        ],
+    ),
+    (
+        DxccEntityCode::UnitedStatesOfAmerica,
+        "DC",
+        &["District of Columbia"],
+    ),
+];
+
+/// Secondary administrative subdivision, such as `MA,Middlesex`: the code
+/// of its primary subdivision, for the entities using one, and the county.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
+pub struct SecondarySubdivision {
+    state: Option<String>,
+    county: String,
+}
+
+impl SecondarySubdivision {
+    pub fn state(&self) -> Option<&str> {
+        self.state.as_deref()
+    }
+
+    pub fn county(&self) -> &str {
+        &self.county
+    }
+
+    /// Whether the county belongs to the entity, or `None` when its state
+    /// has no built-in table and the county cannot be checked. Counties of
+    /// the USA, Alaska and Hawaii need the code of their state.
+    pub fn is_valid_for(&self, dxcc: &DxccEntityCode) -> Option<bool> {
+        self.is_valid_with(dxcc, |subdivision| {
+            COUNTIES
+                .iter()
+                .find(|(entity, code, _)| entity == dxcc && subdivision.state() == Some(*code))
+                .map(|(_, _, counties)| {
+                    counties
+                        .iter()
+                        .any(|county| county.eq_ignore_ascii_case(&subdivision.county))
+                })
+        })
+    }
+
+    /// Checks the state against the entity, then asks `lookup` whether the
+    /// county belongs to it.
+    pub(crate) fn is_valid_with<F>(&self, dxcc: &DxccEntityCode, lookup: F) -> Option<bool>
+    where
+        F: FnOnce(&Self) -> Option<bool>,
+    {
+        let valid_state = match &self.state {
+            Some(state) => !matches!(
+                PrimaryAdministrativeSubdivision::deserialize(state)
+                    .map(|subdivision| subdivision.is_valid_for(dxcc)),
+                Ok(Some(false)) | Err(_)
+            ),
+            None => !STATE_PREFIXED.contains(dxcc),
+        };
+        match valid_state {
+            true => lookup(self),
+            false => Some(false),
+        }
+    }
+}
+
+impl AdifData for SecondarySubdivision {
+    fn serialize(&self) -> String {
+        match &self.state {
+            Some(state) => format!("{},{}", state, self.county),
+            None => self.county.clone(),
+        }
+    }
+
+    fn deserialize(value: &str) -> result::Result<Self>
+    where
+        Self: Sized,
+    {
+        let (state, county) = match value.split_once(',') {
+            Some((state, county)) => (Some(state.trim()), county.trim()),
+            None => (None, value.trim()),
+        };
+        if county.is_empty()
+            || state.is_some_and(|state| {
+                state.is_empty() || !state.chars().all(|c| c.is_ascii_alphanumeric())
+            })
+        {
+            return Err(DeserializeError(format!(
+                "Invalid secondary subdivision '{}'",
+                value
+            )));
+        }
+        Ok(Self {
+            state: state.map(str::to_ascii_uppercase),
+            county: county.to_string(),
+        })
+    }
+}
+
+impl Display for SecondarySubdivision {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.serialize())
+    }
+}
+
+/// Subdivision of an alternative enumeration, as in `CNTY_ALT`, written as
+/// the enumeration name and the subdivision separated by a colon.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
+pub struct SecondarySubdivisionAlt {
+    enumeration: String,
+    subdivision: String,
+}
+
+impl SecondarySubdivisionAlt {
+    pub fn enumeration(&self) -> &str {
+        &self.enumeration
+    }
+
+    pub fn subdivision(&self) -> &str {
+        &self.subdivision
+    }
+}
+
+impl AdifData for SecondarySubdivisionAlt {
+    fn serialize(&self) -> String {
+        format!("{}:{}", self.enumeration, self.subdivision)
+    }
+
+    fn deserialize(value: &str) -> result::Result<Self>
+    where
+        Self: Sized,
+    {
+        match value.split_once(':') {
+            Some((enumeration, subdivision))
+                if !enumeration.trim().is_empty() && !subdivision.trim().is_empty() =>
+            {
+                Ok(Self {
+                    enumeration: enumeration.trim().to_string(),
+                    subdivision: subdivision.trim().to_string(),
+                })
+            }
+            _ => Err(DeserializeError(format!(
+                "Invalid alternative secondary subdivision '{}'",
+                value
+            ))),
+        }
+    }
+}
+
+impl Display for SecondarySubdivisionAlt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.serialize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let actual = SecondarySubdivision::deserialize("ma,Middlesex").unwrap();
+        assert_eq!(actual.state(), Some("MA"));
+        assert_eq!(actual.county(), "Middlesex");
+        assert_eq!(actual.serialize(), "MA,Middlesex");
+
+        let actual = SecondarySubdivision::deserialize("100101").unwrap();
+        assert_eq!(actual.state(), None);
+        assert_eq!(actual.serialize(), "100101");
+
+        assert!(SecondarySubdivision::deserialize("").is_err());
+        assert!(SecondarySubdivision::deserialize("MA,").is_err());
+        assert!(SecondarySubdivision::deserialize(",Middlesex").is_err());
+    }
+
+    #[test]
+    fn test_is_valid_for() {
+        let usa = DxccEntityCode::UnitedStatesOfAmerica;
+        let valid = |value: &str, dxcc: &DxccEntityCode| {
+            SecondarySubdivision::deserialize(value)
+                .unwrap()
+                .is_valid_for(dxcc)
+        };
+
+        assert_eq!(valid("MA,Middlesex", &usa), Some(true));
+        assert_eq!(valid("ct,new haven", &usa), Some(true));
+        assert_eq!(valid("MA,Nowhere", &usa), Some(false));
+        assert_eq!(valid("TX,Harris", &usa), None);
+        assert_eq!(valid("NV,Carson City", &usa), Some(true));
+        assert_eq!(valid("AZ,Clark", &usa), Some(false));
+        assert_eq!(valid("ON,Ottawa", &usa), Some(false));
+        assert_eq!(valid("Middlesex", &usa), Some(false));
+        assert_eq!(valid("MA,Middlesex", &DxccEntityCode::Hawaii), Some(false));
+        assert_eq!(valid("HI,Maui", &DxccEntityCode::Hawaii), Some(true));
+        assert_eq!(valid("100101", &DxccEntityCode::Japan), None);
+    }
+
+    #[test]
+    fn test_alt() {
+        let actual = SecondarySubdivisionAlt::deserialize("NZ_Regions:Auckland").unwrap();
+        assert_eq!(actual.enumeration(), "NZ_Regions");
+        assert_eq!(actual.subdivision(), "Auckland");
+        assert_eq!(actual.serialize(), "NZ_Regions:Auckland");
+
+        assert!(SecondarySubdivisionAlt::deserialize("Auckland").is_err());
+        assert!(SecondarySubdivisionAlt::deserialize("NZ_Regions:").is_err());
+    }
+}
@@ -0,0 +1,108 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use crate::data::AdifData;
+use crate::error::AdifError::DeserializeError;
+use crate::result;
+use std::fmt::{Display, Formatter};
+
+/// Summits on the Air reference such as `W6/CT-006`: association, region
+/// and summit number.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
+pub struct SotaRef {
+    association: String,
+    region: String,
+    summit: u16,
+}
+
+impl SotaRef {
+    pub fn association(&self) -> &str {
+        &self.association
+    }
+
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    pub fn summit(&self) -> u16 {
+        self.summit
+    }
+}
+
+impl AdifData for SotaRef {
+    fn serialize(&self) -> String {
+        format!("{}/{}-{:03}", self.association, self.region, self.summit)
+    }
+
+    fn deserialize(value: &str) -> result::Result<Self>
+    where
+        Self: Sized,
+    {
+        let invalid = || DeserializeError(format!("Invalid SOTA reference '{}'", value));
+        let value_upper = value.trim().to_ascii_uppercase();
+        let (association, rest) = value_upper.split_once('/').ok_or_else(invalid)?;
+        let (region, summit) = rest.split_once('-').ok_or_else(invalid)?;
+        if association.is_empty()
+            || !association.chars().all(|c| c.is_ascii_alphanumeric())
+            || region.is_empty()
+            || !region.chars().all(|c| c.is_ascii_alphanumeric())
+            || summit.len() != 3
+            || !summit.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+        Ok(Self {
+            association: association.to_string(),
+            region: region.to_string(),
+            summit: summit.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+impl Display for SotaRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.serialize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let actual = SotaRef::deserialize("w6/ct-006").unwrap();
+        assert_eq!(actual.association(), "W6");
+        assert_eq!(actual.region(), "CT");
+        assert_eq!(actual.summit(), 6);
+        assert_eq!(actual.serialize(), "W6/CT-006");
+        assert_eq!(
+            SotaRef::deserialize("IS0/IS-001").unwrap().to_string(),
+            "IS0/IS-001"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        assert!(SotaRef::deserialize("").is_err());
+        assert!(SotaRef::deserialize("W6CT-006").is_err());
+        assert!(SotaRef::deserialize("W6/CT006").is_err());
+        assert!(SotaRef::deserialize("W6/CT-06").is_err());
+        assert!(SotaRef::deserialize("/CT-006").is_err());
+        assert!(SotaRef::deserialize("W6/-006").is_err());
+    }
+}
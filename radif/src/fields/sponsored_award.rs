@@ -22,6 +22,7 @@ use radif_macros::{AdifData, AutoDisplay};
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, AdifData, AutoDisplay)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
 pub enum Sponsor {
     ADIF,
     ARI,
@@ -37,6 +38,7 @@ pub enum Sponsor {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
 pub struct SponsoredAward {
     pub sponsor: Sponsor,
     pub program: String,
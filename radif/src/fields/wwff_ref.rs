@@ -0,0 +1,102 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use crate::data::AdifData;
+use crate::error::AdifError::DeserializeError;
+use crate::result;
+use std::fmt::{Display, Formatter};
+
+/// World Wide Flora and Fauna reference such as `IFF-0123`, made of the
+/// national program and the area number.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
+pub struct WwffRef {
+    program: String,
+    number: u16,
+}
+
+impl WwffRef {
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+
+    /// The program without its `FF` suffix, usually a DXCC prefix.
+    pub fn prefix(&self) -> &str {
+        &self.program[..self.program.len() - 2]
+    }
+
+    pub fn number(&self) -> u16 {
+        self.number
+    }
+}
+
+impl AdifData for WwffRef {
+    fn serialize(&self) -> String {
+        format!("{}-{:04}", self.program, self.number)
+    }
+
+    fn deserialize(value: &str) -> result::Result<Self>
+    where
+        Self: Sized,
+    {
+        let invalid = || DeserializeError(format!("Invalid WWFF reference '{}'", value));
+        let value_upper = value.trim().to_ascii_uppercase();
+        let (program, number) = value_upper.split_once('-').ok_or_else(invalid)?;
+        if program.len() < 3
+            || !program.ends_with("FF")
+            || !program.chars().all(|c| c.is_ascii_alphanumeric())
+            || number.len() != 4
+            || !number.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+        Ok(Self {
+            program: program.to_string(),
+            number: number.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+impl Display for WwffRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.serialize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let actual = WwffRef::deserialize("iff-0123").unwrap();
+        assert_eq!(actual.program(), "IFF");
+        assert_eq!(actual.prefix(), "I");
+        assert_eq!(actual.number(), 123);
+        assert_eq!(actual.serialize(), "IFF-0123");
+        assert_eq!(WwffRef::deserialize("VKFF-0001").unwrap().prefix(), "VK");
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        assert!(WwffRef::deserialize("").is_err());
+        assert!(WwffRef::deserialize("IFF0123").is_err());
+        assert!(WwffRef::deserialize("FF-0123").is_err());
+        assert!(WwffRef::deserialize("IXX-0123").is_err());
+        assert!(WwffRef::deserialize("IFF-123").is_err());
+        assert!(WwffRef::deserialize("IFF-01234").is_err());
+    }
+}
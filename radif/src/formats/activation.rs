@@ -0,0 +1,453 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use crate::adif::{Adif, AdifItem};
+use crate::data::AdifData;
+use crate::error::AdifError::SerializeError;
+use crate::field::Field;
+use crate::fields::data::DataValue;
+use crate::fields::header::HeaderFieldName;
+use crate::fields::qso::QSOFieldName;
+use crate::header::{Header, HeaderField};
+use crate::qso::{QSOField, QSO};
+use crate::result;
+use chrono::NaiveDate;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ActivationProgram {
+    Pota,
+    Sota,
+    Wwff,
+}
+
+impl ActivationProgram {
+    /// Unique contacts needed for a valid activation.
+    pub fn required_contacts(&self) -> usize {
+        match self {
+            ActivationProgram::Pota => 10,
+            ActivationProgram::Sota => 4,
+            ActivationProgram::Wwff => 44,
+        }
+    }
+
+    /// Field holding the references of the activator.
+    pub fn my_reference_field(&self) -> QSOFieldName {
+        match self {
+            ActivationProgram::Pota => QSOFieldName::MY_POTA_REF,
+            ActivationProgram::Sota => QSOFieldName::MY_SOTA_REF,
+            ActivationProgram::Wwff => QSOFieldName::MY_WWFF_REF,
+        }
+    }
+
+    /// Field holding the references of the contacted station.
+    pub fn reference_field(&self) -> QSOFieldName {
+        match self {
+            ActivationProgram::Pota => QSOFieldName::POTA_REF,
+            ActivationProgram::Sota => QSOFieldName::SOTA_REF,
+            ActivationProgram::Wwff => QSOFieldName::WWFF_REF,
+        }
+    }
+
+    /// Whether an activation ends with the UTC day; WWFF activations of a
+    /// reference may span several days.
+    pub fn is_daily(&self) -> bool {
+        !matches!(self, ActivationProgram::Wwff)
+    }
+
+    /// References in `field`, with the value to store when the QSO is
+    /// logged for one of them alone.
+    fn references(&self, qso: &QSO, field: &QSOFieldName) -> Vec<(String, DataValue)> {
+        match qso.get_value(field) {
+            Some(DataValue::PotaRefList(references)) => references
+                .iter()
+                .map(|reference| {
+                    (
+                        reference.park(),
+                        DataValue::PotaRefList(vec![reference.clone()]),
+                    )
+                })
+                .collect(),
+            Some(DataValue::SotaRef(reference)) => {
+                vec![(reference.serialize(), DataValue::SotaRef(reference.clone()))]
+            }
+            Some(DataValue::WwffRef(reference)) => {
+                vec![(reference.serialize(), DataValue::WwffRef(reference.clone()))]
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Contacts counted once: per station, band and mode for POTA, per
+    /// station for SOTA and per station, band, mode and UTC day for WWFF.
+    fn contact_key(&self, qso: &QSO) -> Option<String> {
+        let call = qso.callsign()?.base().to_string();
+        Some(match self {
+            ActivationProgram::Pota => format!(
+                "{} {} {}",
+                call,
+                value_or_empty(qso, &QSOFieldName::BAND),
+                value_or_empty(qso, &QSOFieldName::MODE)
+            ),
+            ActivationProgram::Sota => call,
+            ActivationProgram::Wwff => format!(
+                "{} {} {} {}",
+                call,
+                value_or_empty(qso, &QSOFieldName::BAND),
+                value_or_empty(qso, &QSOFieldName::MODE),
+                value_or_empty(qso, &QSOFieldName::QSO_DATE)
+            ),
+        })
+    }
+}
+
+/// QSOs of one station from one reference, during one UTC day for POTA
+/// and SOTA.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Activation {
+    pub program: ActivationProgram,
+    pub reference: String,
+    /// UTC day of the activation, or its first day for WWFF.
+    pub date: NaiveDate,
+    pub station_callsign: Option<String>,
+    /// Records with the activator reference narrowed to `reference`.
+    pub qsos: Vec<QSO>,
+}
+
+impl Activation {
+    pub fn unique_contacts(&self) -> usize {
+        self.qsos
+            .iter()
+            .filter_map(|qso| self.program.contact_key(qso))
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.unique_contacts() >= self.program.required_contacts()
+    }
+
+    /// Park-to-park, summit-to-summit or WWFF-to-WWFF contacts.
+    pub fn reference_to_reference(&self) -> Vec<&QSO> {
+        let field = self.program.reference_field();
+        self.qsos
+            .iter()
+            .filter(|qso| qso.get_value(&field).is_some())
+            .collect()
+    }
+
+    /// Name of the log file, as `CALL@K-0817-20250101.adi` for POTA and
+    /// `CALL@IFF-0123 20250101.adi` for WWFF; `/` in calls and references
+    /// becomes `_`.
+    pub fn file_name(&self) -> String {
+        let separator = match self.program {
+            ActivationProgram::Wwff => ' ',
+            _ => '-',
+        };
+        let name = format!(
+            "{}{}{}.adi",
+            self.reference,
+            separator,
+            self.date.format("%Y%m%d")
+        );
+        match &self.station_callsign {
+            Some(call) => format!("{}@{}", call, name),
+            None => name,
+        }
+        .replace('/', "_")
+    }
+
+    pub fn to_adif(&self) -> Adif {
+        Adif {
+            header: log_header(),
+            qso: self
+                .qsos
+                .iter()
+                .map(|qso| qso.add_end_if_missing())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ActivationReport {
+    activations: Vec<Activation>,
+}
+
+impl ActivationReport {
+    /// Groups the QSOs by station callsign, activator reference and, for
+    /// daily programs, UTC date; records without `QSO_DATE` or reference
+    /// are left out. A QSO logged from several parks counts for each of
+    /// them.
+    pub fn from_adif(adif: &Adif, program: ActivationProgram) -> Self {
+        let field = program.my_reference_field();
+        let mut groups: BTreeMap<(Option<NaiveDate>, String, Option<String>), Vec<QSO>> =
+            BTreeMap::new();
+        for qso in &adif.qso {
+            let date = match qso_date(qso) {
+                Some(date) => program.is_daily().then_some(date),
+                None => continue,
+            };
+            let qso = qso.with_bands_from_frequencies();
+            let station = qso
+                .station_callsign()
+                .map(|callsign| callsign.as_str().to_string());
+            for (reference, value) in program.references(&qso, &field) {
+                groups
+                    .entry((date, reference, station.clone()))
+                    .or_default()
+                    .push(
+                        qso.set_field(&QSOField::new(field.clone(), value))
+                            .expect("a reference of the record is a valid value"),
+                    );
+            }
+        }
+
+        Self {
+            activations: groups
+                .into_iter()
+                .map(|((date, reference, station_callsign), qsos)| Activation {
+                    program,
+                    reference,
+                    date: date
+                        .or_else(|| qsos.iter().filter_map(qso_date).min())
+                        .expect("grouped QSOs have a date"),
+                    station_callsign,
+                    qsos,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn activations(&self) -> &[Activation] {
+        &self.activations
+    }
+
+    pub fn valid(&self) -> impl Iterator<Item = &Activation> {
+        self.activations
+            .iter()
+            .filter(|activation| activation.is_valid())
+    }
+
+    /// Writes one ADIF file per activation into `directory`, returning the
+    /// paths written.
+    pub fn write_files<P: AsRef<Path>>(&self, directory: P) -> result::Result<Vec<PathBuf>> {
+        self.activations
+            .iter()
+            .map(|activation| {
+                let path = directory.as_ref().join(activation.file_name());
+                std::fs::write(&path, log_content(&activation.to_adif()))
+                    .map_err(|e| SerializeError(format!("{}: {}", path.display(), e)))?;
+                Ok(path)
+            })
+            .collect()
+    }
+}
+
+fn log_header() -> Header {
+    [
+        (HeaderFieldName::ADIF_VER, "3.1.5"),
+        (HeaderFieldName::PROGRAMID, "radif"),
+        (HeaderFieldName::PROGRAMVERSION, env!("CARGO_PKG_VERSION")),
+    ]
+    .into_iter()
+    .fold(Header::default(), |header, (name, value)| {
+        header.add_field(&HeaderField::new(
+            name,
+            DataValue::String(value.to_string()),
+        ))
+    })
+    .add_end_if_missing()
+}
+
+/// File content, starting with a line of text so readers do not take the
+/// header for a record.
+fn log_content(adif: &Adif) -> String {
+    format!("Generated by radif\n{}\n", adif.serialize())
+}
+
+fn qso_date(qso: &QSO) -> Option<NaiveDate> {
+    match qso.get_value(&QSOFieldName::QSO_DATE) {
+        Some(DataValue::Date(date)) => Some(*date),
+        _ => None,
+    }
+}
+
+fn value_or_empty(qso: &QSO, name: &QSOFieldName) -> String {
+    qso.get_value(name)
+        .map(DataValue::serialize)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn qso(call: &str, date: &str, band: &str, extra: &[&str]) -> QSO {
+        let mut fields = vec![
+            format!("<CALL:{}>{}", call.len(), call),
+            format!("<QSO_DATE:8>{}", date),
+            format!("<BAND:{}>{}", band.len(), band),
+            "<MODE:2>CW".to_string(),
+            "<STATION_CALLSIGN:5>K1ABC".to_string(),
+        ];
+        fields.extend(extra.iter().map(|field| field.to_string()));
+        QSO::from_fields(&fields.iter().map(String::as_str).collect::<Vec<_>>())
+    }
+
+    fn pota_log() -> Adif {
+        let parks = "<MY_POTA_REF:13>K-0817,K-4556";
+        let mut qsos = (0..9)
+            .map(|n| qso(&format!("W{}XYZ", n), "20250101", "20m", &[parks]))
+            .collect::<Vec<_>>();
+        qsos.push(qso("W0XYZ/P", "20250101", "20m", &[parks]));
+        qsos.push(qso(
+            "W0XYZ",
+            "20250101",
+            "40m",
+            &[parks, "<POTA_REF:6>K-0001"],
+        ));
+        qsos.push(qso("N1AA", "20250102", "20m", &[parks]));
+        qsos.push(qso("N1BB", "20250102", "20m", &[]));
+        Adif {
+            qso: qsos,
+            ..Adif::default()
+        }
+    }
+
+    #[test]
+    fn test_pota() {
+        let report = ActivationReport::from_adif(&pota_log(), ActivationProgram::Pota);
+        let summary = report
+            .activations()
+            .iter()
+            .map(|a| {
+                (
+                    a.reference.as_str(),
+                    a.date.to_string(),
+                    a.qsos.len(),
+                    a.unique_contacts(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("K-0817", "2025-01-01".to_string(), 11, 10),
+                ("K-4556", "2025-01-01".to_string(), 11, 10),
+                ("K-0817", "2025-01-02".to_string(), 1, 1),
+                ("K-4556", "2025-01-02".to_string(), 1, 1),
+            ]
+        );
+        assert_eq!(report.valid().count(), 2);
+
+        let activation = &report.activations()[0];
+        assert_eq!(activation.reference_to_reference().len(), 1);
+        assert_eq!(activation.file_name(), "K1ABC@K-0817-20250101.adi");
+        assert_eq!(
+            activation.qsos[0]
+                .get_value(&QSOFieldName::MY_POTA_REF)
+                .unwrap()
+                .serialize(),
+            "K-0817"
+        );
+    }
+
+    #[test]
+    fn test_sota() {
+        let summit = "<MY_SOTA_REF:9>W6/CT-006";
+        let adif = Adif {
+            qso: vec![
+                qso("W1AA", "20250101", "20m", &[summit]),
+                qso("W1AA", "20250101", "40m", &[summit]),
+                qso(
+                    "W2AA",
+                    "20250101",
+                    "20m",
+                    &[summit, "<SOTA_REF:9>W6/CT-007"],
+                ),
+                qso("W3AA/P", "20250101", "20m", &[summit]),
+                qso("W4AA", "20250101", "20m", &[summit]),
+            ],
+            ..Adif::default()
+        };
+        let report = ActivationReport::from_adif(&adif, ActivationProgram::Sota);
+        assert_eq!(report.activations().len(), 1);
+        let activation = &report.activations()[0];
+        assert_eq!(activation.unique_contacts(), 4);
+        assert!(activation.is_valid());
+        assert_eq!(activation.reference_to_reference().len(), 1);
+        assert_eq!(activation.file_name(), "K1ABC@W6_CT-006-20250101.adi");
+    }
+
+    #[test]
+    fn test_wwff() {
+        let area = "<MY_WWFF_REF:8>iff-0123";
+        let mut qsos = (0..40)
+            .map(|n| qso(&format!("I{}AA", n), "20250101", "20m", &[area]))
+            .collect::<Vec<_>>();
+        qsos.push(qso("I0AA", "20250101", "20m", &[area]));
+        qsos.push(qso("I0AA", "20250101", "40m", &[area]));
+        qsos.push(qso(
+            "I0AA",
+            "20250102",
+            "20m",
+            &[area, "<WWFF_REF:9>ISFF-0001"],
+        ));
+        qsos.push(qso("I1AA", "20250102", "20m", &[area]));
+        let adif = Adif {
+            qso: qsos,
+            ..Adif::default()
+        };
+
+        let report = ActivationReport::from_adif(&adif, ActivationProgram::Wwff);
+        assert_eq!(report.activations().len(), 1);
+        let activation = &report.activations()[0];
+        assert_eq!(activation.reference, "IFF-0123");
+        assert_eq!(activation.date.to_string(), "2025-01-01");
+        assert_eq!(activation.qsos.len(), 44);
+        assert_eq!(activation.unique_contacts(), 43);
+        assert!(!activation.is_valid());
+        assert_eq!(activation.reference_to_reference().len(), 1);
+        assert_eq!(activation.file_name(), "K1ABC@IFF-0123 20250101.adi");
+
+        let adif = Adif {
+            qso: [adif.qso, vec![qso("I2AA", "20250102", "40m", &[area])]].concat(),
+            ..Adif::default()
+        };
+        let report = ActivationReport::from_adif(&adif, ActivationProgram::Wwff);
+        assert!(report.activations()[0].is_valid());
+    }
+
+    #[test]
+    fn test_write_files() {
+        let directory = std::env::temp_dir().join(format!("radif-pota-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let report = ActivationReport::from_adif(&pota_log(), ActivationProgram::Pota);
+        let paths = report.write_files(&directory).unwrap();
+        let content = std::fs::read_to_string(&paths[0]).unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(paths.len(), 4);
+        assert!(paths[0].ends_with("K1ABC@K-0817-20250101.adi"));
+        assert!(content.starts_with("Generated by radif\n<ADIF_VER:5>3.1.5"));
+        assert!(content.contains("<EOH>"));
+        assert_eq!(content.matches("<EOR>").count(), 11);
+        assert!(content.contains("<MY_POTA_REF:6>K-0817"));
+        assert!(!content.contains("K-4556"));
+    }
+}
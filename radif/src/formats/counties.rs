@@ -0,0 +1,162 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use crate::data::AdifData;
+use crate::enumerations::dxcc_enity_code::DxccEntityCode;
+use crate::error::AdifError::DeserializeError;
+use crate::fields::secondary_subdivision::SecondarySubdivision;
+use crate::formats::csv::split_rows;
+use crate::result;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Secondary administrative subdivisions (US counties, Alaska boroughs,
+/// Russian districts, Japanese cities and guns, ...) loaded from the tables
+/// of the ADIF specification. Entities and states missing from the loaded
+/// tables fall back to the built-in ones of `SecondarySubdivision`.
+#[derive(Debug, Clone, Default)]
+pub struct CountyTable {
+    counties: HashMap<(DxccEntityCode, Option<String>), Vec<String>>,
+}
+
+impl CountyTable {
+    pub fn load<P: AsRef<Path>>(path: P) -> result::Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| DeserializeError(format!("{}: {}", path.display(), e)))?;
+        Self::from_csv(&content)
+    }
+
+    /// Reads the secondary administrative subdivision enumeration exported
+    /// by the ADIF specification as CSV, with the `Code` and
+    /// `DXCC Entity Code` columns.
+    pub fn from_csv(value: &str) -> result::Result<Self> {
+        let mut rows = split_rows(value, ',')?.into_iter();
+        let header = rows
+            .next()
+            .ok_or_else(|| DeserializeError("Empty county table".to_string()))?;
+        let column = |name: &str| {
+            header
+                .iter()
+                .position(|cell| cell.trim().eq_ignore_ascii_case(name))
+                .ok_or_else(|| DeserializeError(format!("Missing '{}' in county table", name)))
+        };
+        let (code, dxcc) = (column("Code")?, column("DXCC Entity Code")?);
+
+        let mut table = Self::default();
+        for row in rows.filter(|row| row.iter().any(|cell| !cell.trim().is_empty())) {
+            let cell = |index: usize| row.get(index).map(String::as_str).unwrap_or_default();
+            let subdivision = SecondarySubdivision::deserialize(cell(code))?;
+            let dxcc = DxccEntityCode::deserialize(cell(dxcc).trim())?;
+            table
+                .counties
+                .entry((dxcc, subdivision.state().map(str::to_string)))
+                .or_default()
+                .push(subdivision.county().to_string());
+        }
+        Ok(table)
+    }
+
+    pub fn len(&self) -> usize {
+        self.counties.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counties.is_empty()
+    }
+
+    /// Whether the county belongs to the entity, or `None` when neither the
+    /// loaded nor the built-in tables cover its state.
+    pub fn is_valid(
+        &self,
+        subdivision: &SecondarySubdivision,
+        dxcc: &DxccEntityCode,
+    ) -> Option<bool> {
+        match self
+            .counties
+            .get(&(dxcc.clone(), subdivision.state().map(str::to_string)))
+        {
+            Some(counties) => subdivision.is_valid_with(dxcc, |subdivision| {
+                Some(
+                    counties
+                        .iter()
+                        .any(|county| county.eq_ignore_ascii_case(subdivision.county())),
+                )
+            }),
+            None => subdivision.is_valid_for(dxcc),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COUNTIES_CSV: &str = "\
+Enumeration Name,Code,Secondary Administrative Subdivision,DXCC Entity Code
+Secondary_Administrative_Subdivision,\"TX,Harris\",Harris,291
+Secondary_Administrative_Subdivision,\"TX,Travis\",Travis,291
+Secondary_Administrative_Subdivision,\"AK,Juneau\",Juneau,6
+Secondary_Administrative_Subdivision,AB-01,Maykop,54
+";
+
+    fn county(value: &str) -> SecondarySubdivision {
+        SecondarySubdivision::deserialize(value).unwrap()
+    }
+
+    #[test]
+    fn test_from_csv() {
+        let table = CountyTable::from_csv(COUNTIES_CSV).unwrap();
+        assert_eq!(table.len(), 4);
+        let usa = DxccEntityCode::UnitedStatesOfAmerica;
+        assert_eq!(table.is_valid(&county("TX,Harris"), &usa), Some(true));
+        assert_eq!(table.is_valid(&county("tx,travis"), &usa), Some(true));
+        assert_eq!(table.is_valid(&county("TX,Middlesex"), &usa), Some(false));
+        assert_eq!(table.is_valid(&county("Harris"), &usa), Some(false));
+        assert_eq!(table.is_valid(&county("MA,Middlesex"), &usa), Some(true));
+        assert_eq!(table.is_valid(&county("CA,Kern"), &usa), None);
+        assert_eq!(
+            table.is_valid(&county("AK,Juneau"), &DxccEntityCode::Alaska),
+            Some(true)
+        );
+        assert_eq!(
+            table.is_valid(&county("AB-01"), &DxccEntityCode::EuropeanRussia),
+            Some(true)
+        );
+        assert_eq!(
+            table.is_valid(&county("AB-99"), &DxccEntityCode::EuropeanRussia),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_from_csv_invalid() {
+        assert!(CountyTable::from_csv("").is_err());
+        assert!(CountyTable::from_csv("Code\n\"MA,Essex\"\n").is_err());
+        assert!(CountyTable::from_csv("Code,DXCC Entity Code\n\"MA,Essex\",x\n").is_err());
+        assert!(CountyTable::default().is_empty());
+    }
+
+    #[test]
+    fn test_load() {
+        let path = std::env::temp_dir().join(format!("radif-counties-{}.csv", std::process::id()));
+        std::fs::write(&path, COUNTIES_CSV).unwrap();
+        let table = CountyTable::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(!table.is_empty());
+        assert!(CountyTable::load(&path).is_err());
+    }
+}
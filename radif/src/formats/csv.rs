@@ -0,0 +1,436 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::adif::{Adif, AdifItem};
+use crate::data::AdifData;
+use crate::error::AdifError::{DeserializeError, SerializeError};
+use crate::field::{Field, FieldName};
+use crate::fields::data::{DataType, DataValue};
+use crate::fields::qso::QSOFieldName;
+use crate::qso::{QSOField, QSO};
+use crate::result;
+use chrono::{NaiveDate, NaiveTime};
+
+const ADIF_DATE_FORMAT: &str = "%Y%m%d";
+const ADIF_TIME_FORMAT: &str = "%H%M%S";
+const ADIF_SHORT_TIME_FORMAT: &str = "%H%M";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvCodec {
+    pub delimiter: char,
+    pub has_header: bool,
+    pub mapping: Vec<(String, QSOFieldName)>,
+    pub columns: Vec<QSOFieldName>,
+    pub date_format: String,
+    pub time_format: String,
+}
+
+impl Default for CsvCodec {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            has_header: true,
+            mapping: vec![],
+            columns: vec![],
+            date_format: ADIF_DATE_FORMAT.to_string(),
+            time_format: ADIF_TIME_FORMAT.to_string(),
+        }
+    }
+}
+
+impl CsvCodec {
+    /// Reads the QSO records of a CSV document.
+    ///
+    /// With a header row, each column is matched first against `mapping`
+    /// and then against the ADIF field names; unknown columns are skipped.
+    /// Without a header, `columns` gives the field of each column in order.
+    /// With the default `time_format`, `HHMM` times are accepted as well.
+    pub fn decode(&self, value: &str) -> result::Result<Adif> {
+        let mut rows = split_rows(value, self.delimiter)?.into_iter();
+
+        let columns: Vec<Option<QSOFieldName>> = if self.has_header {
+            rows.next()
+                .unwrap_or_default()
+                .iter()
+                .map(|name| self.column_to_field(name))
+                .collect()
+        } else {
+            self.columns.iter().cloned().map(Some).collect()
+        };
+
+        if columns.iter().all(Option::is_none) {
+            return Err(DeserializeError(
+                "No CSV column could be mapped to a QSO field".to_string(),
+            ));
+        }
+
+        let qso = rows
+            .enumerate()
+            .map(|(index, row)| {
+                let fields = columns
+                    .iter()
+                    .zip(row.iter())
+                    .filter(|(_, cell)| !cell.trim().is_empty())
+                    .filter_map(|(column, cell)| column.as_ref().map(|name| (name, cell.trim())))
+                    .map(|(name, cell)| {
+                        self.convert_cell(name, cell)
+                            .map(|value| QSOField::new(name.clone(), value))
+                            .map_err(|e| {
+                                DeserializeError(format!(
+                                    "Row {}, column {}: {}",
+                                    index + 1,
+                                    name,
+                                    e
+                                ))
+                            })
+                    })
+                    .collect::<result::Result<Vec<QSOField>>>()?;
+                QSO::try_from(fields).map(|qso| qso.add_end_if_missing())
+            })
+            .collect::<result::Result<Vec<QSO>>>()?;
+
+        Ok(Adif {
+            qso,
+            ..Adif::default()
+        })
+    }
+
+    /// Writes the selected `fields` of every QSO record as CSV, one column
+    /// per field, named after `mapping` when the field has an entry there.
+    pub fn encode(&self, adif: &Adif, fields: &[QSOFieldName]) -> result::Result<String> {
+        if fields.is_empty() {
+            return Err(SerializeError(
+                "At least one field is needed for CSV export".to_string(),
+            ));
+        }
+
+        let header = self.has_header.then(|| {
+            fields
+                .iter()
+                .map(|field| self.field_to_column(field))
+                .collect::<Vec<String>>()
+        });
+
+        let rows = adif.qso.iter().map(|qso| {
+            fields
+                .iter()
+                .map(|field| {
+                    qso.get_value(field)
+                        .map(|value| self.format_value(value))
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<String>>()
+        });
+
+        Ok(header
+            .into_iter()
+            .chain(rows)
+            .map(|row| self.join_row(&row))
+            .collect())
+    }
+
+    fn column_to_field(&self, column: &str) -> Option<QSOFieldName> {
+        let column = column.trim();
+        self.mapping
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(column))
+            .map(|(_, field)| field.clone())
+            .or_else(|| QSOFieldName::deserialize(column).ok())
+            .filter(|field| *field != QSOFieldName::EOR)
+    }
+
+    fn field_to_column(&self, field: &QSOFieldName) -> String {
+        self.mapping
+            .iter()
+            .find(|(_, item)| item == field)
+            .map(|(name, _)| name.clone())
+            .unwrap_or_else(|| field.serialize())
+    }
+
+    fn convert_cell(&self, name: &QSOFieldName, cell: &str) -> result::Result<DataValue> {
+        match name.get_data_type() {
+            DataType::Date => {
+                let date = NaiveDate::parse_from_str(cell, &self.date_format)
+                    .map_err(|e| DeserializeError(format!("Invalid date '{}': {}", cell, e)))?;
+                name.parse_value(&date.format(ADIF_DATE_FORMAT).to_string())
+            }
+            DataType::Time => {
+                let time = NaiveTime::parse_from_str(cell, &self.time_format)
+                    .or_else(|e| match self.time_format == ADIF_TIME_FORMAT {
+                        true => NaiveTime::parse_from_str(cell, ADIF_SHORT_TIME_FORMAT),
+                        false => Err(e),
+                    })
+                    .map_err(|e| DeserializeError(format!("Invalid time '{}': {}", cell, e)))?;
+                name.parse_value(&time.format(ADIF_TIME_FORMAT).to_string())
+            }
+            _ => name.parse_value(cell),
+        }
+    }
+
+    fn format_value(&self, value: &DataValue) -> String {
+        match value {
+            DataValue::Date(date) => date.format(&self.date_format).to_string(),
+            DataValue::Time(time) => time.format(&self.time_format).to_string(),
+            _ => value.serialize(),
+        }
+    }
+
+    fn join_row(&self, row: &[String]) -> String {
+        let separator = self.delimiter.to_string();
+        let line = row
+            .iter()
+            .map(|cell| {
+                if cell.contains([self.delimiter, '"', '\r', '\n']) {
+                    format!("\"{}\"", cell.replace('"', "\"\""))
+                } else {
+                    cell.clone()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(&separator);
+        format!("{}\r\n", line)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum CellState {
+    Unquoted,
+    Quoted,
+    QuoteInQuoted,
+}
+
+pub(crate) fn split_rows(value: &str, delimiter: char) -> result::Result<Vec<Vec<String>>> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut cell = String::new();
+    let mut state = CellState::Unquoted;
+
+    for c in value.chars() {
+        match state {
+            CellState::Unquoted => match c {
+                '"' if cell.is_empty() => state = CellState::Quoted,
+                '\r' => {}
+                '\n' => {
+                    row.push(std::mem::take(&mut cell));
+                    rows.push(std::mem::take(&mut row));
+                }
+                c if c == delimiter => row.push(std::mem::take(&mut cell)),
+                c => cell.push(c),
+            },
+            CellState::Quoted => match c {
+                '"' => state = CellState::QuoteInQuoted,
+                c => cell.push(c),
+            },
+            CellState::QuoteInQuoted => match c {
+                '"' => {
+                    cell.push('"');
+                    state = CellState::Quoted;
+                }
+                '\r' => state = CellState::Unquoted,
+                '\n' => {
+                    row.push(std::mem::take(&mut cell));
+                    rows.push(std::mem::take(&mut row));
+                    state = CellState::Unquoted;
+                }
+                c if c == delimiter => {
+                    row.push(std::mem::take(&mut cell));
+                    state = CellState::Unquoted;
+                }
+                c => {
+                    return Err(DeserializeError(format!(
+                        "Unexpected character '{}' after closing quote",
+                        c
+                    )))
+                }
+            },
+        }
+    }
+
+    if state == CellState::Quoted {
+        return Err(DeserializeError(
+            "Unterminated quoted CSV value".to_string(),
+        ));
+    }
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+
+    Ok(rows
+        .into_iter()
+        .filter(|row| row.iter().any(|cell| !cell.is_empty()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enumerations::band::Band;
+    use crate::enumerations::mode::Mode;
+    use crate::enumerations::Enumeration;
+
+    #[test]
+    fn test_split_rows_simple() {
+        let actual = split_rows("a,b,c\r\n1,2,3\r\n", ',').unwrap();
+        assert_eq!(actual, vec![vec!["a", "b", "c"], vec!["1", "2", "3"]]);
+    }
+
+    #[test]
+    fn test_split_rows_quoted() {
+        let actual = split_rows("\"a,b\",\"say \"\"hi\"\"\",\"x\ny\"\n1,,3", ',').unwrap();
+        assert_eq!(
+            actual,
+            vec![vec!["a,b", "say \"hi\"", "x\ny"], vec!["1", "", "3"]]
+        );
+    }
+
+    #[test]
+    fn test_split_rows_delimiter() {
+        let actual = split_rows("a;b\n1;2", ';').unwrap();
+        assert_eq!(actual, vec![vec!["a", "b"], vec!["1", "2"]]);
+    }
+
+    #[test]
+    fn test_split_rows_invalid() {
+        assert!(split_rows("\"a,b", ',').is_err());
+        assert!(split_rows("\"a\"b,c", ',').is_err());
+    }
+
+    #[test]
+    fn test_decode_auto_detect() {
+        let input = "call,qso_date,time_on,band,mode,unknown\nIS0GVH,20250705,140000,20m,CW,x\n";
+        let adif = CsvCodec::default().decode(input).unwrap();
+        assert_eq!(adif.qso_count(), 1);
+        let qso = &adif.qso[0];
+        assert_eq!(
+            qso.get_value(&QSOFieldName::CALL),
+            Some(&DataValue::String("IS0GVH".to_string()))
+        );
+        assert_eq!(
+            qso.get_value(&QSOFieldName::QSO_DATE),
+            Some(&DataValue::Date(
+                NaiveDate::from_ymd_opt(2025, 7, 5).unwrap()
+            ))
+        );
+        assert_eq!(
+            qso.get_value(&QSOFieldName::BAND),
+            Some(&DataValue::Enumeration(Enumeration::Band(Band::Band20m)))
+        );
+        assert_eq!(
+            qso.get_value(&QSOFieldName::MODE),
+            Some(&DataValue::Enumeration(Enumeration::Mode(Mode::CW)))
+        );
+        assert_eq!(qso.len(), 6);
+
+        let input = "call,time_on,time_off\nIS0GVH,1400,140130\n";
+        let adif = CsvCodec::default().decode(input).unwrap();
+        assert_eq!(
+            adif.qso[0].get_value(&QSOFieldName::TIME_ON),
+            Some(&DataValue::Time(NaiveTime::from_hms_opt(14, 0, 0).unwrap()))
+        );
+        assert_eq!(
+            adif.qso[0].get_value(&QSOFieldName::TIME_OFF),
+            Some(&DataValue::Time(
+                NaiveTime::from_hms_opt(14, 1, 30).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_decode_custom_mapping_and_formats() {
+        let codec = CsvCodec {
+            delimiter: ';',
+            mapping: vec![
+                ("Callsign".to_string(), QSOFieldName::CALL),
+                ("Date".to_string(), QSOFieldName::QSO_DATE),
+                ("UTC".to_string(), QSOFieldName::TIME_ON),
+            ],
+            date_format: "%d/%m/%Y".to_string(),
+            time_format: "%H:%M".to_string(),
+            ..CsvCodec::default()
+        };
+        let adif = codec
+            .decode("Callsign;Date;UTC\nIS0GVH;05/07/2025;14:02\n")
+            .unwrap();
+        let qso = &adif.qso[0];
+        assert_eq!(
+            qso.get_value(&QSOFieldName::QSO_DATE),
+            Some(&DataValue::Date(
+                NaiveDate::from_ymd_opt(2025, 7, 5).unwrap()
+            ))
+        );
+        assert_eq!(
+            qso.get_value(&QSOFieldName::TIME_ON),
+            Some(&DataValue::Time(NaiveTime::from_hms_opt(14, 2, 0).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_decode_without_header() {
+        let codec = CsvCodec {
+            has_header: false,
+            columns: vec![QSOFieldName::CALL, QSOFieldName::RST_SENT],
+            ..CsvCodec::default()
+        };
+        let adif = codec.decode("IS0GVH,599\nIS0XX,\n").unwrap();
+        assert_eq!(adif.qso_count(), 2);
+        assert_eq!(adif.qso[1].get_value(&QSOFieldName::RST_SENT), None);
+    }
+
+    #[test]
+    fn test_decode_invalid_value() {
+        let result = CsvCodec::default().decode("CALL,BAND\nIS0GVH,21M\n");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_decode_no_known_column() {
+        assert!(CsvCodec::default().decode("foo,bar\n1,2\n").is_err());
+    }
+
+    #[test]
+    fn test_encode_subset() {
+        let adif = CsvCodec::default()
+            .decode("CALL,QSO_DATE,COMMENT,RST_SENT\nIS0GVH,20250705,\"Hi, there\",599\n")
+            .unwrap();
+        let codec = CsvCodec {
+            mapping: vec![("Date".to_string(), QSOFieldName::QSO_DATE)],
+            date_format: "%Y-%m-%d".to_string(),
+            ..CsvCodec::default()
+        };
+        let actual = codec
+            .encode(
+                &adif,
+                &[
+                    QSOFieldName::CALL,
+                    QSOFieldName::QSO_DATE,
+                    QSOFieldName::COMMENT,
+                    QSOFieldName::NAME,
+                ],
+            )
+            .unwrap();
+        assert_eq!(
+            actual,
+            "CALL,Date,COMMENT,NAME\r\nIS0GVH,2025-07-05,\"Hi, there\",\r\n"
+        );
+    }
+
+    #[test]
+    fn test_encode_no_fields() {
+        assert!(CsvCodec::default().encode(&Adif::default(), &[]).is_err());
+    }
+}
@@ -0,0 +1,541 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use crate::data::AdifData;
+use crate::enumerations::continent::Continent;
+use crate::enumerations::dxcc_enity_code::DxccEntityCode;
+use crate::enumerations::Enumeration;
+use crate::error::AdifError::DeserializeError;
+use crate::field::Field;
+use crate::fields::callsign::Callsign;
+use crate::fields::data::DataValue;
+use crate::fields::qso::QSOFieldName;
+use crate::qso::{QSOField, QSO};
+use crate::result;
+use crate::utilities::maidenhead_locator::Point;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Entity of the AD1C country files, with the zones of the matched prefix
+/// or call when resolved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CtyEntity {
+    pub name: String,
+    pub prefix: String,
+    /// `None` for the few entries, such as `*4U1V`, with no DXCC entity.
+    pub dxcc: Option<DxccEntityCode>,
+    pub continent: Continent,
+    pub cq_zone: u8,
+    pub itu_zone: u8,
+    pub position: Point,
+    pub utc_offset: f64,
+    /// Entity only counted for the DARC WAE award, such as Sicily.
+    pub wae_only: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Overrides {
+    cq_zone: Option<u8>,
+    itu_zone: Option<u8>,
+    continent: Option<Continent>,
+    position: Option<Point>,
+    utc_offset: Option<f64>,
+}
+
+/// Callsign to entity resolver built from the `cty.dat` or `cty.csv` files
+/// published by AD1C.
+#[derive(Debug, Clone, Default)]
+pub struct CtyResolver {
+    entities: Vec<CtyEntity>,
+    prefixes: HashMap<String, (usize, Overrides)>,
+    calls: HashMap<String, (usize, Overrides)>,
+}
+
+impl CtyResolver {
+    /// Reads a country file, as `cty.csv` when its extension is `csv` and
+    /// as `cty.dat` otherwise.
+    pub fn load<P: AsRef<Path>>(path: P) -> result::Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| DeserializeError(format!("{}: {}", path.display(), e)))?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("csv") => Self::from_csv(&content),
+            _ => Self::from_dat(&content),
+        }
+    }
+
+    pub fn from_dat(value: &str) -> result::Result<Self> {
+        let mut resolver = Self::default();
+        for record in value.split(';').filter(|record| !record.trim().is_empty()) {
+            let fields = record.splitn(9, ':').map(str::trim).collect::<Vec<_>>();
+            if fields.len() != 9 {
+                return Err(DeserializeError(format!(
+                    "Invalid cty.dat record: {}",
+                    record.trim()
+                )));
+            }
+            let entity = parse_entity(
+                fields[0], fields[7], None, fields[3], fields[1], fields[2], fields[4], fields[5],
+                fields[6],
+            )?;
+            resolver.add(entity, fields[8].split([',', '\n', '\r', ' ']))?;
+        }
+        Ok(resolver)
+    }
+
+    pub fn from_csv(value: &str) -> result::Result<Self> {
+        let mut resolver = Self::default();
+        for line in value.lines().filter(|line| !line.trim().is_empty()) {
+            let fields = line.splitn(10, ',').map(str::trim).collect::<Vec<_>>();
+            if fields.len() != 10 {
+                return Err(DeserializeError(format!("Invalid cty.csv line: {}", line)));
+            }
+            let entity = parse_entity(
+                fields[1],
+                fields[0],
+                Some(fields[2]),
+                fields[3],
+                fields[4],
+                fields[5],
+                fields[6],
+                fields[7],
+                fields[8],
+            )?;
+            resolver.add(entity, fields[9].trim_end_matches(';').split(' '))?;
+        }
+        Ok(resolver)
+    }
+
+    pub fn entities(&self) -> &[CtyEntity] {
+        &self.entities
+    }
+
+    /// Entity of the callsign, with exact-call entries taking precedence
+    /// over the longest matching prefix. Maritime and aeronautical mobile
+    /// stations have no entity.
+    pub fn resolve(&self, callsign: &str) -> Option<CtyEntity> {
+        let callsign = callsign.trim().to_uppercase();
+        if let Some(found) = self.calls.get(&callsign) {
+            return Some(self.apply(found));
+        }
+
+        let callsign = Callsign::deserialize(&callsign).ok()?;
+        if callsign.is_maritime_mobile() || callsign.is_aeronautical_mobile() {
+            return None;
+        }
+        if callsign.prefix().is_none() {
+            if let Some(found) = self.calls.get(callsign.base()) {
+                return Some(self.apply(found));
+            }
+        }
+
+        let prefix = callsign.location_call();
+        (1..=prefix.len())
+            .rev()
+            .filter(|len| prefix.is_char_boundary(*len))
+            .find_map(|len| self.prefixes.get(&prefix[..len]))
+            .map(|found| self.apply(found))
+    }
+
+    /// Fills missing `DXCC`, `COUNTRY`, `CONT`, `CQZ`, `ITUZ` and `PFX` of
+    /// the QSO from its `CALL`; the record is unchanged when the call cannot
+    /// be resolved. Fails when the country file gives an invalid zone.
+    pub fn enrich(&self, qso: &QSO) -> result::Result<QSO> {
+        let callsign = match qso.callsign() {
+            Some(callsign) => callsign,
+            None => return Ok(qso.clone()),
+        };
+        let entity = match self.resolve(callsign.as_str()) {
+            Some(entity) => entity,
+            None => return Ok(qso.clone()),
+        };
+
+        let mut values = vec![
+            (
+                QSOFieldName::CONT,
+                DataValue::Enumeration(Enumeration::Continent(entity.continent)),
+            ),
+            (
+                QSOFieldName::CQZ,
+                DataValue::PositiveInteger(entity.cq_zone as u64),
+            ),
+            (
+                QSOFieldName::ITUZ,
+                DataValue::PositiveInteger(entity.itu_zone as u64),
+            ),
+        ];
+        if let Some(dxcc) = entity.dxcc {
+            values.push((
+                QSOFieldName::COUNTRY,
+                DataValue::String(dxcc.info().name.to_string()),
+            ));
+            values.push((
+                QSOFieldName::DXCC,
+                DataValue::Enumeration(Enumeration::DxccEntityCode(dxcc)),
+            ));
+        }
+        values.push((QSOFieldName::PFX, DataValue::String(callsign.wpx_prefix())));
+
+        values
+            .into_iter()
+            .try_fold(qso.clone(), |qso, (name, value)| {
+                match qso.get_value(&name) {
+                    Some(_) => Ok(qso),
+                    None => qso.set_field(&QSOField::new(name, value)),
+                }
+            })
+    }
+
+    fn add<'a, I>(&mut self, entity: CtyEntity, aliases: I) -> result::Result<()>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let index = self.entities.len();
+        self.entities.push(entity);
+        for alias in aliases.map(str::trim).filter(|alias| !alias.is_empty()) {
+            let (exact, alias) = match alias.strip_prefix('=') {
+                Some(alias) => (true, alias),
+                None => (false, alias),
+            };
+            let end = alias.find(['(', '[', '{', '<', '~']).unwrap_or(alias.len());
+            let overrides = parse_overrides(&alias[end..])?;
+            let key = alias[..end].to_uppercase();
+            match exact {
+                true => self.calls.insert(key, (index, overrides)),
+                false => self.prefixes.insert(key, (index, overrides)),
+            };
+        }
+        Ok(())
+    }
+
+    fn apply(&self, (index, overrides): &(usize, Overrides)) -> CtyEntity {
+        let entity = &self.entities[*index];
+        CtyEntity {
+            continent: overrides
+                .continent
+                .clone()
+                .unwrap_or_else(|| entity.continent.clone()),
+            cq_zone: overrides.cq_zone.unwrap_or(entity.cq_zone),
+            itu_zone: overrides.itu_zone.unwrap_or(entity.itu_zone),
+            position: overrides.position.unwrap_or(entity.position),
+            utc_offset: overrides.utc_offset.unwrap_or(entity.utc_offset),
+            ..entity.clone()
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn parse_entity(
+    name: &str,
+    prefix: &str,
+    dxcc: Option<&str>,
+    continent: &str,
+    cq_zone: &str,
+    itu_zone: &str,
+    latitude: &str,
+    longitude: &str,
+    utc_offset: &str,
+) -> result::Result<CtyEntity> {
+    let (wae_only, prefix) = match prefix.strip_prefix('*') {
+        Some(prefix) => (true, prefix.to_uppercase()),
+        None => (false, prefix.to_uppercase()),
+    };
+    let dxcc = match dxcc {
+        Some(dxcc) => Some(DxccEntityCode::deserialize(dxcc)?),
+        None => dxcc_from_prefix(&prefix, wae_only),
+    };
+    Ok(CtyEntity {
+        name: name.to_string(),
+        prefix,
+        dxcc,
+        continent: Continent::deserialize(continent)?,
+        cq_zone: parse_number(cq_zone)?,
+        itu_zone: parse_number(itu_zone)?,
+        // Country files count longitude positive to the west
+        position: Point::new(-parse_number::<f64>(longitude)?, parse_number(latitude)?),
+        utc_offset: parse_number(utc_offset)?,
+        wae_only,
+    })
+}
+
+/// `cty.dat` has no entity numbers, so they are matched on the primary
+/// prefix, which follows the same notation as `DxccEntityInfo`. WAE-only
+/// entities (e.g. `IT9` for Sicily) belong to the DXCC entity whose primary
+/// prefix starts theirs.
+fn dxcc_from_prefix(prefix: &str, wae_only: bool) -> Option<DxccEntityCode> {
+    crate::enumerations::dxcc_entity_info::DxccEntityInfo::all()
+        .iter()
+        .find(|info| !info.deleted && info.prefix.eq_ignore_ascii_case(prefix))
+        .map(|info| info.entity.clone())
+        .or_else(|| {
+            wae_only
+                .then(|| DxccEntityCode::from_primary_prefix(prefix))
+                .flatten()
+        })
+}
+
+fn parse_overrides(value: &str) -> result::Result<Overrides> {
+    let mut overrides = Overrides::default();
+    let mut rest = value;
+    while let Some(open) = rest.chars().next() {
+        let close = match open {
+            '(' => ')',
+            '[' => ']',
+            '{' => '}',
+            '<' => '>',
+            '~' => '~',
+            _ => return Err(DeserializeError(format!("Invalid cty override: {}", value))),
+        };
+        let end = rest[1..]
+            .find(close)
+            .ok_or_else(|| DeserializeError(format!("Unterminated cty override: {}", value)))?;
+        let content = &rest[1..end + 1];
+        match open {
+            '(' => overrides.cq_zone = Some(parse_number(content)?),
+            '[' => overrides.itu_zone = Some(parse_number(content)?),
+            '{' => overrides.continent = Some(Continent::deserialize(content)?),
+            '<' => {
+                let (latitude, longitude) = content.split_once('/').ok_or_else(|| {
+                    DeserializeError(format!("Invalid cty position override: {}", value))
+                })?;
+                overrides.position = Some(Point::new(
+                    -parse_number::<f64>(longitude)?,
+                    parse_number(latitude)?,
+                ));
+            }
+            _ => overrides.utc_offset = Some(parse_number(content)?),
+        }
+        rest = &rest[end + 2..];
+    }
+    Ok(overrides)
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str) -> result::Result<T> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| DeserializeError(format!("Invalid cty number: {}", value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CTY_DAT: &str = "\
+Sardinia:                 15:  28:  EU:   40.15:    -9.27:    -1.0:  IS:
+    IM0,IS,IW0U,IW0V,=II0M;
+Italy:                    15:  28:  EU:   42.82:   -12.58:    -1.0:  I:
+    I,=4U4F,
+    =IN3TJK/J;
+Sicily:                   15:  28:  EU:   37.50:   -14.00:    -1.0:  *IT9:
+    IB9,IT9,IW9;
+United States:            05:  08:  NA:   37.53:    91.67:     5.0:  K:
+    AA,K,N,W,=AH2O(3)[6],AA0(4)[7];
+Hawaii:                   31:  61:  OC:   21.12:   157.48:    10.0:  KH6:
+    AH6,KH6,NH6,WH6,=W1HI;
+Canada:                   05:  09:  NA:   44.35:    78.75:     5.0:  VE:
+    VA,VE,VE7(3)[2],VY2{NA}<46.0/63.0>~4.0~;
+";
+
+    const CTY_CSV: &str = "\
+IS,Sardinia,225,EU,15,28,40.15,-9.27,-1.0,IM0 IS IW0U =II0M;
+*IT9,Sicily,248,EU,15,28,37.50,-14.00,-1.0,IB9 IT9 IW9;
+K,United States,291,NA,5,8,37.53,91.67,5.0,AA K N W =AH2O(3)[6];
+";
+
+    #[test]
+    fn test_from_dat() {
+        let resolver = CtyResolver::from_dat(CTY_DAT).unwrap();
+        assert_eq!(resolver.entities().len(), 6);
+
+        let sardinia = resolver.resolve("is0gvh").unwrap();
+        assert_eq!(sardinia.name, "Sardinia");
+        assert_eq!(sardinia.prefix, "IS");
+        assert_eq!(sardinia.dxcc, Some(DxccEntityCode::Sardinia));
+        assert_eq!(sardinia.continent, Continent::Europe);
+        assert_eq!(sardinia.position, Point::new(9.27, 40.15));
+
+        let sicily = resolver.resolve("IT9ABC").unwrap();
+        assert!(sicily.wae_only);
+        assert_eq!(sicily.dxcc, Some(DxccEntityCode::Italy));
+    }
+
+    #[test]
+    fn test_dxcc_only_from_primary_prefix() {
+        let resolver = CtyResolver::from_dat(
+            "Foo:                      15:  28:  EU:   40.00:    -9.00:    -1.0:  IS5:\n    IS5;\n",
+        )
+        .unwrap();
+        assert_eq!(resolver.resolve("IS5ABC").unwrap().dxcc, None);
+    }
+
+    #[test]
+    fn test_from_csv() {
+        let resolver = CtyResolver::from_csv(CTY_CSV).unwrap();
+        assert_eq!(resolver.entities().len(), 3);
+        assert_eq!(
+            resolver.resolve("IS0GVH").unwrap().dxcc,
+            Some(DxccEntityCode::Sardinia)
+        );
+        assert_eq!(
+            resolver.resolve("IT9ABC").unwrap().dxcc,
+            Some(DxccEntityCode::Italy)
+        );
+        assert_eq!(resolver.resolve("AH2O").unwrap().cq_zone, 3);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(CtyResolver::from_dat("Sardinia: 15: 28: EU: 40.15;").is_err());
+        assert!(
+            CtyResolver::from_dat("Sardinia: 15: 28: XX: 40.15: -9.27: -1.0: IS:\n    IS;")
+                .is_err()
+        );
+        assert!(
+            CtyResolver::from_dat("Sardinia: 15: 28: EU: 40.15: -9.27: -1.0: IS:\n    IS(15;")
+                .is_err()
+        );
+        assert!(CtyResolver::from_csv("IS,Sardinia,9999,EU,15,28,40.15,-9.27,-1.0,IS;").is_err());
+    }
+
+    #[test]
+    fn test_exact_calls_and_overrides() {
+        let resolver = CtyResolver::from_dat(CTY_DAT).unwrap();
+
+        let exact = resolver.resolve("W1HI").unwrap();
+        assert_eq!(exact.dxcc, Some(DxccEntityCode::Hawaii));
+        assert_eq!(
+            resolver.resolve("W1HJ").unwrap().dxcc,
+            Some(DxccEntityCode::UnitedStatesOfAmerica)
+        );
+        assert_eq!(
+            resolver.resolve("4U4F").unwrap().dxcc,
+            Some(DxccEntityCode::Italy)
+        );
+        assert_eq!(
+            resolver.resolve("IN3TJK/J").unwrap().dxcc,
+            Some(DxccEntityCode::Italy)
+        );
+
+        let zones = resolver.resolve("AH2O").unwrap();
+        assert_eq!((zones.cq_zone, zones.itu_zone), (3, 6));
+        let zones = resolver.resolve("AA0XYZ").unwrap();
+        assert_eq!((zones.cq_zone, zones.itu_zone), (4, 7));
+        let zones = resolver.resolve("K1ABC").unwrap();
+        assert_eq!((zones.cq_zone, zones.itu_zone), (5, 8));
+
+        let overridden = resolver.resolve("VY2ZM").unwrap();
+        assert_eq!(overridden.continent, Continent::NorthAmerica);
+        assert_eq!(overridden.position, Point::new(-63.0, 46.0));
+        assert_eq!(overridden.utc_offset, 4.0);
+        assert_eq!(resolver.resolve("VE7ABC").unwrap().cq_zone, 3);
+    }
+
+    #[test]
+    fn test_portable() {
+        let resolver = CtyResolver::from_dat(CTY_DAT).unwrap();
+
+        assert_eq!(
+            resolver.resolve("IS0GVH/P").unwrap().dxcc,
+            Some(DxccEntityCode::Sardinia)
+        );
+        assert_eq!(
+            resolver.resolve("IS0GVH/QRP/P").unwrap().dxcc,
+            Some(DxccEntityCode::Sardinia)
+        );
+        assert_eq!(
+            resolver.resolve("KH6/IS0GVH").unwrap().dxcc,
+            Some(DxccEntityCode::Hawaii)
+        );
+        assert_eq!(
+            resolver.resolve("IS0GVH/KH6").unwrap().dxcc,
+            Some(DxccEntityCode::Hawaii)
+        );
+        assert_eq!(
+            resolver.resolve("IW2UAB/0").unwrap().dxcc,
+            Some(DxccEntityCode::Sardinia)
+        );
+        assert_eq!(resolver.resolve("IS0GVH/MM"), None);
+        assert_eq!(resolver.resolve("IS0GVH/AM"), None);
+        assert_eq!(resolver.resolve("QQ1ABC"), None);
+    }
+
+    #[test]
+    fn test_load() {
+        let path = std::env::temp_dir().join(format!("radif-cty-{}.csv", std::process::id()));
+        std::fs::write(&path, CTY_CSV).unwrap();
+        let resolver = CtyResolver::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(resolver.entities().len(), 3);
+
+        assert!(CtyResolver::load(path).is_err());
+    }
+
+    #[test]
+    fn test_enrich() {
+        let resolver =
+            CtyResolver::from_dat("Sardinia: 15: 28: EU: 40.15: -9.27: -1.0: IS:\n    IS,IM0;")
+                .unwrap();
+        let input = QSO::try_from(vec![
+            QSOField::new(
+                QSOFieldName::CALL,
+                DataValue::String("IS0GVH/P".to_string()),
+            ),
+            QSOField::new(QSOFieldName::CQZ, DataValue::PositiveInteger(14)),
+            QSOField::end(),
+        ])
+        .unwrap();
+
+        let actual = resolver.enrich(&input).unwrap();
+        assert_eq!(
+            actual.get_value(&QSOFieldName::DXCC),
+            Some(&DataValue::Enumeration(Enumeration::DxccEntityCode(
+                DxccEntityCode::Sardinia
+            )))
+        );
+        assert_eq!(
+            actual.get_value(&QSOFieldName::COUNTRY),
+            Some(&DataValue::String("SARDINIA".to_string()))
+        );
+        assert_eq!(
+            actual.get_value(&QSOFieldName::CONT),
+            Some(&DataValue::Enumeration(Enumeration::Continent(
+                Continent::Europe
+            )))
+        );
+        assert_eq!(
+            actual.get_value(&QSOFieldName::CQZ),
+            Some(&DataValue::PositiveInteger(14))
+        );
+        assert_eq!(
+            actual.get_value(&QSOFieldName::ITUZ),
+            Some(&DataValue::PositiveInteger(28))
+        );
+        assert_eq!(
+            actual.get_value(&QSOFieldName::PFX),
+            Some(&DataValue::String("IS0".to_string()))
+        );
+        assert!(actual.fields().last().unwrap().is_end());
+
+        let unknown = input
+            .set_field(&QSOField::new(
+                QSOFieldName::CALL,
+                DataValue::String("K1ABC".to_string()),
+            ))
+            .unwrap();
+        assert_eq!(resolver.enrich(&unknown).unwrap(), unknown);
+    }
+}
@@ -0,0 +1,869 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::adif::{Adif, AdifItem};
+use crate::data::AdifData;
+use crate::enumerations::band::Band;
+use crate::enumerations::mode::Mode;
+use crate::enumerations::submode::SubMode;
+use crate::enumerations::Enumeration;
+use crate::error::AdifError;
+use crate::error::AdifError::{DeserializeError, SerializeError};
+use crate::field::{Field, FieldName};
+use crate::fields::data::DataValue;
+use crate::fields::qso::QSOFieldName;
+use crate::qso::{QSOField, QSO};
+use crate::result;
+use crate::utilities::maidenhead_locator::locator_to_coordinates;
+use chrono::{NaiveDate, NaiveTime};
+use radif_macros::{AdifData, AutoDisplay};
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+const LINE_SEPARATOR: &str = "\r\n";
+
+const BANDS: [(Band, &str); 15] = [
+    (Band::Band6m, "50 MHz"),
+    (Band::Band4m, "70 MHz"),
+    (Band::Band2m, "144 MHz"),
+    (Band::Band70cm, "432 MHz"),
+    (Band::Band23cm, "1,3 GHz"),
+    (Band::Band13cm, "2,3 GHz"),
+    (Band::Band9cm, "3,4 GHz"),
+    (Band::Band6cm, "5,7 GHz"),
+    (Band::Band3cm, "10 GHz"),
+    (Band::Band1_25cm, "24 GHz"),
+    (Band::Band6mm, "47 GHz"),
+    (Band::Band4mm, "76 GHz"),
+    (Band::Band2_5mm, "122 GHz"),
+    (Band::Band2mm, "134 GHz"),
+    (Band::Band1mm, "241 GHz"),
+];
+
+const COMPUTED_KEYS: [&str; 10] = [
+    "CQSOS", "CQSOP", "CWWLS", "CWWLB", "CEXCS", "CEXCB", "CDXCS", "CDXCB", "CTOSC", "CODXC",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, AutoDisplay, AdifData)]
+#[cfg_attr(feature = "serde", derive(radif_macros::AdifSerde))]
+pub enum EdiMode {
+    #[adif("0")]
+    Unknown,
+    #[adif("1")]
+    Ssb,
+    #[adif("2")]
+    Cw,
+    #[adif("3")]
+    SsbCw,
+    #[adif("4")]
+    CwSsb,
+    #[adif("5")]
+    Am,
+    #[adif("6")]
+    Fm,
+    #[adif("7")]
+    Rtty,
+    #[adif("8")]
+    Sstv,
+    #[adif("9")]
+    Atv,
+}
+
+impl EdiMode {
+    fn from_qso(qso: &QSO) -> Self {
+        if let Some(DataValue::Enumeration(Enumeration::SubMode(SubMode::USB | SubMode::LSB))) =
+            qso.get_value(&QSOFieldName::SUBMODE)
+        {
+            return EdiMode::Ssb;
+        }
+
+        match qso.get_value(&QSOFieldName::MODE) {
+            Some(DataValue::Enumeration(Enumeration::Mode(mode))) => match mode {
+                Mode::SSB => EdiMode::Ssb,
+                Mode::CW => EdiMode::Cw,
+                Mode::AM => EdiMode::Am,
+                Mode::FM => EdiMode::Fm,
+                Mode::RTTY => EdiMode::Rtty,
+                Mode::SSTV => EdiMode::Sstv,
+                Mode::ATV => EdiMode::Atv,
+                _ => EdiMode::Unknown,
+            },
+            _ => EdiMode::Unknown,
+        }
+    }
+
+    fn to_mode(&self) -> Option<Mode> {
+        match self {
+            EdiMode::Ssb | EdiMode::SsbCw => Some(Mode::SSB),
+            EdiMode::Cw | EdiMode::CwSsb => Some(Mode::CW),
+            EdiMode::Am => Some(Mode::AM),
+            EdiMode::Fm => Some(Mode::FM),
+            EdiMode::Rtty => Some(Mode::RTTY),
+            EdiMode::Sstv => Some(Mode::SSTV),
+            EdiMode::Atv => Some(Mode::ATV),
+            EdiMode::Unknown => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EdiHeader {
+    pub contest_name: Option<String>,
+    pub dates: Option<(NaiveDate, NaiveDate)>,
+    pub call: Option<String>,
+    pub locator: Option<String>,
+    pub exchange: Option<String>,
+    pub section: Option<String>,
+    pub band: Option<Band>,
+    pub club: Option<String>,
+    pub others: Vec<(String, String)>,
+    pub remarks: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdiRecord {
+    pub date: NaiveDate,
+    pub time: NaiveTime,
+    pub call: String,
+    pub mode: EdiMode,
+    pub sent_rst: String,
+    pub sent_number: Option<u32>,
+    pub received_rst: String,
+    pub received_number: Option<u32>,
+    pub received_exchange: String,
+    pub received_locator: String,
+    pub points: u32,
+    pub new_exchange: bool,
+    pub new_locator: bool,
+    pub new_dxcc: bool,
+    pub duplicate: bool,
+}
+
+impl EdiRecord {
+    fn to_qso(&self, header: &EdiHeader) -> result::Result<QSO> {
+        let mut fields = vec![
+            QSOField::new(QSOFieldName::QSO_DATE, DataValue::Date(self.date)),
+            QSOField::new(QSOFieldName::TIME_ON, DataValue::Time(self.time)),
+            QSOField::new(QSOFieldName::CALL, DataValue::String(self.call.clone())),
+        ];
+
+        if let Some(band) = &header.band {
+            fields.push(QSOField::new(
+                QSOFieldName::BAND,
+                DataValue::Enumeration(Enumeration::Band(band.clone())),
+            ));
+        }
+        if let Some(mode) = self.mode.to_mode() {
+            fields.push(QSOField::new(
+                QSOFieldName::MODE,
+                DataValue::Enumeration(Enumeration::Mode(mode)),
+            ));
+        }
+
+        let optional_fields = [
+            (QSOFieldName::RST_SENT, self.sent_rst.clone()),
+            (QSOFieldName::STX, optional_to_string(self.sent_number)),
+            (QSOFieldName::RST_RCVD, self.received_rst.clone()),
+            (QSOFieldName::SRX, optional_to_string(self.received_number)),
+            (QSOFieldName::SRX_STRING, self.received_exchange.clone()),
+            (QSOFieldName::GRIDSQUARE, self.received_locator.clone()),
+            (
+                QSOFieldName::STATION_CALLSIGN,
+                header.call.clone().unwrap_or_default(),
+            ),
+            (
+                QSOFieldName::MY_GRIDSQUARE,
+                header.locator.clone().unwrap_or_default(),
+            ),
+        ];
+        for (name, value) in optional_fields {
+            if !value.is_empty() {
+                let value = name.parse_value(&value)?;
+                fields.push(QSOField::new(name, value));
+            }
+        }
+
+        QSO::try_from(fields).map(|qso| qso.add_end_if_missing())
+    }
+}
+
+impl AdifData for EdiRecord {
+    fn serialize(&self) -> String {
+        [
+            self.date.format("%y%m%d").to_string(),
+            self.time.format("%H%M").to_string(),
+            self.call.clone(),
+            self.mode.serialize(),
+            self.sent_rst.clone(),
+            self.sent_number
+                .map(|n| format!("{:03}", n))
+                .unwrap_or_default(),
+            self.received_rst.clone(),
+            self.received_number
+                .map(|n| format!("{:03}", n))
+                .unwrap_or_default(),
+            self.received_exchange.clone(),
+            self.received_locator.clone(),
+            self.points.to_string(),
+            flag(self.new_exchange, "N"),
+            flag(self.new_locator, "N"),
+            flag(self.new_dxcc, "N"),
+            flag(self.duplicate, "D"),
+        ]
+        .join(";")
+    }
+
+    fn deserialize(value: &str) -> result::Result<Self>
+    where
+        Self: Sized,
+    {
+        let items: Vec<&str> = value.split(';').map(str::trim).collect();
+        if items.len() < 15 {
+            return Err(DeserializeError(format!(
+                "Invalid EDI QSO record: expected 15 fields, got {}",
+                items.len()
+            )));
+        }
+
+        Ok(Self {
+            date: NaiveDate::parse_from_str(items[0], "%y%m%d")
+                .map_err(|e| DeserializeError(format!("Invalid EDI QSO date: {}", e)))?,
+            time: NaiveTime::parse_from_str(items[1], "%H%M")
+                .map_err(|e| DeserializeError(format!("Invalid EDI QSO time: {}", e)))?,
+            call: items[2].to_uppercase(),
+            mode: if items[3].is_empty() {
+                EdiMode::Unknown
+            } else {
+                EdiMode::deserialize(items[3])?
+            },
+            sent_rst: items[4].to_string(),
+            sent_number: parse_optional_number(items[5])?,
+            received_rst: items[6].to_string(),
+            received_number: parse_optional_number(items[7])?,
+            received_exchange: items[8].to_string(),
+            received_locator: items[9].to_uppercase(),
+            points: parse_optional_number(items[10])?.unwrap_or(0),
+            new_exchange: !items[11].is_empty(),
+            new_locator: !items[12].is_empty(),
+            new_dxcc: !items[13].is_empty(),
+            duplicate: !items[14].is_empty(),
+        })
+    }
+}
+
+impl Display for EdiRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.serialize())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EdiLog {
+    pub header: EdiHeader,
+    pub records: Vec<EdiRecord>,
+}
+
+impl EdiLog {
+    /// Builds an EDI log out of the QSO records, filling the header fields not
+    /// given in `header` from the log itself and computing the distance to
+    /// each station from the `MY_GRIDSQUARE` and `GRIDSQUARE` locators.
+    ///
+    /// QSO points are the distance in kilometres rounded down, plus one, as
+    /// in the IARU Region 1 VHF contest rules; duplicates and contacts
+    /// without a locator score zero.
+    pub fn from_adif(adif: &Adif, header: EdiHeader) -> result::Result<Self> {
+        let mut header = header;
+
+        if header.call.is_none() {
+            header.call = find_first(adif, &QSOFieldName::STATION_CALLSIGN)
+                .or_else(|| find_first(adif, &QSOFieldName::OPERATOR));
+        }
+        if header.locator.is_none() {
+            header.locator = find_first(adif, &QSOFieldName::MY_GRIDSQUARE);
+        }
+        if header.band.is_none() {
+            header.band =
+                adif.qso
+                    .iter()
+                    .find_map(|qso| match qso.get_value(&QSOFieldName::BAND) {
+                        Some(DataValue::Enumeration(Enumeration::Band(band))) => Some(band.clone()),
+                        _ => None,
+                    });
+        }
+        if header.dates.is_none() {
+            let dates: Vec<NaiveDate> = adif
+                .qso
+                .iter()
+                .filter_map(|qso| match qso.get_value(&QSOFieldName::QSO_DATE) {
+                    Some(DataValue::Date(date)) => Some(*date),
+                    _ => None,
+                })
+                .collect();
+            header.dates = dates
+                .iter()
+                .min()
+                .zip(dates.iter().max())
+                .map(|(first, last)| (*first, *last));
+        }
+
+        let locator = header
+            .locator
+            .clone()
+            .ok_or_else(|| SerializeError("Missing station locator for EDI log".to_string()))?;
+        let home = locator_to_coordinates(&locator.chars().take(6).collect::<String>())?;
+
+        let mut calls = HashSet::new();
+        let mut locators = HashSet::new();
+        let mut exchanges = HashSet::new();
+        let mut entities = HashSet::new();
+        let mut records = Vec::with_capacity(adif.qso.len());
+
+        for qso in &adif.qso {
+            let call = required_string(qso, &QSOFieldName::CALL)?.to_uppercase();
+            let date = match qso.get_value(&QSOFieldName::QSO_DATE) {
+                Some(DataValue::Date(date)) => *date,
+                _ => return Err(SerializeError(format!("Missing QSO_DATE for {}", call))),
+            };
+            let time = match qso.get_value(&QSOFieldName::TIME_ON) {
+                Some(DataValue::Time(time)) => *time,
+                _ => return Err(SerializeError(format!("Missing TIME_ON for {}", call))),
+            };
+            let received_locator = optional_string(qso, &QSOFieldName::GRIDSQUARE)
+                .to_uppercase()
+                .chars()
+                .take(6)
+                .collect::<String>();
+            let received_exchange = optional_string(qso, &QSOFieldName::SRX_STRING);
+
+            let duplicate = !calls.insert(call.clone());
+            let points = if duplicate || received_locator.is_empty() {
+                0
+            } else {
+                let point = locator_to_coordinates(&received_locator)?;
+                home.distance(&point).floor() as u32 + 1
+            };
+            let valid = !duplicate && points > 0;
+
+            records.push(EdiRecord {
+                date,
+                time,
+                mode: EdiMode::from_qso(qso),
+                sent_rst: optional_string(qso, &QSOFieldName::RST_SENT),
+                sent_number: optional_number(qso, &QSOFieldName::STX),
+                received_rst: optional_string(qso, &QSOFieldName::RST_RCVD),
+                received_number: optional_number(qso, &QSOFieldName::SRX),
+                new_exchange: valid
+                    && !received_exchange.is_empty()
+                    && exchanges.insert(received_exchange.clone()),
+                new_locator: valid
+                    && locators.insert(received_locator.chars().take(4).collect::<String>()),
+                new_dxcc: valid
+                    && qso
+                        .get_value(&QSOFieldName::DXCC)
+                        .is_some_and(|dxcc| entities.insert(dxcc.serialize())),
+                received_exchange,
+                received_locator,
+                points,
+                duplicate,
+                call,
+            });
+        }
+
+        Ok(Self { header, records })
+    }
+
+    pub fn to_adif(&self) -> result::Result<Adif> {
+        Ok(Adif {
+            qso: self
+                .records
+                .iter()
+                .map(|record| record.to_qso(&self.header))
+                .collect::<result::Result<Vec<QSO>>>()?,
+            ..Adif::default()
+        })
+    }
+
+    pub fn claimed_score(&self) -> u32 {
+        self.records.iter().map(|record| record.points).sum()
+    }
+
+    fn serialize_header(&self) -> Vec<String> {
+        let header = &self.header;
+        let valid = self.records.iter().filter(|r| !r.duplicate && r.points > 0);
+        let count = |predicate: fn(&EdiRecord) -> bool| {
+            self.records.iter().filter(|r| predicate(r)).count()
+        };
+        let best = valid.clone().max_by_key(|record| record.points);
+
+        let mut lines = vec![
+            "[REG1TEST;1]".to_string(),
+            format!("TName={}", header.contest_name.clone().unwrap_or_default()),
+            format!(
+                "TDate={}",
+                header
+                    .dates
+                    .map(|(first, last)| format!(
+                        "{};{}",
+                        first.format("%Y%m%d"),
+                        last.format("%Y%m%d")
+                    ))
+                    .unwrap_or_default()
+            ),
+            format!("PCall={}", header.call.clone().unwrap_or_default()),
+            format!("PWWLo={}", header.locator.clone().unwrap_or_default()),
+            format!("PExch={}", header.exchange.clone().unwrap_or_default()),
+            format!("PSect={}", header.section.clone().unwrap_or_default()),
+            format!(
+                "PBand={}",
+                header
+                    .band
+                    .as_ref()
+                    .and_then(band_to_edi)
+                    .unwrap_or_default()
+            ),
+            format!("PClub={}", header.club.clone().unwrap_or_default()),
+        ];
+        lines.extend(
+            header
+                .others
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value)),
+        );
+        lines.extend([
+            format!("CQSOs={};1", valid.clone().count()),
+            format!("CQSOP={}", self.claimed_score()),
+            format!("CWWLs={};0;1", count(|r| r.new_locator)),
+            "CWWLB=0".to_string(),
+            format!("CExcs={};0;1", count(|r| r.new_exchange)),
+            "CExcB=0".to_string(),
+            format!("CDXCs={};0;1", count(|r| r.new_dxcc)),
+            "CDXCB=0".to_string(),
+            format!("CToSc={}", self.claimed_score()),
+            format!(
+                "CODXC={}",
+                best.map(|r| format!("{};{};{}", r.call, r.received_locator, r.points))
+                    .unwrap_or_default()
+            ),
+        ]);
+        lines
+    }
+}
+
+impl AdifData for EdiLog {
+    fn serialize(&self) -> String {
+        self.serialize_header()
+            .into_iter()
+            .chain(std::iter::once(format!(
+                "[Remarks]{}",
+                self.header
+                    .remarks
+                    .iter()
+                    .map(|remark| format!("{}{}", LINE_SEPARATOR, remark))
+                    .collect::<String>()
+            )))
+            .chain(std::iter::once(format!(
+                "[QSORecords;{}]",
+                self.records.len()
+            )))
+            .chain(self.records.iter().map(EdiRecord::serialize))
+            .map(|line| format!("{}{}", line, LINE_SEPARATOR))
+            .collect()
+    }
+
+    fn deserialize(value: &str) -> result::Result<Self>
+    where
+        Self: Sized,
+    {
+        #[derive(PartialEq)]
+        enum Section {
+            Header,
+            Remarks,
+            Records,
+            Other,
+        }
+
+        let mut section = None;
+        let mut log = EdiLog::default();
+
+        for line in value.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            if line.starts_with('[') && line.ends_with(']') {
+                let name = line[1..line.len() - 1].split(';').next().unwrap_or("");
+                section = Some(match name.to_uppercase().as_str() {
+                    "REG1TEST" => Section::Header,
+                    "REMARKS" => Section::Remarks,
+                    "QSORECORDS" => Section::Records,
+                    _ => Section::Other,
+                });
+                continue;
+            }
+
+            match section {
+                None => {
+                    return Err(DeserializeError(
+                        "Invalid EDI file: missing [REG1TEST;1] section".to_string(),
+                    ))
+                }
+                Some(Section::Header) => parse_header_line(&mut log.header, line)?,
+                Some(Section::Remarks) => log.header.remarks.push(line.to_string()),
+                Some(Section::Records) => log.records.push(EdiRecord::deserialize(line)?),
+                Some(Section::Other) => {}
+            }
+        }
+
+        if section.is_none() {
+            return Err(DeserializeError("Empty EDI file".to_string()));
+        }
+
+        Ok(log)
+    }
+}
+
+impl Display for EdiLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.serialize())
+    }
+}
+
+impl TryFrom<&EdiLog> for Adif {
+    type Error = AdifError;
+
+    fn try_from(value: &EdiLog) -> Result<Self, Self::Error> {
+        value.to_adif()
+    }
+}
+
+fn parse_header_line(header: &mut EdiHeader, line: &str) -> result::Result<()> {
+    let (key, value) = line
+        .split_once('=')
+        .ok_or_else(|| DeserializeError(format!("Invalid EDI header line: {}", line)))?;
+    let value = value.trim();
+    let optional = || (!value.is_empty()).then(|| value.to_string());
+
+    match key.trim().to_uppercase().as_str() {
+        "TNAME" => header.contest_name = optional(),
+        "TDATE" => {
+            header.dates = match value.split_once(';') {
+                Some((first, last)) => Some((parse_header_date(first)?, parse_header_date(last)?)),
+                None if value.is_empty() => None,
+                None => {
+                    let date = parse_header_date(value)?;
+                    Some((date, date))
+                }
+            }
+        }
+        "PCALL" => header.call = optional(),
+        "PWWLO" => header.locator = optional(),
+        "PEXCH" => header.exchange = optional(),
+        "PSECT" => header.section = optional(),
+        "PBAND" => header.band = edi_to_band(value),
+        "PCLUB" => header.club = optional(),
+        key if COMPUTED_KEYS.contains(&key) => {}
+        _ => header
+            .others
+            .push((key.trim().to_string(), value.to_string())),
+    }
+
+    Ok(())
+}
+
+fn parse_header_date(value: &str) -> result::Result<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y%m%d")
+        .map_err(|e| DeserializeError(format!("Invalid EDI contest date: {}", e)))
+}
+
+fn parse_optional_number(value: &str) -> result::Result<Option<u32>> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+    value
+        .parse::<u32>()
+        .map(Some)
+        .map_err(|e| DeserializeError(format!("Invalid number '{}': {}", value, e)))
+}
+
+fn band_to_edi(band: &Band) -> Option<&'static str> {
+    BANDS
+        .iter()
+        .find(|(item, _)| item == band)
+        .map(|(_, edi)| *edi)
+}
+
+fn edi_to_band(value: &str) -> Option<Band> {
+    let normalized = value.replace('.', ",").to_uppercase();
+    BANDS
+        .iter()
+        .find(|(_, edi)| edi.to_uppercase() == normalized)
+        .map(|(band, _)| band.clone())
+}
+
+fn flag(value: bool, marker: &str) -> String {
+    if value {
+        marker.to_string()
+    } else {
+        String::new()
+    }
+}
+
+fn optional_to_string(value: Option<u32>) -> String {
+    value.map(|n| n.to_string()).unwrap_or_default()
+}
+
+fn find_first(adif: &Adif, name: &QSOFieldName) -> Option<String> {
+    adif.qso
+        .iter()
+        .find_map(|qso| qso.get_value(name).map(DataValue::serialize))
+}
+
+fn optional_string(qso: &QSO, name: &QSOFieldName) -> String {
+    qso.get_value(name)
+        .map(DataValue::serialize)
+        .unwrap_or_default()
+}
+
+fn required_string(qso: &QSO, name: &QSOFieldName) -> result::Result<String> {
+    qso.get_value(name)
+        .map(DataValue::serialize)
+        .ok_or_else(|| SerializeError(format!("Missing {} in QSO record", name)))
+}
+
+fn optional_number(qso: &QSO, name: &QSOFieldName) -> Option<u32> {
+    match qso.get_value(name) {
+        Some(DataValue::Integer(n)) => u32::try_from(*n).ok(),
+        Some(DataValue::PositiveInteger(n)) => u32::try_from(*n).ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fields::grid_square::GridSquare;
+
+    const SAMPLE: &str = "[REG1TEST;1]\r\n\
+        TName=Test Contest\r\n\
+        TDate=20250705;20250706\r\n\
+        PCall=IS0GVH\r\n\
+        PWWLo=JM49SK\r\n\
+        PExch=\r\n\
+        PSect=SINGLE\r\n\
+        PBand=144 MHz\r\n\
+        PClub=\r\n\
+        SPowe=100\r\n\
+        CQSOs=2;1\r\n\
+        CToSc=380\r\n\
+        [Remarks]\r\n\
+        Portable operation\r\n\
+        [QSORecords;2]\r\n\
+        250705;1400;IS0ABC;1;59;001;59;012;;JM49TK;8;;N;;\r\n\
+        250705;1402;I0XYZ;2;599;002;599;034;;JN61FW;372;;N;;\r\n";
+
+    fn qso(call: &str, locator: &str) -> QSO {
+        QSO::try_from(vec![
+            QSOField::new(QSOFieldName::CALL, DataValue::String(call.to_string())),
+            QSOField::new(
+                QSOFieldName::QSO_DATE,
+                DataValue::Date(NaiveDate::from_ymd_opt(2025, 7, 5).unwrap()),
+            ),
+            QSOField::new(
+                QSOFieldName::TIME_ON,
+                DataValue::Time(NaiveTime::from_hms_opt(14, 0, 0).unwrap()),
+            ),
+            QSOField::new(
+                QSOFieldName::BAND,
+                DataValue::Enumeration(Enumeration::Band(Band::Band2m)),
+            ),
+            QSOField::new(
+                QSOFieldName::MODE,
+                DataValue::Enumeration(Enumeration::Mode(Mode::SSB)),
+            ),
+            QSOField::new(QSOFieldName::RST_SENT, DataValue::String("59".to_string())),
+            QSOField::new(QSOFieldName::STX, DataValue::Integer(1)),
+            QSOField::new(QSOFieldName::RST_RCVD, DataValue::String("59".to_string())),
+            QSOField::new(QSOFieldName::SRX, DataValue::Integer(12)),
+            QSOField::new(
+                QSOFieldName::GRIDSQUARE,
+                DataValue::GridSquare(GridSquare::deserialize(locator).unwrap()),
+            ),
+            QSOField::new(
+                QSOFieldName::MY_GRIDSQUARE,
+                DataValue::GridSquare(GridSquare::deserialize("JM49SK").unwrap()),
+            ),
+            QSOField::new(
+                QSOFieldName::STATION_CALLSIGN,
+                DataValue::String("IS0GVH".to_string()),
+            ),
+            QSOField::end(),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_record_serialize() {
+        let record = EdiRecord {
+            date: NaiveDate::from_ymd_opt(2025, 7, 5).unwrap(),
+            time: NaiveTime::from_hms_opt(14, 2, 0).unwrap(),
+            call: "I0XYZ".to_string(),
+            mode: EdiMode::Cw,
+            sent_rst: "599".to_string(),
+            sent_number: Some(2),
+            received_rst: "599".to_string(),
+            received_number: Some(34),
+            received_exchange: "".to_string(),
+            received_locator: "JN61FW".to_string(),
+            points: 372,
+            new_exchange: false,
+            new_locator: true,
+            new_dxcc: false,
+            duplicate: false,
+        };
+        assert_eq!(
+            record.serialize(),
+            "250705;1402;I0XYZ;2;599;002;599;034;;JN61FW;372;;N;;"
+        );
+    }
+
+    #[test]
+    fn test_record_deserialize() {
+        let input = "250705;1400;is0abc;1;59;001;59;012;;jm49tk;8;;N;;D";
+        let actual = EdiRecord::deserialize(input).unwrap();
+        assert_eq!(actual.call, "IS0ABC");
+        assert_eq!(actual.mode, EdiMode::Ssb);
+        assert_eq!(actual.sent_number, Some(1));
+        assert_eq!(actual.received_number, Some(12));
+        assert_eq!(actual.received_locator, "JM49TK");
+        assert_eq!(actual.points, 8);
+        assert!(actual.new_locator);
+        assert!(actual.duplicate);
+    }
+
+    #[test]
+    fn test_record_deserialize_invalid() {
+        assert!(EdiRecord::deserialize("").is_err());
+        assert!(EdiRecord::deserialize("250705;1400;IS0ABC").is_err());
+        assert!(EdiRecord::deserialize("2507;1400;IS0ABC;1;59;001;59;012;;JM49TK;8;;;;").is_err());
+        assert!(
+            EdiRecord::deserialize("250705;1400;IS0ABC;X;59;001;59;012;;JM49TK;8;;;;").is_err()
+        );
+    }
+
+    #[test]
+    fn test_log_deserialize() {
+        let log = EdiLog::deserialize(SAMPLE).unwrap();
+        assert_eq!(log.header.contest_name, Some("Test Contest".to_string()));
+        assert_eq!(log.header.call, Some("IS0GVH".to_string()));
+        assert_eq!(log.header.locator, Some("JM49SK".to_string()));
+        assert_eq!(log.header.band, Some(Band::Band2m));
+        assert_eq!(
+            log.header.others,
+            vec![("SPowe".to_string(), "100".to_string())]
+        );
+        assert_eq!(log.header.remarks, vec!["Portable operation".to_string()]);
+        assert_eq!(log.records.len(), 2);
+        assert_eq!(log.claimed_score(), 380);
+    }
+
+    #[test]
+    fn test_log_deserialize_invalid() {
+        assert!(EdiLog::deserialize("").is_err());
+        assert!(EdiLog::deserialize("TName=Test").is_err());
+    }
+
+    #[test]
+    fn test_log_round_trip() {
+        let log = EdiLog::deserialize(SAMPLE).unwrap();
+        let actual = EdiLog::deserialize(&log.serialize()).unwrap();
+        assert_eq!(log, actual);
+    }
+
+    #[test]
+    fn test_log_to_adif() {
+        let adif = EdiLog::deserialize(SAMPLE).unwrap().to_adif().unwrap();
+        assert_eq!(adif.qso_count(), 2);
+        let qso = &adif.qso[1];
+        assert_eq!(
+            qso.get_value(&QSOFieldName::CALL),
+            Some(&DataValue::String("I0XYZ".to_string()))
+        );
+        assert_eq!(
+            qso.get_value(&QSOFieldName::MODE),
+            Some(&DataValue::Enumeration(Enumeration::Mode(Mode::CW)))
+        );
+        assert_eq!(
+            qso.get_value(&QSOFieldName::BAND),
+            Some(&DataValue::Enumeration(Enumeration::Band(Band::Band2m)))
+        );
+        assert_eq!(
+            qso.get_value(&QSOFieldName::GRIDSQUARE),
+            Some(&DataValue::GridSquare(
+                GridSquare::deserialize("JN61FW").unwrap()
+            ))
+        );
+        assert_eq!(
+            qso.get_value(&QSOFieldName::SRX),
+            Some(&DataValue::Integer(34))
+        );
+        assert_eq!(
+            qso.get_value(&QSOFieldName::MY_GRIDSQUARE),
+            Some(&DataValue::GridSquare(
+                GridSquare::deserialize("JM49SK").unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_log_from_adif() {
+        let adif = Adif {
+            qso: vec![qso("IS0ABC", "JM49TK"), qso("I0XYZ", "JN61FW")],
+            ..Adif::default()
+        };
+        let log = EdiLog::from_adif(&adif, EdiHeader::default()).unwrap();
+        assert_eq!(log.header.call, Some("IS0GVH".to_string()));
+        assert_eq!(log.header.locator, Some("JM49SK".to_string()));
+        assert_eq!(log.header.band, Some(Band::Band2m));
+        assert_eq!(log.records[0].points, 8);
+        assert_eq!(log.records[1].points, 372);
+        assert_eq!(log.records[0].mode, EdiMode::Ssb);
+        assert!(log.records.iter().all(|record| record.new_locator));
+        assert_eq!(log.claimed_score(), 380);
+    }
+
+    #[test]
+    fn test_log_from_adif_duplicate() {
+        let adif = Adif {
+            qso: vec![qso("IS0ABC", "JM49TK"), qso("IS0ABC", "JM49TK")],
+            ..Adif::default()
+        };
+        let log = EdiLog::from_adif(&adif, EdiHeader::default()).unwrap();
+        assert!(!log.records[0].duplicate);
+        assert!(log.records[1].duplicate);
+        assert_eq!(log.records[1].points, 0);
+        assert!(!log.records[1].new_locator);
+    }
+
+    #[test]
+    fn test_log_from_adif_missing_locator() {
+        let adif = Adif {
+            qso: vec![QSO::try_from(vec![QSOField::new(
+                QSOFieldName::CALL,
+                DataValue::String("IS0ABC".to_string()),
+            )])
+            .unwrap()],
+            ..Adif::default()
+        };
+        assert!(EdiLog::from_adif(&adif, EdiHeader::default()).is_err());
+    }
+}
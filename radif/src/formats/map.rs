@@ -0,0 +1,370 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::adif::Adif;
+use crate::data::AdifData;
+use crate::fields::data::DataValue;
+use crate::fields::qso::QSOFieldName;
+use crate::utilities::maidenhead_locator::Point;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapExporter {
+    pub properties: Vec<QSOFieldName>,
+    pub great_circle: bool,
+    pub great_circle_segments: usize,
+}
+
+impl Default for MapExporter {
+    fn default() -> Self {
+        Self {
+            properties: vec![
+                QSOFieldName::CALL,
+                QSOFieldName::QSO_DATE,
+                QSOFieldName::TIME_ON,
+                QSOFieldName::BAND,
+                QSOFieldName::MODE,
+            ],
+            great_circle: false,
+            great_circle_segments: 64,
+        }
+    }
+}
+
+struct Contact {
+    name: Option<String>,
+    position: Point,
+    path: Option<Vec<Point>>,
+    properties: Vec<(String, String)>,
+}
+
+impl MapExporter {
+    /// Builds a GeoJSON `FeatureCollection` with one `Point` feature per
+    /// located QSO, followed by its great-circle `LineString` when enabled.
+    pub fn to_geojson(&self, adif: &Adif) -> String {
+        let features = self
+            .contacts(adif)
+            .iter()
+            .flat_map(|contact| {
+                let properties = geojson_properties(&contact.properties);
+                let point = format!(
+                    r#"{{"type":"Feature","geometry":{{"type":"Point","coordinates":{}}},"properties":{}}}"#,
+                    geojson_position(&contact.position),
+                    properties
+                );
+                let line = contact.path.as_ref().map(|path| {
+                    format!(
+                        r#"{{"type":"Feature","geometry":{{"type":"LineString","coordinates":[{}]}},"properties":{}}}"#,
+                        path.iter()
+                            .map(geojson_position)
+                            .collect::<Vec<String>>()
+                            .join(","),
+                        properties
+                    )
+                });
+                std::iter::once(point).chain(line)
+            })
+            .collect::<Vec<String>>();
+
+        format!(
+            r#"{{"type":"FeatureCollection","features":[{}]}}"#,
+            features.join(",")
+        )
+    }
+
+    /// Builds a KML document with one `Placemark` per located QSO, named
+    /// after the contacted call, plus a tessellated path when enabled.
+    pub fn to_kml(&self, adif: &Adif) -> String {
+        let placemarks = self
+            .contacts(adif)
+            .iter()
+            .map(|contact| {
+                let name = contact
+                    .name
+                    .as_ref()
+                    .map(|name| format!("<name>{}</name>", xml_escape(name)))
+                    .unwrap_or_default();
+                let data = contact
+                    .properties
+                    .iter()
+                    .map(|(key, value)| {
+                        format!(
+                            r#"<Data name="{}"><value>{}</value></Data>"#,
+                            xml_escape(key),
+                            xml_escape(value)
+                        )
+                    })
+                    .collect::<String>();
+                let geometry = match &contact.path {
+                    Some(path) => format!(
+                        "<MultiGeometry><Point><coordinates>{}</coordinates></Point><LineString><tessellate>1</tessellate><coordinates>{}</coordinates></LineString></MultiGeometry>",
+                        kml_position(&contact.position),
+                        path.iter()
+                            .map(kml_position)
+                            .collect::<Vec<String>>()
+                            .join(" ")
+                    ),
+                    None => format!(
+                        "<Point><coordinates>{}</coordinates></Point>",
+                        kml_position(&contact.position)
+                    ),
+                };
+                format!(
+                    "<Placemark>{}<ExtendedData>{}</ExtendedData>{}</Placemark>\n",
+                    name, data, geometry
+                )
+            })
+            .collect::<String>();
+
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<kml xmlns=\"http://www.opengis.net/kml/2.2\">\n<Document>\n{}</Document>\n</kml>\n",
+            placemarks
+        )
+    }
+
+    fn contacts(&self, adif: &Adif) -> Vec<Contact> {
+        let mut contacts = vec![];
+
+        for qso in &adif.qso {
+            let position = match qso.position() {
+                Some(position) => position,
+                None => continue,
+            };
+
+            let station = if self.great_circle {
+                qso.my_position()
+            } else {
+                None
+            };
+
+            let segments = self.great_circle_segments.max(1);
+            let path = station.map(|station| {
+                (0..=segments)
+                    .map(|i| station.intermediate(&position, i as f64 / segments as f64))
+                    .collect()
+            });
+
+            contacts.push(Contact {
+                name: qso.get_value(&QSOFieldName::CALL).map(DataValue::serialize),
+                position,
+                path,
+                properties: self
+                    .properties
+                    .iter()
+                    .filter_map(|name| {
+                        qso.get_value(name)
+                            .map(|value| (name.to_string(), value.serialize()))
+                    })
+                    .collect(),
+            });
+        }
+
+        contacts
+    }
+}
+
+fn geojson_position(point: &Point) -> String {
+    format!("[{:.6},{:.6}]", point.longitude, point.latitude)
+}
+
+fn geojson_properties(properties: &[(String, String)]) -> String {
+    format!(
+        "{{{}}}",
+        properties
+            .iter()
+            .map(|(key, value)| format!("{}:{}", json_string(key), json_string(value)))
+            .collect::<Vec<String>>()
+            .join(",")
+    )
+}
+
+fn kml_position(point: &Point) -> String {
+    format!("{:.6},{:.6}", point.longitude, point.latitude)
+}
+
+fn json_string(value: &str) -> String {
+    let escaped = value
+        .chars()
+        .map(|c| match c {
+            '"' => "\\\"".to_string(),
+            '\\' => "\\\\".to_string(),
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            c if c.is_control() => format!("\\u{:04x}", c as u32),
+            c => c.to_string(),
+        })
+        .collect::<String>();
+    format!("\"{}\"", escaped)
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adif::AdifItem;
+    use crate::field::Field;
+    use crate::fields::grid_square::GridSquare;
+    use crate::fields::location::Location;
+    use crate::header::Header;
+    use crate::qso::{QSOField, QSO};
+
+    fn adif(qso: Vec<QSO>) -> Adif {
+        Adif {
+            header: Header::default(),
+            qso,
+        }
+    }
+
+    fn qso(fields: Vec<(QSOFieldName, DataValue)>) -> QSO {
+        fields
+            .into_iter()
+            .fold(QSO::default(), |qso, (name, value)| {
+                qso.add_field(&QSOField::new(name, value))
+            })
+            .add_end_if_missing()
+    }
+
+    #[test]
+    fn test_geojson_gridsquare() {
+        let input = adif(vec![qso(vec![
+            (QSOFieldName::CALL, DataValue::String("IS0GVH".to_string())),
+            (
+                QSOFieldName::GRIDSQUARE,
+                DataValue::GridSquare(GridSquare::deserialize("JM49").unwrap()),
+            ),
+        ])]);
+        let exporter = MapExporter {
+            properties: vec![QSOFieldName::CALL],
+            ..Default::default()
+        };
+        let actual = exporter.to_geojson(&input);
+        assert_eq!(
+            actual,
+            r#"{"type":"FeatureCollection","features":[{"type":"Feature","geometry":{"type":"Point","coordinates":[9.000000,39.500000]},"properties":{"CALL":"IS0GVH"}}]}"#
+        );
+    }
+
+    #[test]
+    fn test_geojson_location_before_gridsquare() {
+        let input = adif(vec![qso(vec![
+            (QSOFieldName::CALL, DataValue::String("K1\"A".to_string())),
+            (
+                QSOFieldName::LAT,
+                DataValue::Location(Location::deserialize("S012 30.000").unwrap()),
+            ),
+            (
+                QSOFieldName::LON,
+                DataValue::Location(Location::deserialize("W045 15.000").unwrap()),
+            ),
+            (
+                QSOFieldName::GRIDSQUARE,
+                DataValue::GridSquare(GridSquare::deserialize("JM49").unwrap()),
+            ),
+        ])]);
+        let exporter = MapExporter {
+            properties: vec![QSOFieldName::CALL, QSOFieldName::BAND],
+            ..Default::default()
+        };
+        let actual = exporter.to_geojson(&input);
+        assert!(actual.contains(r#""coordinates":[-45.250000,-12.500000]"#));
+        assert!(actual.contains(r#""properties":{"CALL":"K1\"A"}"#));
+    }
+
+    #[test]
+    fn test_geojson_skips_unlocated() {
+        let input = adif(vec![qso(vec![(
+            QSOFieldName::CALL,
+            DataValue::String("IS0GVH".to_string()),
+        )])]);
+        let actual = MapExporter::default().to_geojson(&input);
+        assert_eq!(actual, r#"{"type":"FeatureCollection","features":[]}"#);
+    }
+
+    #[test]
+    fn test_geojson_great_circle() {
+        let input = adif(vec![qso(vec![
+            (
+                QSOFieldName::GRIDSQUARE,
+                DataValue::GridSquare(GridSquare::deserialize("JN61").unwrap()),
+            ),
+            (
+                QSOFieldName::MY_GRIDSQUARE,
+                DataValue::GridSquare(GridSquare::deserialize("JM49").unwrap()),
+            ),
+        ])]);
+        let exporter = MapExporter {
+            great_circle: true,
+            great_circle_segments: 4,
+            ..Default::default()
+        };
+        let actual = exporter.to_geojson(&input);
+        assert!(actual.contains(r#""type":"LineString","coordinates":[[9.000000,39.500000],"#));
+        assert!(actual.contains(r#",[13.000000,41.500000]]"#));
+        assert_eq!(actual.matches("],[").count(), 4);
+    }
+
+    #[test]
+    fn test_kml() {
+        let input = adif(vec![qso(vec![
+            (QSOFieldName::CALL, DataValue::String("IS0GVH".to_string())),
+            (
+                QSOFieldName::GRIDSQUARE,
+                DataValue::GridSquare(GridSquare::deserialize("JM49").unwrap()),
+            ),
+        ])]);
+        let exporter = MapExporter {
+            properties: vec![QSOFieldName::CALL],
+            ..Default::default()
+        };
+        let actual = exporter.to_kml(&input);
+        assert!(actual.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<kml"));
+        assert!(actual.contains(
+            r#"<Placemark><name>IS0GVH</name><ExtendedData><Data name="CALL"><value>IS0GVH</value></Data></ExtendedData><Point><coordinates>9.000000,39.500000</coordinates></Point></Placemark>"#
+        ));
+    }
+
+    #[test]
+    fn test_kml_great_circle() {
+        let input = adif(vec![qso(vec![
+            (QSOFieldName::CALL, DataValue::String("A&B".to_string())),
+            (
+                QSOFieldName::GRIDSQUARE,
+                DataValue::GridSquare(GridSquare::deserialize("JN61").unwrap()),
+            ),
+            (
+                QSOFieldName::MY_GRIDSQUARE,
+                DataValue::GridSquare(GridSquare::deserialize("JM49").unwrap()),
+            ),
+        ])]);
+        let exporter = MapExporter {
+            great_circle: true,
+            ..Default::default()
+        };
+        let actual = exporter.to_kml(&input);
+        assert!(actual.contains("<name>A&amp;B</name>"));
+        assert!(actual
+            .contains("<LineString><tessellate>1</tessellate><coordinates>9.000000,39.500000 "));
+    }
+}
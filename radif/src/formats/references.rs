@@ -0,0 +1,599 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use crate::data::AdifData;
+use crate::enumerations::dxcc_enity_code::DxccEntityCode;
+use crate::enumerations::Enumeration;
+use crate::error::AdifError::{DeserializeError, GenericError};
+use crate::field::Field;
+use crate::fields::data::DataValue;
+use crate::fields::grid_square::GridSquare;
+use crate::fields::iota_ref_no::IotaRefNo;
+use crate::fields::location::Location;
+use crate::fields::pota_ref::PotaRef;
+use crate::fields::qso::QSOFieldName;
+use crate::fields::sota_ref::SotaRef;
+use crate::fields::wwff_ref::WwffRef;
+use crate::formats::csv::split_rows;
+use crate::qso::{QSOField, QSO};
+use crate::result;
+use crate::utilities::maidenhead_locator::Point;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Park, summit or island of an award program.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reference {
+    pub reference: String,
+    pub name: String,
+    /// Program-specific area, such as the ISO 3166-2 code of a park or the
+    /// region of a summit.
+    pub location: Option<String>,
+    pub position: Option<Point>,
+    pub gridsquare: Option<GridSquare>,
+    pub dxcc: Option<DxccEntityCode>,
+}
+
+/// Index of POTA parks, SOTA summits, WWFF areas and IOTA islands loaded
+/// from the lists published by each program.
+#[derive(Debug, Clone, Default)]
+pub struct ReferenceDb {
+    pota: HashMap<String, Reference>,
+    sota: HashMap<String, Reference>,
+    wwff: HashMap<String, Reference>,
+    iota: HashMap<String, Reference>,
+    iota_islands: HashMap<u64, Reference>,
+}
+
+impl ReferenceDb {
+    pub fn load_pota<P: AsRef<Path>>(&mut self, path: P) -> result::Result<()> {
+        self.add_pota(&read(path)?)
+    }
+
+    pub fn load_sota<P: AsRef<Path>>(&mut self, path: P) -> result::Result<()> {
+        self.add_sota(&read(path)?)
+    }
+
+    pub fn load_wwff<P: AsRef<Path>>(&mut self, path: P) -> result::Result<()> {
+        self.add_wwff(&read(path)?)
+    }
+
+    pub fn load_iota<P: AsRef<Path>>(&mut self, path: P) -> result::Result<()> {
+        self.add_iota(&read(path)?)
+    }
+
+    /// Adds the parks of the POTA `all_parks_ext.csv` list, with the
+    /// `reference`, `name`, `entityId`, `locationDesc`, `latitude`,
+    /// `longitude` and `grid` columns.
+    pub fn add_pota(&mut self, value: &str) -> result::Result<()> {
+        for row in Table::parse(value, "reference")?.rows() {
+            let reference = PotaRef::deserialize(row.require("reference")?)?;
+            let entry = row.reference(
+                reference.park(),
+                "name",
+                &["locationdesc"],
+                row.get("entityid")
+                    .and_then(|dxcc| DxccEntityCode::deserialize(dxcc).ok()),
+            )?;
+            self.pota.insert(entry.reference.clone(), entry);
+        }
+        Ok(())
+    }
+
+    /// Adds the summits of the SOTA `summitslist.csv` list, with the
+    /// `SummitCode`, `SummitName`, `RegionName`, `Latitude` and `Longitude`
+    /// columns.
+    pub fn add_sota(&mut self, value: &str) -> result::Result<()> {
+        for row in Table::parse(value, "summitcode")?.rows() {
+            let reference = SotaRef::deserialize(row.require("summitcode")?)?;
+            let entry =
+                row.reference(reference.serialize(), "summitname", &["regionname"], None)?;
+            self.sota.insert(entry.reference.clone(), entry);
+        }
+        Ok(())
+    }
+
+    /// Adds the areas of the WWFF `wwff_directory.csv` list, with the
+    /// `reference`, `name`, `dxcc` prefix, `state`, `iaruLocator`,
+    /// `latitude` and `longitude` columns.
+    pub fn add_wwff(&mut self, value: &str) -> result::Result<()> {
+        for row in Table::parse(value, "reference")?.rows() {
+            let reference = WwffRef::deserialize(row.require("reference")?)?;
+            let dxcc = row
+                .get("dxcc")
+                .and_then(DxccEntityCode::from_primary_prefix);
+            let entry = row.reference(reference.serialize(), "name", &["state"], dxcc)?;
+            self.wwff.insert(entry.reference.clone(), entry);
+        }
+        Ok(())
+    }
+
+    /// Adds IOTA islands from a list with the `refno`, `name` and optional
+    /// `island_id`, `dxcc`, `latitude` and `longitude` columns. Rows
+    /// without an island identifier name the group; the first row of a
+    /// group stands for it otherwise.
+    pub fn add_iota(&mut self, value: &str) -> result::Result<()> {
+        for row in Table::parse(value, "refno")?.rows() {
+            let reference = IotaRefNo::deserialize(row.require("refno")?)?;
+            let dxcc = row
+                .get("dxcc")
+                .and_then(|dxcc| DxccEntityCode::deserialize(dxcc).ok());
+            let entry = row.reference(reference.serialize(), "name", &[], dxcc)?;
+            match row.get("island_id") {
+                Some(id) => {
+                    let id = id.parse::<u64>().map_err(|_| {
+                        DeserializeError(format!("Invalid IOTA island identifier '{}'", id))
+                    })?;
+                    self.iota
+                        .entry(entry.reference.clone())
+                        .or_insert_with(|| entry.clone());
+                    self.iota_islands.insert(id, entry);
+                }
+                None => {
+                    self.iota.insert(entry.reference.clone(), entry);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The park, ignoring the location of the reference.
+    pub fn pota(&self, reference: &PotaRef) -> Option<&Reference> {
+        self.pota.get(&reference.park())
+    }
+
+    pub fn sota(&self, reference: &SotaRef) -> Option<&Reference> {
+        self.sota.get(&reference.serialize())
+    }
+
+    pub fn wwff(&self, reference: &WwffRef) -> Option<&Reference> {
+        self.wwff.get(&reference.serialize())
+    }
+
+    pub fn iota(&self, reference: &IotaRefNo) -> Option<&Reference> {
+        self.iota.get(&reference.serialize())
+    }
+
+    pub fn iota_island(&self, id: u64) -> Option<&Reference> {
+        self.iota_islands.get(&id)
+    }
+
+    pub fn has_pota(&self) -> bool {
+        !self.pota.is_empty()
+    }
+
+    pub fn has_sota(&self) -> bool {
+        !self.sota.is_empty()
+    }
+
+    pub fn has_wwff(&self) -> bool {
+        !self.wwff.is_empty()
+    }
+
+    pub fn has_iota(&self) -> bool {
+        !self.iota.is_empty()
+    }
+
+    /// References of every program whose name contains `name`, ignoring
+    /// case, sorted by reference.
+    pub fn find_by_name(&self, name: &str) -> Vec<&Reference> {
+        let name = name.to_lowercase();
+        let mut found = [&self.pota, &self.sota, &self.wwff, &self.iota]
+            .into_iter()
+            .flat_map(|references| references.values())
+            .filter(|reference| reference.name.to_lowercase().contains(&name))
+            .collect::<Vec<_>>();
+        found.sort_by(|a, b| a.reference.cmp(&b.reference));
+        found
+    }
+
+    /// Fills missing `GRIDSQUARE`, `LAT` and `LON` of the QSO from the most
+    /// precise known reference of the contacted station (summit, park, WWFF
+    /// area, IOTA island, IOTA group), and the `MY_` fields from those of
+    /// the logging station.
+    pub fn enrich(&self, qso: &QSO) -> result::Result<QSO> {
+        [
+            (
+                false,
+                QSOFieldName::GRIDSQUARE,
+                QSOFieldName::LAT,
+                QSOFieldName::LON,
+            ),
+            (
+                true,
+                QSOFieldName::MY_GRIDSQUARE,
+                QSOFieldName::MY_LAT,
+                QSOFieldName::MY_LON,
+            ),
+        ]
+        .into_iter()
+        .try_fold(qso.clone(), |qso, (my, gridsquare, lat, lon)| {
+            let reference = match self
+                .lookups(&qso, my)
+                .into_iter()
+                .find_map(|(_, reference)| reference.filter(|r| r.position.is_some()))
+            {
+                Some(reference) => reference,
+                None => return Ok(qso),
+            };
+            let qso = match (&reference.gridsquare, qso.get_value(&gridsquare)) {
+                (Some(value), None) if my => qso.with_my_full_gridsquare(value),
+                (Some(value), None) => qso.with_full_gridsquare(value),
+                _ => qso,
+            };
+            match (
+                reference.position.map(|point| Location::from_point(&point)),
+                qso.get_value(&lat),
+                qso.get_value(&lon),
+            ) {
+                (Some(Ok((latitude, longitude))), None, None) => qso
+                    .set_field(&QSOField::new(lat, DataValue::Location(latitude)))?
+                    .set_field(&QSOField::new(lon, DataValue::Location(longitude))),
+                _ => Ok(qso),
+            }
+        })
+    }
+
+    /// Fails when a POTA, SOTA, WWFF or IOTA reference of the QSO is missing
+    /// from the loaded lists of its program, or lies in another entity than
+    /// `DXCC` or `MY_DXCC`.
+    pub fn check(&self, qso: &QSO) -> result::Result<()> {
+        [(false, QSOFieldName::DXCC), (true, QSOFieldName::MY_DXCC)]
+            .into_iter()
+            .try_for_each(|(my, dxcc_field)| {
+                let dxcc = match qso.get_value(&dxcc_field) {
+                    Some(DataValue::Enumeration(Enumeration::DxccEntityCode(dxcc))) => Some(dxcc),
+                    _ => None,
+                };
+                self.lookups(qso, my)
+                    .into_iter()
+                    .try_for_each(|(field, reference)| match reference {
+                        None => Err(GenericError(format!(
+                            "{} {} is not a known reference",
+                            field,
+                            qso.get_value(&field)
+                                .map(|v| v.serialize())
+                                .unwrap_or_default()
+                        ))),
+                        Some(Reference {
+                            reference,
+                            dxcc: Some(entity),
+                            ..
+                        }) if dxcc.is_some_and(|dxcc| dxcc != entity) => {
+                            Err(GenericError(format!(
+                                "{} {} is not in {} {}",
+                                field,
+                                reference,
+                                dxcc_field,
+                                dxcc.map(|dxcc| dxcc.serialize()).unwrap_or_default()
+                            )))
+                        }
+                        _ => Ok(()),
+                    })
+            })
+    }
+
+    /// References of one station of the QSO from the most to the least
+    /// precise, with their entry; programs with no loaded list are skipped.
+    fn lookups(&self, qso: &QSO, my: bool) -> Vec<(QSOFieldName, Option<&Reference>)> {
+        let (sota, pota, wwff, island, iota) = if my {
+            (
+                QSOFieldName::MY_SOTA_REF,
+                QSOFieldName::MY_POTA_REF,
+                QSOFieldName::MY_WWFF_REF,
+                QSOFieldName::MY_IOTA_ISLAND_ID,
+                QSOFieldName::MY_IOTA,
+            )
+        } else {
+            (
+                QSOFieldName::SOTA_REF,
+                QSOFieldName::POTA_REF,
+                QSOFieldName::WWFF_REF,
+                QSOFieldName::IOTA_ISLAND_ID,
+                QSOFieldName::IOTA,
+            )
+        };
+        let mut lookups = vec![];
+        if let (true, Some(DataValue::SotaRef(reference))) = (self.has_sota(), qso.get_value(&sota))
+        {
+            lookups.push((sota, self.sota(reference)));
+        }
+        if let (true, Some(DataValue::PotaRefList(references))) =
+            (self.has_pota(), qso.get_value(&pota))
+        {
+            lookups.extend(
+                references
+                    .iter()
+                    .map(|reference| (pota.clone(), self.pota(reference))),
+            );
+        }
+        if let (true, Some(DataValue::WwffRef(reference))) = (self.has_wwff(), qso.get_value(&wwff))
+        {
+            lookups.push((wwff, self.wwff(reference)));
+        }
+        if self.has_iota() {
+            if let Some(DataValue::PositiveInteger(id)) = qso.get_value(&island) {
+                lookups.push((island, self.iota_island(*id)));
+            }
+            if let Some(DataValue::IotaRefNo(reference)) = qso.get_value(&iota) {
+                lookups.push((iota, self.iota(reference)));
+            }
+        }
+        lookups
+    }
+}
+
+struct Row<'a> {
+    columns: &'a HashMap<String, usize>,
+    cells: &'a [String],
+}
+
+impl Row<'_> {
+    fn get(&self, column: &str) -> Option<&str> {
+        self.columns
+            .get(column)
+            .and_then(|index| self.cells.get(*index))
+            .map(|cell| cell.trim())
+            .filter(|cell| !cell.is_empty())
+    }
+
+    fn require(&self, column: &str) -> result::Result<&str> {
+        self.get(column)
+            .ok_or_else(|| DeserializeError(format!("Missing '{}' in reference list", column)))
+    }
+
+    fn reference(
+        &self,
+        reference: String,
+        name: &str,
+        location: &[&str],
+        dxcc: Option<DxccEntityCode>,
+    ) -> result::Result<Reference> {
+        let position = match (self.get("latitude"), self.get("longitude")) {
+            (Some(latitude), Some(longitude)) => Some(Point::new(
+                parse_degrees(longitude)?,
+                parse_degrees(latitude)?,
+            )),
+            _ => None,
+        };
+        let gridsquare = self
+            .get("grid")
+            .or_else(|| self.get("iarulocator"))
+            .and_then(|grid| GridSquare::deserialize(grid).ok())
+            .or_else(|| position.and_then(|point| GridSquare::from_point(point, 6).ok()));
+        Ok(Reference {
+            reference,
+            name: self.require(name)?.to_string(),
+            location: location
+                .iter()
+                .find_map(|column| self.get(column))
+                .map(String::from),
+            position: position.or_else(|| gridsquare.as_ref().map(GridSquare::center)),
+            gridsquare,
+            dxcc,
+        })
+    }
+}
+
+struct Table {
+    columns: HashMap<String, usize>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// Parses a CSV list, skipping any title lines before the header
+    /// holding `key`.
+    fn parse(value: &str, key: &str) -> result::Result<Self> {
+        let mut rows = split_rows(value, ',')?.into_iter();
+        let header = rows
+            .by_ref()
+            .find(|row| row.iter().any(|cell| cell.trim().eq_ignore_ascii_case(key)))
+            .ok_or_else(|| {
+                DeserializeError(format!("Missing '{}' column in reference list", key))
+            })?;
+        Ok(Self {
+            columns: header
+                .iter()
+                .enumerate()
+                .map(|(index, column)| (column.trim().to_lowercase(), index))
+                .collect(),
+            rows: rows.collect(),
+        })
+    }
+
+    fn rows(&self) -> impl Iterator<Item = Row<'_>> {
+        self.rows.iter().map(|cells| Row {
+            columns: &self.columns,
+            cells,
+        })
+    }
+}
+
+fn parse_degrees(value: &str) -> result::Result<f64> {
+    value
+        .parse()
+        .map_err(|_| DeserializeError(format!("Invalid coordinate '{}'", value)))
+}
+
+fn read<P: AsRef<Path>>(path: P) -> result::Result<String> {
+    let path = path.as_ref();
+    std::fs::read_to_string(path)
+        .map_err(|e| DeserializeError(format!("{}: {}", path.display(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POTA_CSV: &str = "\
+\"reference\",\"name\",\"active\",\"entityId\",\"locationDesc\",\"latitude\",\"longitude\",\"grid\"
+\"K-0817\",\"Joshua Tree National Park\",\"1\",\"291\",\"US-CA\",\"33.8734\",\"-115.901\",\"DM14ev\"
+\"IT-0001\",\"Parco Nazionale del Gran Paradiso\",\"1\",\"248\",\"IT-23\",\"45.5167\",\"7.2667\",\"\"
+";
+
+    const SOTA_CSV: &str = "\
+SOTA Summits List (Date=01/01/2025)
+SummitCode,AssociationName,RegionName,SummitName,AltM,AltFt,GridRef1,GridRef2,Longitude,Latitude
+W6/CT-006,USA (California),Central Coast,Mount Pinos,2692,8831,-119.1453,34.8128,-119.1453,34.8128
+";
+
+    const WWFF_CSV: &str = "\
+reference,status,name,program,dxcc,state,county,continent,iota,iaruLocator,latitude,longitude
+IFF-0123,active,Parco Nazionale del Gargano,IFF,I,FG,,EU,,JN71lq,41.8,15.9
+ISFF-0001,active,Parco Nazionale dell'Asinara,ISFF,IS,SS,,EU,EU-024,JN40gx,41.06,8.27
+";
+
+    const IOTA_CSV: &str = "\
+refno,island_id,name,dxcc,latitude,longitude
+EU-024,,Sardinia Island group,225,,
+EU-024,120,Asinara,225,41.06,8.27
+";
+
+    fn db() -> ReferenceDb {
+        let mut db = ReferenceDb::default();
+        db.add_pota(POTA_CSV).unwrap();
+        db.add_sota(SOTA_CSV).unwrap();
+        db.add_wwff(WWFF_CSV).unwrap();
+        db.add_iota(IOTA_CSV).unwrap();
+        db
+    }
+
+    #[test]
+    fn test_pota() {
+        let db = db();
+        let park = db
+            .pota(&PotaRef::deserialize("K-0817@US-CA").unwrap())
+            .unwrap();
+        assert_eq!(park.name, "Joshua Tree National Park");
+        assert_eq!(park.location.as_deref(), Some("US-CA"));
+        assert_eq!(park.dxcc, Some(DxccEntityCode::UnitedStatesOfAmerica));
+        assert_eq!(park.gridsquare.as_ref().unwrap().as_str(), "DM14EV");
+        assert_eq!(park.position, Some(Point::new(-115.901, 33.8734)));
+
+        let park = db.pota(&PotaRef::deserialize("IT-0001").unwrap()).unwrap();
+        assert_eq!(park.gridsquare.as_ref().unwrap().as_str(), "JN35PM");
+        assert!(db.pota(&PotaRef::deserialize("K-0001").unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_sota_wwff_iota() {
+        let db = db();
+        let summit = db
+            .sota(&SotaRef::deserialize("W6/CT-006").unwrap())
+            .unwrap();
+        assert_eq!(summit.name, "Mount Pinos");
+        assert_eq!(summit.location.as_deref(), Some("Central Coast"));
+        assert_eq!(summit.dxcc, None);
+
+        let area = db
+            .wwff(&WwffRef::deserialize("ISFF-0001").unwrap())
+            .unwrap();
+        assert_eq!(area.dxcc, Some(DxccEntityCode::Sardinia));
+        assert_eq!(area.gridsquare.as_ref().unwrap().as_str(), "JN40GX");
+
+        let group = db.iota(&IotaRefNo::deserialize("EU-024").unwrap()).unwrap();
+        assert_eq!(group.name, "Sardinia Island group");
+        assert_eq!(group.position, None);
+        assert_eq!(db.iota_island(120).unwrap().name, "Asinara");
+        assert!(db.iota_island(121).is_none());
+    }
+
+    #[test]
+    fn test_find_by_name() {
+        let db = db();
+        let found = db
+            .find_by_name("asinara")
+            .iter()
+            .map(|reference| reference.reference.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(found, vec!["ISFF-0001"]);
+        assert_eq!(db.find_by_name("parco nazionale").len(), 3);
+    }
+
+    #[test]
+    fn test_enrich() {
+        let db = db();
+        let input = QSO::from_fields(&[
+            "<POTA_REF:6>K-0817",
+            "<SOTA_REF:9>W6/CT-006",
+            "<MY_POTA_REF:6>K-0817",
+            "<MY_GRIDSQUARE:4>DM13",
+        ]);
+        let actual = db.enrich(&input).unwrap();
+        assert_eq!(
+            actual.get_value(&QSOFieldName::GRIDSQUARE),
+            Some(&DataValue::GridSquare(
+                GridSquare::deserialize("DM04KT").unwrap()
+            ))
+        );
+        assert_eq!(
+            actual
+                .position()
+                .map(|point| (point.latitude, point.longitude)),
+            Some((34.8128, -119.1453))
+        );
+        assert_eq!(
+            actual.get_value(&QSOFieldName::MY_GRIDSQUARE),
+            Some(&DataValue::GridSquare(
+                GridSquare::deserialize("DM13").unwrap()
+            ))
+        );
+        assert!(actual.get_value(&QSOFieldName::MY_LAT).is_some());
+        assert!(actual.fields().last().unwrap().is_end());
+
+        assert_eq!(ReferenceDb::default().enrich(&input).unwrap(), input);
+    }
+
+    #[test]
+    fn test_check() {
+        let db = db();
+        let input = QSO::from_fields(&["<POTA_REF:12>K-0817@US-CA", "<DXCC:3>291"]);
+        assert!(db.check(&input).is_ok());
+        for field in [
+            "<DXCC:2>61",
+            "<POTA_REF:6>K-0001",
+            "<MY_SOTA_REF:9>W6/CT-007",
+        ] {
+            let qso = input
+                .set_field(&QSOField::deserialize(field).unwrap())
+                .unwrap();
+            assert!(db.check(&qso).is_err(), "{}", field);
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        let mut db = ReferenceDb::default();
+        assert!(db.add_pota("name\nfoo").is_err());
+        assert!(db.add_pota("reference,name\nK0817,Foo").is_err());
+        assert!(db.add_pota("reference,name\nK-0817,").is_err());
+        assert!(db
+            .add_wwff("reference,name,latitude,longitude\nIFF-0001,Foo,x,1")
+            .is_err());
+        assert!(!db.has_pota());
+    }
+
+    #[test]
+    fn test_load() {
+        let path = std::env::temp_dir().join(format!("radif-sota-{}.csv", std::process::id()));
+        std::fs::write(&path, SOTA_CSV).unwrap();
+        let mut db = ReferenceDb::default();
+        db.load_sota(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(db.has_sota());
+        assert!(db.load_sota(&path).is_err());
+    }
+}
@@ -24,6 +24,7 @@ use crate::fields::header::HeaderFieldName;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HeaderField {
     name: HeaderFieldName,
     value: DataValue,
@@ -164,6 +165,33 @@ impl AdifData for Header {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Header {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::field::serialize_fields(&self.header, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Header {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let header = Self {
+            header: crate::field::deserialize_fields(deserializer)?,
+        };
+        Ok(if header.header.is_empty() {
+            header
+        } else {
+            header.add_end_if_missing()
+        })
+    }
+}
+
 impl TryFrom<Vec<HeaderField>> for Header {
     type Error = AdifError;
 
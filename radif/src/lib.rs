@@ -18,19 +18,46 @@
 pub use radif_macros::*;
 mod adif;
 mod data;
-mod enumerations;
+mod dxcc_tracker;
+pub mod enumerations;
 mod error;
 mod field;
 mod fields;
 mod file;
+mod formats;
 mod header;
 mod qso;
 mod result;
 mod utilities;
+mod validate;
 
+pub use adif::{Adif, AdifItem};
 pub use data::AdifData;
+pub use dxcc_tracker::{DxccSlot, DxccStanding, DxccTracker, QslSource, CHALLENGE_BANDS};
 pub use error::AdifError;
+pub use field::{Field, FieldName};
+pub use fields::callsign::Callsign;
+pub use fields::data::{DataType, DataValue};
+pub use fields::grid_square::GridSquare;
+pub use fields::header::HeaderFieldName;
+pub use fields::location::{Location, LocationAxis, LocationDirection};
+pub use fields::pota_ref::PotaRef;
+pub use fields::qso::QSOFieldName;
+pub use fields::secondary_subdivision::{SecondarySubdivision, SecondarySubdivisionAlt};
+pub use fields::sota_ref::SotaRef;
+pub use fields::wwff_ref::WwffRef;
 pub use file::parse;
+pub use formats::activation::{Activation, ActivationProgram, ActivationReport};
+pub use formats::counties::CountyTable;
+pub use formats::csv::CsvCodec;
+pub use formats::cty::{CtyEntity, CtyResolver};
+pub use formats::edi::{EdiHeader, EdiLog, EdiMode, EdiRecord};
+pub use formats::map::MapExporter;
+pub use formats::references::{Reference, ReferenceDb};
+pub use header::{Header, HeaderField};
+pub use qso::{QSOField, QSO};
+pub use utilities::maidenhead_locator::{coordinates_to_locator, locator_to_coordinates, Point};
+pub use validate::{CountyRule, Rule, Severity, ValidationReport, Validator, Violation};
 
 #[cfg(feature = "tokio")]
 pub use file::parse_tokio;
@@ -17,13 +17,25 @@
 
 use crate::adif::AdifItem;
 use crate::data::AdifData;
+use crate::enumerations::ant_path::AntPath;
+use crate::enumerations::band::Band;
+use crate::enumerations::dxcc_enity_code::DxccEntityCode;
+use crate::enumerations::mode_category::{ModeCategory, ModeCategoryMapping};
+use crate::enumerations::primary_administrative_subdivision::PrimaryAdministrativeSubdivision;
+use crate::enumerations::Enumeration;
 use crate::error::AdifError;
-use crate::field::Field;
+use crate::field::{Field, FieldName};
+use crate::fields::callsign::Callsign;
 use crate::fields::data::DataValue;
+use crate::fields::grid_square::GridSquare;
+use crate::fields::location::Location;
 use crate::fields::qso::QSOFieldName;
+use crate::fields::secondary_subdivision::SecondarySubdivision;
+use crate::utilities::maidenhead_locator::Point;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct QSOField {
     name: QSOFieldName,
     value: DataValue,
@@ -141,22 +153,542 @@ impl TryFrom<Vec<QSOField>> for QSO {
     type Error = AdifError;
 
     fn try_from(value: Vec<QSOField>) -> Result<Self, Self::Error> {
+        value
+            .iter()
+            .try_for_each(|field| field.name.check_value(&field.value))?;
         Ok(Self { qso: value })
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for QSO {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::field::serialize_fields(&self.qso, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for QSO {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let qso = Self {
+            qso: crate::field::deserialize_fields(deserializer)?,
+        };
+        Ok(if qso.is_empty() {
+            qso
+        } else {
+            qso.add_end_if_missing()
+        })
+    }
+}
+
 impl QSO {
     pub fn len(&self) -> usize {
         self.qso.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.qso.is_empty()
+    }
+
+    pub fn fields(&self) -> impl Iterator<Item = &QSOField> {
+        self.qso.iter()
+    }
+
+    pub fn get_value(&self, name: &QSOFieldName) -> Option<&DataValue> {
+        self.qso
+            .iter()
+            .find(|field| &field.name == name)
+            .map(|field| &field.value)
+    }
+
+    /// Replaces the field with the same name, or adds it before the end of
+    /// record when missing; fails when the value breaks the constraints of
+    /// the field.
+    pub fn set_field(&self, field: &QSOField) -> crate::result::Result<Self> {
+        field.name.check_value(&field.value)?;
+        Ok(self.insert_field(field))
+    }
+
+    pub fn remove_field(&self, name: &QSOFieldName) -> Self {
+        Self {
+            qso: self
+                .qso
+                .iter()
+                .filter(|field| &field.name != name)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Fills missing `BAND` and `BAND_RX` from `FREQ` and `FREQ_RX`; bands
+    /// already present are kept, see `band_mismatches`.
+    pub fn with_bands_from_frequencies(&self) -> Self {
+        [
+            (QSOFieldName::BAND, QSOFieldName::FREQ),
+            (QSOFieldName::BAND_RX, QSOFieldName::FREQ_RX),
+        ]
+        .into_iter()
+        .fold(self.clone(), |qso, (band, freq)| {
+            match (qso.get_value(&band), qso.frequency_band(&freq)) {
+                (None, Some(value)) => qso.insert_field(&QSOField::new(
+                    band,
+                    DataValue::Enumeration(Enumeration::Band(value)),
+                )),
+                _ => qso,
+            }
+        })
+    }
+
+    /// Band fields whose value disagrees with the band of the matching
+    /// frequency field.
+    pub fn band_mismatches(&self) -> Vec<QSOFieldName> {
+        [
+            (QSOFieldName::BAND, QSOFieldName::FREQ),
+            (QSOFieldName::BAND_RX, QSOFieldName::FREQ_RX),
+        ]
+        .into_iter()
+        .filter(
+            |(band, freq)| match (self.get_value(band), self.get_value(freq)) {
+                (
+                    Some(DataValue::Enumeration(Enumeration::Band(band))),
+                    Some(DataValue::Number(mhz)),
+                ) => !band.contains(*mhz),
+                _ => false,
+            },
+        )
+        .map(|(band, _)| band)
+        .collect()
+    }
+
+    /// Fails when `SUBMODE` does not belong to `MODE`.
+    pub fn check_mode(&self) -> crate::result::Result<()> {
+        match (
+            self.get_value(&QSOFieldName::MODE),
+            self.get_value(&QSOFieldName::SUBMODE),
+        ) {
+            (
+                Some(DataValue::Enumeration(Enumeration::Mode(mode))),
+                Some(DataValue::Enumeration(Enumeration::SubMode(submode))),
+            ) if &submode.parent_mode() != mode => Err(AdifError::GenericError(format!(
+                "Submode {} does not belong to mode {}",
+                submode.serialize(),
+                mode.serialize()
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Fails when `STATE` or `MY_STATE` is not a subdivision code, or is
+    /// not a subdivision of `DXCC` or `MY_DXCC` respectively. Codes of
+    /// entities without a subdivision table are not checked.
+    pub fn check_state(&self) -> crate::result::Result<()> {
+        [
+            (QSOFieldName::STATE, QSOFieldName::DXCC),
+            (QSOFieldName::MY_STATE, QSOFieldName::MY_DXCC),
+        ]
+        .iter()
+        .try_for_each(|(state, dxcc)| {
+            let subdivision = match self.get_value(state) {
+                Some(DataValue::Enumeration(Enumeration::PrimaryAdministrativeSubdivision(
+                    subdivision,
+                ))) => subdivision,
+                _ => return Ok(()),
+            };
+            if !subdivision.is_code() {
+                return Err(AdifError::GenericError(format!(
+                    "{} '{}' is not a subdivision code",
+                    state, subdivision
+                )));
+            }
+            match self.get_value(dxcc) {
+                Some(DataValue::Enumeration(Enumeration::DxccEntityCode(dxcc)))
+                    if subdivision.is_valid_for(dxcc) == Some(false) =>
+                {
+                    Err(AdifError::GenericError(format!(
+                        "{} {} is not a subdivision of DXCC {}",
+                        state,
+                        subdivision,
+                        dxcc.serialize()
+                    )))
+                }
+                _ => Ok(()),
+            }
+        })
+    }
+
+    /// Fails when the state of `CNTY` differs from `STATE` or the county is
+    /// not a subdivision of `DXCC`, and likewise for `MY_CNTY`. Counties in
+    /// `USACA_COUNTIES` and `MY_USACA_COUNTIES` must belong to the USA,
+    /// Alaska or Hawaii. Counties without a built-in table are not checked.
+    pub fn check_county(&self) -> crate::result::Result<()> {
+        self.check_county_with(SecondarySubdivision::is_valid_for)
+    }
+
+    /// Like `check_county`, with `is_valid` telling whether a county belongs
+    /// to an entity, e.g. `CountyTable::is_valid` with the complete ADIF
+    /// tables.
+    pub fn check_county_with<F>(&self, is_valid: F) -> crate::result::Result<()>
+    where
+        F: Fn(&SecondarySubdivision, &DxccEntityCode) -> Option<bool>,
+    {
+        [
+            (QSOFieldName::CNTY, QSOFieldName::STATE, QSOFieldName::DXCC),
+            (
+                QSOFieldName::MY_CNTY,
+                QSOFieldName::MY_STATE,
+                QSOFieldName::MY_DXCC,
+            ),
+        ]
+        .iter()
+        .try_for_each(|(county_field, state_field, dxcc_field)| {
+            let county = match self.get_value(county_field) {
+                Some(DataValue::SecondarySubdivision(county)) => county,
+                _ => return Ok(()),
+            };
+            if let (
+                Some(county_state),
+                Some(DataValue::Enumeration(Enumeration::PrimaryAdministrativeSubdivision(state))),
+            ) = (county.state(), self.get_value(state_field))
+            {
+                if county_state != state.as_str() {
+                    return Err(AdifError::GenericError(format!(
+                        "{} {} is not in {} {}",
+                        county_field, county, state_field, state
+                    )));
+                }
+            }
+            match self.get_value(dxcc_field) {
+                Some(DataValue::Enumeration(Enumeration::DxccEntityCode(dxcc)))
+                    if is_valid(county, dxcc) == Some(false) =>
+                {
+                    Err(AdifError::GenericError(format!(
+                        "{} {} is not a subdivision of DXCC {}",
+                        county_field,
+                        county,
+                        dxcc.serialize()
+                    )))
+                }
+                _ => Ok(()),
+            }
+        })?;
+        [
+            QSOFieldName::USACA_COUNTIES,
+            QSOFieldName::MY_USACA_COUNTIES,
+        ]
+        .iter()
+        .try_for_each(|field| match self.get_value(field) {
+            Some(DataValue::SecondarySubdivisionList(counties)) => counties
+                .iter()
+                .find(|county| {
+                    [
+                        DxccEntityCode::UnitedStatesOfAmerica,
+                        DxccEntityCode::Alaska,
+                        DxccEntityCode::Hawaii,
+                    ]
+                    .iter()
+                    .all(|dxcc| is_valid(county, dxcc) == Some(false))
+                })
+                .map_or(Ok(()), |county| {
+                    Err(AdifError::GenericError(format!(
+                        "{} {} is not a county of the USA",
+                        field, county
+                    )))
+                }),
+            _ => Ok(()),
+        })
+    }
+
+    /// Moves the import-only `VE_PROV` into a missing `STATE`.
+    pub fn with_state_from_ve_prov(&self) -> Self {
+        let province = match self.get_value(&QSOFieldName::VE_PROV) {
+            Some(DataValue::String(province)) => province,
+            _ => return self.clone(),
+        };
+        match (
+            self.get_value(&QSOFieldName::STATE),
+            PrimaryAdministrativeSubdivision::deserialize(province),
+        ) {
+            (None, Ok(subdivision)) => self
+                .insert_field(&QSOField::new(
+                    QSOFieldName::STATE,
+                    DataValue::Enumeration(Enumeration::PrimaryAdministrativeSubdivision(
+                        subdivision,
+                    )),
+                ))
+                .remove_field(&QSOFieldName::VE_PROV),
+            _ => self.clone(),
+        }
+    }
+
+    /// Fills a missing `MODE` with the parent mode of `SUBMODE`.
+    pub fn with_mode_from_submode(&self) -> Self {
+        match (
+            self.get_value(&QSOFieldName::MODE),
+            self.get_value(&QSOFieldName::SUBMODE),
+        ) {
+            (None, Some(DataValue::Enumeration(Enumeration::SubMode(submode)))) => self
+                .insert_field(&QSOField::new(
+                    QSOFieldName::MODE,
+                    DataValue::Enumeration(Enumeration::Mode(submode.parent_mode())),
+                )),
+            _ => self.clone(),
+        }
+    }
+
+    /// Rewrites an import-only `MODE`, such as `PSK31`, into its current
+    /// `MODE` and `SUBMODE` pair, keeping a `SUBMODE` already present.
+    pub fn with_upgraded_mode(&self) -> Self {
+        match self.get_value(&QSOFieldName::MODE) {
+            Some(DataValue::Enumeration(Enumeration::Mode(mode))) => match mode.upgrade() {
+                Some((mode, submode)) => {
+                    let qso = self.insert_field(&QSOField::new(
+                        QSOFieldName::MODE,
+                        DataValue::Enumeration(Enumeration::Mode(mode)),
+                    ));
+                    match qso.get_value(&QSOFieldName::SUBMODE) {
+                        Some(_) => qso,
+                        None => qso.insert_field(&QSOField::new(
+                            QSOFieldName::SUBMODE,
+                            DataValue::Enumeration(Enumeration::SubMode(submode)),
+                        )),
+                    }
+                }
+                None => self.clone(),
+            },
+            _ => self.clone(),
+        }
+    }
+
+    /// Category of the QSO mode, taken from `MODE` or else from the parent
+    /// of `SUBMODE`.
+    pub fn mode_category(&self) -> Option<ModeCategory> {
+        self.mode_category_with(&ModeCategoryMapping::default())
+    }
+
+    pub fn mode_category_with(&self, mapping: &ModeCategoryMapping) -> Option<ModeCategory> {
+        let submode = match self.get_value(&QSOFieldName::SUBMODE) {
+            Some(DataValue::Enumeration(Enumeration::SubMode(submode))) => Some(submode),
+            _ => None,
+        };
+        let mode = match self.get_value(&QSOFieldName::MODE) {
+            Some(DataValue::Enumeration(Enumeration::Mode(mode))) => mode.clone(),
+            _ => submode?.parent_mode(),
+        };
+        Some(mapping.category(&mode, submode))
+    }
+
+    /// Locator of the contacted station at full precision, joining
+    /// `GRIDSQUARE` with `GRIDSQUARE_EXT` when present.
+    pub fn full_gridsquare(&self) -> Option<GridSquare> {
+        self.find_full_gridsquare(&QSOFieldName::GRIDSQUARE, &QSOFieldName::GRIDSQUARE_EXT)
+    }
+
+    /// Locator of the logging station at full precision, joining
+    /// `MY_GRIDSQUARE` with `MY_GRIDSQUARE_EXT` when present.
+    pub fn my_full_gridsquare(&self) -> Option<GridSquare> {
+        self.find_full_gridsquare(
+            &QSOFieldName::MY_GRIDSQUARE,
+            &QSOFieldName::MY_GRIDSQUARE_EXT,
+        )
+    }
+
+    /// Stores the locator of the contacted station, splitting characters
+    /// beyond the eighth into `GRIDSQUARE_EXT`.
+    pub fn with_full_gridsquare(&self, gridsquare: &GridSquare) -> Self {
+        self.store_full_gridsquare(
+            gridsquare,
+            QSOFieldName::GRIDSQUARE,
+            QSOFieldName::GRIDSQUARE_EXT,
+        )
+    }
+
+    /// Stores the locator of the logging station, splitting characters
+    /// beyond the eighth into `MY_GRIDSQUARE_EXT`.
+    pub fn with_my_full_gridsquare(&self, gridsquare: &GridSquare) -> Self {
+        self.store_full_gridsquare(
+            gridsquare,
+            QSOFieldName::MY_GRIDSQUARE,
+            QSOFieldName::MY_GRIDSQUARE_EXT,
+        )
+    }
+
+    /// Position of the contacted station, from `LAT`/`LON` or else from
+    /// the centre of the full-precision grid square.
+    pub fn position(&self) -> Option<Point> {
+        self.find_position(&QSOFieldName::LAT, &QSOFieldName::LON)
+            .or_else(|| self.full_gridsquare().map(|gridsquare| gridsquare.center()))
+    }
+
+    /// Position of the logging station, from `MY_LAT`/`MY_LON` or else from
+    /// the centre of the full-precision grid square.
+    pub fn my_position(&self) -> Option<Point> {
+        self.find_position(&QSOFieldName::MY_LAT, &QSOFieldName::MY_LON)
+            .or_else(|| {
+                self.my_full_gridsquare()
+                    .map(|gridsquare| gridsquare.center())
+            })
+    }
+
+    /// Distance in kilometres and antenna azimuth in degrees from the
+    /// logging station to the contacted one, along the long path when
+    /// `ANT_PATH` says so.
+    pub fn distance_azimuth(&self) -> Option<(f64, f64)> {
+        let (from, to) = (self.my_position()?, self.position()?);
+
+        Some(match self.get_value(&QSOFieldName::ANT_PATH) {
+            Some(DataValue::Enumeration(Enumeration::AntPath(AntPath::LongPath))) => {
+                (from.long_path_distance(&to), from.long_path_bearing(&to))
+            }
+            _ => (from.distance(&to), from.bearing(&to)),
+        })
+    }
+
+    /// Sets `DISTANCE` and `ANT_AZ` from `distance_azimuth`, rounded to one
+    /// decimal; the record is unchanged when a position is missing.
+    pub fn with_distance_azimuth(&self) -> crate::result::Result<Self> {
+        match self.distance_azimuth() {
+            Some((distance, azimuth)) => self
+                .set_field(&QSOField::new(
+                    QSOFieldName::DISTANCE,
+                    DataValue::Number((distance * 10.0).round() / 10.0),
+                ))?
+                .set_field(&QSOField::new(
+                    QSOFieldName::ANT_AZ,
+                    DataValue::Number((azimuth * 10.0).round() / 10.0),
+                )),
+            None => Ok(self.clone()),
+        }
+    }
+
+    /// Callsign of the contacted station, when `CALL` is a valid one.
+    pub fn callsign(&self) -> Option<Callsign> {
+        self.find_callsign(&QSOFieldName::CALL)
+    }
+
+    /// Callsign of the logging station, from `STATION_CALLSIGN` or else
+    /// from `OPERATOR`.
+    pub fn station_callsign(&self) -> Option<Callsign> {
+        self.find_callsign(&QSOFieldName::STATION_CALLSIGN)
+            .or_else(|| self.find_callsign(&QSOFieldName::OPERATOR))
+    }
+
+    /// Fills a missing `PFX` with the WPX prefix of `CALL`.
+    pub fn with_prefix_from_call(&self) -> Self {
+        match (self.get_value(&QSOFieldName::PFX), self.callsign()) {
+            (None, Some(callsign)) => self.insert_field(&QSOField::new(
+                QSOFieldName::PFX,
+                DataValue::String(callsign.wpx_prefix()),
+            )),
+            _ => self.clone(),
+        }
+    }
+
+    /// `set_field` for values meeting the constraints of their field by
+    /// construction.
+    fn insert_field(&self, field: &QSOField) -> Self {
+        let mut qso = self.qso.clone();
+        match qso.iter().position(|item| item.name == field.name) {
+            Some(index) => qso[index] = field.clone(),
+            None => {
+                let index = qso.iter().position(Field::is_end).unwrap_or(qso.len());
+                qso.insert(index, field.clone());
+            }
+        }
+        Self { qso }
+    }
+
+    fn find_callsign(&self, name: &QSOFieldName) -> Option<Callsign> {
+        match self.get_value(name) {
+            Some(DataValue::String(call)) => Callsign::deserialize(call).ok(),
+            _ => None,
+        }
+    }
+
+    fn frequency_band(&self, freq: &QSOFieldName) -> Option<Band> {
+        match self.get_value(freq) {
+            Some(DataValue::Number(mhz)) => Band::from_frequency(*mhz),
+            _ => None,
+        }
+    }
+
+    fn find_position(&self, lat: &QSOFieldName, lon: &QSOFieldName) -> Option<Point> {
+        match (self.get_value(lat), self.get_value(lon)) {
+            (Some(DataValue::Location(lat)), Some(DataValue::Location(lon))) => {
+                Location::to_point(lat, lon).ok()
+            }
+            _ => None,
+        }
+    }
+
+    fn find_full_gridsquare(
+        &self,
+        gridsquare: &QSOFieldName,
+        ext: &QSOFieldName,
+    ) -> Option<GridSquare> {
+        let gridsquare = match self.get_value(gridsquare) {
+            Some(DataValue::GridSquare(gridsquare)) => gridsquare,
+            _ => return None,
+        };
+        match self.get_value(ext) {
+            Some(DataValue::GridSquareExt(ext)) if gridsquare.precision() == 8 => {
+                Some(GridSquare::merge(gridsquare, ext).unwrap_or_else(|_| gridsquare.clone()))
+            }
+            _ => Some(gridsquare.clone()),
+        }
+    }
+
+    fn store_full_gridsquare(
+        &self,
+        gridsquare: &GridSquare,
+        name: QSOFieldName,
+        ext_name: QSOFieldName,
+    ) -> Self {
+        let (gridsquare, ext) = gridsquare.split();
+        let qso = self.insert_field(&QSOField::new(name, DataValue::GridSquare(gridsquare)));
+        match ext {
+            Some(ext) => qso.insert_field(&QSOField::new(ext_name, DataValue::GridSquareExt(ext))),
+            None => qso.remove_field(&ext_name),
+        }
+    }
+}
+
+#[cfg(test)]
+impl QSO {
+    /// Builds a record from serialized fields, adding the end of record.
+    pub(crate) fn from_fields(fields: &[&str]) -> Self {
+        Self::try_from(
+            fields
+                .iter()
+                .map(|field| QSOField::deserialize(field).unwrap())
+                .chain(std::iter::once(QSOField::end()))
+                .collect::<Vec<QSOField>>(),
+        )
+        .unwrap()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::adif::AdifItem;
     use crate::data::AdifData;
+    use crate::enumerations::ant_path::AntPath;
+    use crate::enumerations::band::Band;
+    use crate::enumerations::dxcc_enity_code::DxccEntityCode;
+    use crate::enumerations::mode::Mode;
+    use crate::enumerations::mode_category::{ModeCategory, ModeCategoryMapping};
+    use crate::enumerations::primary_administrative_subdivision::PrimaryAdministrativeSubdivision;
+    use crate::enumerations::submode::SubMode;
+    use crate::enumerations::Enumeration;
     use crate::field::Field;
     use crate::fields::data::DataValue;
+    use crate::fields::grid_square::GridSquare;
+    use crate::fields::location::Location;
     use crate::fields::qso::QSOFieldName;
     use crate::qso::{QSOField, QSO};
 
@@ -183,6 +715,543 @@ mod tests {
         assert_eq!(QSOField::deserialize("<INVALID:6>IS0GVH").is_err(), true);
     }
 
+    #[test]
+    fn test_qso_field_deserialize_location_axis() {
+        assert!(QSOField::deserialize("<LAT:11>N045 12.456").is_ok());
+        assert!(QSOField::deserialize("<LAT:11>E045 12.456").is_err());
+        assert!(QSOField::deserialize("<MY_LON:11>S009 30.000").is_err());
+    }
+
+    #[test]
+    fn test_qso_field_deserialize_value_range() {
+        assert!(QSOField::deserialize("<CQZ:2>14").is_ok());
+        assert!(QSOField::deserialize("<CQZ:1>0").is_err());
+        assert!(QSOField::deserialize("<CQZ:2>41").is_err());
+        assert!(QSOField::deserialize("<ITUZ:2>90").is_ok());
+        assert!(QSOField::deserialize("<ITUZ:2>91").is_err());
+        assert!(QSOField::deserialize("<K_INDEX:1>9").is_ok());
+        assert!(QSOField::deserialize("<K_INDEX:2>10").is_err());
+        assert!(QSOField::deserialize("<ANT_EL:3>-45").is_ok());
+        assert_eq!(
+            QSOField::deserialize("<ANT_EL:3>-95").unwrap().get_value(),
+            &DataValue::Number(-5.0)
+        );
+        assert_eq!(
+            QSOField::deserialize("<ANT_AZ:3>370").unwrap().get_value(),
+            &DataValue::Number(10.0)
+        );
+        assert_eq!(
+            QSOField::deserialize("<ANT_AZ:3>-90").unwrap().get_value(),
+            &DataValue::Number(270.0)
+        );
+        assert_eq!(
+            QSOField::deserialize("<ANT_AZ:3>360").unwrap().get_value(),
+            &DataValue::Number(360.0)
+        );
+        assert!(QSOField::try_new(QSOFieldName::ANT_AZ, DataValue::Number(370.0)).is_err());
+        assert!(QSOField::try_new(QSOFieldName::ANT_EL, DataValue::Number(-95.0)).is_err());
+        assert!(QSOField::deserialize("<SFI:3>301").is_err());
+        assert!(QSOField::deserialize("<AGE:3>121").is_err());
+        assert!(QSOField::deserialize("<TX_PWR:2>-5").is_err());
+        assert!(QSOField::deserialize("<TX_PWR:4>1500").is_ok());
+        assert_eq!(QSOFieldName::TX_PWR.value_range(), Some((0.0, None)));
+        assert_eq!(QSOFieldName::CQZ.value_range(), Some((1.0, Some(40.0))));
+        assert_eq!(QSOFieldName::CALL.value_range(), None);
+    }
+
+    #[test]
+    fn test_qso_field_deserialize_format() {
+        assert!(QSOField::deserialize("<SWL:1>Y").is_ok());
+        assert!(QSOField::deserialize("<SWL:1>X").is_err());
+        assert!(QSOField::deserialize("<EMAIL:16>is0gvh@example.org").is_ok());
+        assert!(QSOField::deserialize("<EMAIL:6>is0gvh").is_err());
+        assert!(QSOField::deserialize("<EMAIL:8>a b@c.de").is_err());
+        assert!(QSOField::deserialize("<WEB:18>https://radif.org/").is_ok());
+        assert!(QSOField::deserialize("<WEB:9>radif org").is_err());
+        assert!(QSOField::deserialize("<WEB:3>foo").is_err());
+        assert!(QSOField::deserialize("<WEB:8>https://").is_err());
+        assert!(QSOField::deserialize("<WEB:17>radif.org/contact").is_err());
+        assert!(QSOField::deserialize("<WEB:22>http://radif.org?x=1#y").is_ok());
+    }
+
+    #[test]
+    fn test_qso_field_try_new() {
+        assert!(QSOField::try_new(QSOFieldName::CQZ, DataValue::PositiveInteger(40)).is_ok());
+        assert!(QSOField::try_new(QSOFieldName::CQZ, DataValue::PositiveInteger(41)).is_err());
+        assert!(QSOField::try_new(QSOFieldName::K_INDEX, DataValue::Integer(-1)).is_err());
+    }
+
+    #[test]
+    fn test_qso_get_value() {
+        let input = QSO::try_from(vec![
+            QSOField::new(QSOFieldName::CALL, DataValue::String("IS0GVH".to_string())),
+            QSOField::new(QSOFieldName::RST_SENT, DataValue::String("599".to_string())),
+        ])
+        .unwrap();
+        assert_eq!(
+            input.get_value(&QSOFieldName::RST_SENT),
+            Some(&DataValue::String("599".to_string()))
+        );
+        assert_eq!(input.get_value(&QSOFieldName::RST_RCVD), None);
+    }
+
+    #[test]
+    fn test_qso_set_field() {
+        let input = QSO::try_from(vec![
+            QSOField::new(QSOFieldName::CALL, DataValue::String("IS0GVH".to_string())),
+            QSOField::new(QSOFieldName::RST_SENT, DataValue::String("599".to_string())),
+            QSOField::end(),
+        ])
+        .unwrap();
+        let expected = QSO::try_from(vec![
+            QSOField::new(QSOFieldName::CALL, DataValue::String("IS0GVH".to_string())),
+            QSOField::new(QSOFieldName::RST_SENT, DataValue::String("579".to_string())),
+            QSOField::new(QSOFieldName::RST_RCVD, DataValue::String("559".to_string())),
+            QSOField::end(),
+        ])
+        .unwrap();
+        let actual = input
+            .set_field(&QSOField::new(
+                QSOFieldName::RST_SENT,
+                DataValue::String("579".to_string()),
+            ))
+            .unwrap()
+            .set_field(&QSOField::new(
+                QSOFieldName::RST_RCVD,
+                DataValue::String("559".to_string()),
+            ))
+            .unwrap();
+        assert_eq!(expected, actual);
+
+        let cqz = QSOField::new(QSOFieldName::CQZ, DataValue::PositiveInteger(0));
+        assert!(input.set_field(&cqz).is_err());
+        assert!(QSOField::try_new(QSOFieldName::CQZ, DataValue::PositiveInteger(0)).is_err());
+        let cqz = QSOField::new(QSOFieldName::CQZ, DataValue::PositiveInteger(14));
+        assert_eq!(
+            input.set_field(&cqz).unwrap().get_value(&QSOFieldName::CQZ),
+            Some(&DataValue::PositiveInteger(14))
+        );
+    }
+
+    #[test]
+    fn test_qso_try_from_checks_values() {
+        assert!(QSO::try_from(vec![
+            QSOField::new(QSOFieldName::K_INDEX, DataValue::Integer(10)),
+            QSOField::end(),
+        ])
+        .is_err());
+        assert!(QSO::try_from(vec![
+            QSOField::new(QSOFieldName::K_INDEX, DataValue::Integer(9)),
+            QSOField::end(),
+        ])
+        .is_ok());
+    }
+
+    fn qso_with_positions(ant_path: Option<AntPath>) -> QSO {
+        let qso = QSO::try_from(vec![
+            QSOField::new(
+                QSOFieldName::MY_GRIDSQUARE,
+                DataValue::GridSquare(GridSquare::deserialize("JJ00").unwrap()),
+            ),
+            QSOField::new(
+                QSOFieldName::LAT,
+                DataValue::Location(Location::deserialize("N001 30.000").unwrap()),
+            ),
+            QSOField::new(
+                QSOFieldName::LON,
+                DataValue::Location(Location::deserialize("E001 00.000").unwrap()),
+            ),
+            QSOField::end(),
+        ])
+        .unwrap();
+        match ant_path {
+            Some(path) => qso
+                .set_field(&QSOField::new(
+                    QSOFieldName::ANT_PATH,
+                    DataValue::Enumeration(Enumeration::AntPath(path)),
+                ))
+                .unwrap(),
+            None => qso,
+        }
+    }
+
+    #[test]
+    fn test_qso_distance_azimuth() {
+        let (distance, azimuth) = qso_with_positions(None).distance_azimuth().unwrap();
+        assert!((distance - 111.2).abs() < 0.1);
+        assert!(azimuth.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_qso_distance_azimuth_long_path() {
+        let (distance, azimuth) = qso_with_positions(Some(AntPath::LongPath))
+            .distance_azimuth()
+            .unwrap();
+        assert!((distance - 39_920.8).abs() < 0.1);
+        assert!((azimuth - 180.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_qso_distance_azimuth_missing_position() {
+        let input = QSO::try_from(vec![
+            QSOField::new(QSOFieldName::CALL, DataValue::String("IS0GVH".to_string())),
+            QSOField::end(),
+        ])
+        .unwrap();
+        assert_eq!(input.distance_azimuth(), None);
+        assert_eq!(input.with_distance_azimuth().unwrap(), input);
+    }
+
+    #[test]
+    fn test_qso_with_distance_azimuth() {
+        let actual = qso_with_positions(None).with_distance_azimuth().unwrap();
+        assert_eq!(
+            actual.get_value(&QSOFieldName::DISTANCE),
+            Some(&DataValue::Number(111.2))
+        );
+        assert_eq!(
+            actual.get_value(&QSOFieldName::ANT_AZ),
+            Some(&DataValue::Number(0.0))
+        );
+        assert!(actual.fields().last().unwrap().is_end());
+    }
+
+    #[test]
+    fn test_qso_check_state() {
+        let input = QSO::try_from(vec![
+            QSOField::new(
+                QSOFieldName::STATE,
+                DataValue::Enumeration(Enumeration::PrimaryAdministrativeSubdivision(
+                    PrimaryAdministrativeSubdivision::deserialize("CA").unwrap(),
+                )),
+            ),
+            QSOField::new(
+                QSOFieldName::DXCC,
+                DataValue::Enumeration(Enumeration::DxccEntityCode(DxccEntityCode::Sardinia)),
+            ),
+            QSOField::end(),
+        ])
+        .unwrap();
+        assert!(input.check_state().is_ok());
+
+        let canada = input
+            .set_field(&QSOField::new(
+                QSOFieldName::DXCC,
+                DataValue::Enumeration(Enumeration::DxccEntityCode(DxccEntityCode::Canada)),
+            ))
+            .unwrap();
+        assert!(canada.check_state().is_err());
+        assert!(canada
+            .remove_field(&QSOFieldName::DXCC)
+            .check_state()
+            .is_ok());
+
+        let my_state = QSO::try_from(vec![
+            QSOField::deserialize("<MY_STATE:2>XX").unwrap(),
+            QSOField::deserialize("<MY_DXCC:3>291").unwrap(),
+            QSOField::end(),
+        ])
+        .unwrap();
+        assert!(my_state.check_state().is_err());
+
+        let name = QSO::from_fields(&["<STATE:8>New York"]);
+        assert!(name.check_state().is_err());
+        assert!(QSOField::deserialize("<STATE:4>N.Y.").is_ok());
+        let russia = QSO::from_fields(&["<STATE:2>SP", "<DXCC:2>54"]);
+        assert!(russia.check_state().is_ok());
+    }
+
+    #[test]
+    fn test_qso_check_county() {
+        let input = QSO::try_from(vec![
+            QSOField::deserialize("<CNTY:12>MA,Middlesex").unwrap(),
+            QSOField::deserialize("<STATE:2>MA").unwrap(),
+            QSOField::deserialize("<DXCC:3>291").unwrap(),
+            QSOField::end(),
+        ])
+        .unwrap();
+        assert!(input.check_county().is_ok());
+
+        let other_state = input
+            .set_field(&QSOField::deserialize("<STATE:2>CT").unwrap())
+            .unwrap();
+        assert!(other_state.check_county().is_err());
+
+        let unknown = input
+            .set_field(&QSOField::deserialize("<CNTY:10>MA,Nowhere").unwrap())
+            .unwrap();
+        assert!(unknown.check_county().is_err());
+        assert!(unknown
+            .remove_field(&QSOFieldName::DXCC)
+            .check_county()
+            .is_ok());
+
+        let my_county = QSO::try_from(vec![
+            QSOField::deserialize("<MY_CNTY:7>HI,Maui").unwrap(),
+            QSOField::deserialize("<MY_DXCC:3>291").unwrap(),
+            QSOField::end(),
+        ])
+        .unwrap();
+        assert!(my_county.check_county().is_err());
+
+        let no_state = QSO::from_fields(&["<CNTY:9>Middlesex", "<DXCC:3>291"]);
+        assert!(no_state.check_county().is_err());
+        let unchecked = QSO::from_fields(&["<CNTY:9>TX,Harris", "<DXCC:3>291"]);
+        assert!(unchecked.check_county().is_ok());
+        assert!(unchecked.check_county_with(|_, _| Some(false)).is_err());
+
+        let usaca = QSO::try_from(vec![
+            QSOField::deserialize("<USACA_COUNTIES:24>MA,Franklin:MA,Hampshire").unwrap(),
+            QSOField::end(),
+        ])
+        .unwrap();
+        assert!(usaca.check_county().is_ok());
+        let hawaii = usaca
+            .set_field(&QSOField::deserialize("<USACA_COUNTIES:21>HI,Honolulu:AK,Juneau").unwrap())
+            .unwrap();
+        assert!(hawaii.check_county().is_ok());
+        let hawaii = usaca
+            .set_field(&QSOField::deserialize("<USACA_COUNTIES:7>HI,Oahu").unwrap())
+            .unwrap();
+        assert!(hawaii.check_county().is_err());
+        let usaca = usaca
+            .set_field(&QSOField::deserialize("<USACA_COUNTIES:14>ON,Ottawa:MA,X").unwrap())
+            .unwrap();
+        assert!(usaca.check_county().is_err());
+    }
+
+    #[test]
+    fn test_qso_with_state_from_ve_prov() {
+        let input = QSO::try_from(vec![
+            QSOField::deserialize("<CALL:5>VE3AB").unwrap(),
+            QSOField::deserialize("<VE_PROV:2>on").unwrap(),
+            QSOField::end(),
+        ])
+        .unwrap();
+        let actual = input.with_state_from_ve_prov();
+        assert_eq!(
+            actual.get_value(&QSOFieldName::STATE),
+            Some(&DataValue::Enumeration(
+                Enumeration::PrimaryAdministrativeSubdivision(
+                    PrimaryAdministrativeSubdivision::deserialize("ON").unwrap()
+                )
+            ))
+        );
+        assert_eq!(actual.get_value(&QSOFieldName::VE_PROV), None);
+        assert!(actual.fields().last().unwrap().is_end());
+
+        let with_state = QSO::try_from(vec![
+            QSOField::deserialize("<STATE:2>QC").unwrap(),
+            QSOField::deserialize("<VE_PROV:2>ON").unwrap(),
+            QSOField::end(),
+        ])
+        .unwrap();
+        assert_eq!(with_state.with_state_from_ve_prov(), with_state);
+    }
+
+    #[test]
+    fn test_qso_callsign() {
+        let input = QSO::try_from(vec![
+            QSOField::new(
+                QSOFieldName::CALL,
+                DataValue::String("EA8/IS0GVH/P".to_string()),
+            ),
+            QSOField::new(
+                QSOFieldName::OPERATOR,
+                DataValue::String("IS0GVH".to_string()),
+            ),
+            QSOField::end(),
+        ])
+        .unwrap();
+        assert_eq!(input.callsign().unwrap().base(), "IS0GVH");
+        assert_eq!(input.station_callsign().unwrap().as_str(), "IS0GVH");
+
+        let actual = input.with_prefix_from_call();
+        assert_eq!(
+            actual.get_value(&QSOFieldName::PFX),
+            Some(&DataValue::String("EA8".to_string()))
+        );
+        assert_eq!(actual.with_prefix_from_call(), actual);
+
+        let invalid = input
+            .set_field(&QSOField::new(
+                QSOFieldName::CALL,
+                DataValue::String("IS0 GVH".to_string()),
+            ))
+            .unwrap();
+        assert_eq!(invalid.callsign(), None);
+        assert_eq!(invalid.with_prefix_from_call(), invalid);
+    }
+
+    #[test]
+    fn test_qso_full_gridsquare() {
+        let input = QSO::try_from(vec![
+            QSOField::new(
+                QSOFieldName::GRIDSQUARE,
+                DataValue::GridSquare(GridSquare::deserialize("JM49SK46").unwrap()),
+            ),
+            QSOField::new(
+                QSOFieldName::GRIDSQUARE_EXT,
+                DataValue::GridSquareExt("XV94".to_string()),
+            ),
+            QSOField::new(
+                QSOFieldName::MY_GRIDSQUARE,
+                DataValue::GridSquare(GridSquare::deserialize("JN61FW").unwrap()),
+            ),
+            QSOField::end(),
+        ])
+        .unwrap();
+        assert_eq!(
+            input.full_gridsquare(),
+            Some(GridSquare::deserialize("JM49SK46XV94").unwrap())
+        );
+        assert_eq!(
+            input.my_full_gridsquare(),
+            Some(GridSquare::deserialize("JN61FW").unwrap())
+        );
+        assert_eq!(
+            input.position(),
+            Some(GridSquare::deserialize("JM49SK46XV94").unwrap().center())
+        );
+
+        let bad_ext = QSOField::new(
+            QSOFieldName::GRIDSQUARE_EXT,
+            DataValue::GridSquareExt("9Z".to_string()),
+        );
+        assert!(input.set_field(&bad_ext).is_err());
+        assert!(QSOField::deserialize("<GRIDSQUARE_EXT:2>9Z").is_err());
+        assert!(QSOField::deserialize("<MY_GRIDSQUARE_EXT:2>xv").is_ok());
+        assert!(QSOField::deserialize("<GRIDSQUARE:10>JN40AA00AA").is_err());
+        assert!(QSOField::deserialize("<GRIDSQUARE:8>JN40AA00").is_ok());
+    }
+
+    #[test]
+    fn test_qso_with_full_gridsquare() {
+        let input = QSO::default().add_end_if_missing();
+        let actual = input
+            .with_full_gridsquare(&GridSquare::deserialize("JM49SK46XV").unwrap())
+            .with_my_full_gridsquare(&GridSquare::deserialize("JN61FW").unwrap());
+        assert_eq!(
+            actual.serialize(),
+            "<GRIDSQUARE:8>JM49SK46<GRIDSQUARE_EXT:2>XV<MY_GRIDSQUARE:6>JN61FW<EOR>"
+        );
+        let actual = actual.with_full_gridsquare(&GridSquare::deserialize("JM49").unwrap());
+        assert_eq!(
+            actual.serialize(),
+            "<GRIDSQUARE:4>JM49<MY_GRIDSQUARE:6>JN61FW<EOR>"
+        );
+    }
+
+    #[test]
+    fn test_qso_with_bands_from_frequencies() {
+        let input = QSO::try_from(vec![
+            QSOField::new(QSOFieldName::FREQ, DataValue::Number(14.074)),
+            QSOField::new(QSOFieldName::FREQ_RX, DataValue::Number(7.074)),
+            QSOField::new(
+                QSOFieldName::BAND_RX,
+                DataValue::Enumeration(Enumeration::Band(Band::Band20m)),
+            ),
+            QSOField::end(),
+        ])
+        .unwrap();
+        let actual = input.with_bands_from_frequencies();
+        assert_eq!(
+            actual.get_value(&QSOFieldName::BAND),
+            Some(&DataValue::Enumeration(Enumeration::Band(Band::Band20m)))
+        );
+        assert_eq!(
+            actual.get_value(&QSOFieldName::BAND_RX),
+            Some(&DataValue::Enumeration(Enumeration::Band(Band::Band20m)))
+        );
+        assert_eq!(input.band_mismatches(), vec![QSOFieldName::BAND_RX]);
+        assert_eq!(actual.band_mismatches(), vec![QSOFieldName::BAND_RX]);
+    }
+
+    #[test]
+    fn test_qso_with_bands_from_frequencies_out_of_band() {
+        let input = QSO::try_from(vec![
+            QSOField::new(QSOFieldName::FREQ, DataValue::Number(15.0)),
+            QSOField::end(),
+        ])
+        .unwrap();
+        assert_eq!(input.with_bands_from_frequencies(), input);
+        assert!(input.band_mismatches().is_empty());
+    }
+
+    fn qso_with_modes(mode: Option<Mode>, submode: Option<SubMode>) -> QSO {
+        let mut fields = vec![];
+        if let Some(mode) = mode {
+            fields.push(QSOField::new(
+                QSOFieldName::MODE,
+                DataValue::Enumeration(Enumeration::Mode(mode)),
+            ));
+        }
+        if let Some(submode) = submode {
+            fields.push(QSOField::new(
+                QSOFieldName::SUBMODE,
+                DataValue::Enumeration(Enumeration::SubMode(submode)),
+            ));
+        }
+        QSO::try_from(fields).unwrap().add_end_if_missing()
+    }
+
+    #[test]
+    fn test_qso_check_mode() {
+        assert!(qso_with_modes(Some(Mode::MFSK), Some(SubMode::FT4))
+            .check_mode()
+            .is_ok());
+        assert!(qso_with_modes(Some(Mode::CW), Some(SubMode::PSK31))
+            .check_mode()
+            .is_err());
+        assert!(qso_with_modes(Some(Mode::FT8), None).check_mode().is_ok());
+        assert!(qso_with_modes(None, Some(SubMode::USB))
+            .check_mode()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_qso_with_mode_from_submode() {
+        let actual = qso_with_modes(None, Some(SubMode::USB)).with_mode_from_submode();
+        assert_eq!(
+            actual.get_value(&QSOFieldName::MODE),
+            Some(&DataValue::Enumeration(Enumeration::Mode(Mode::SSB)))
+        );
+        let input = qso_with_modes(Some(Mode::CW), Some(SubMode::USB));
+        assert_eq!(input.with_mode_from_submode(), input);
+    }
+
+    #[test]
+    fn test_qso_with_upgraded_mode() {
+        let actual = qso_with_modes(Some(Mode::PSK31), None).with_upgraded_mode();
+        assert_eq!(
+            actual,
+            qso_with_modes(Some(Mode::PSK), Some(SubMode::PSK31))
+        );
+        let input = qso_with_modes(Some(Mode::FT8), None);
+        assert_eq!(input.with_upgraded_mode(), input);
+    }
+
+    #[test]
+    fn test_qso_mode_category() {
+        assert_eq!(
+            qso_with_modes(Some(Mode::MFSK), Some(SubMode::FT4)).mode_category(),
+            Some(ModeCategory::Digital)
+        );
+        assert_eq!(
+            qso_with_modes(None, Some(SubMode::USB)).mode_category(),
+            Some(ModeCategory::Phone)
+        );
+        assert_eq!(qso_with_modes(None, None).mode_category(), None);
+        let mapping = ModeCategoryMapping {
+            submodes: vec![(SubMode::FT4, ModeCategory::Cw)],
+            ..Default::default()
+        };
+        assert_eq!(
+            qso_with_modes(Some(Mode::MFSK), Some(SubMode::FT4)).mode_category_with(&mapping),
+            Some(ModeCategory::Cw)
+        );
+    }
+
     #[test]
     fn test_qso_add_end_if_missing_add() {
         let now = chrono::Utc::now();
@@ -240,4 +1309,54 @@ mod tests {
         let actual = input.serialize();
         assert_eq!(expected, actual);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_qso_serde_flat_map() {
+        use crate::enumerations::band::Band;
+        use crate::enumerations::Enumeration;
+
+        let input = QSO::try_from(vec![
+            QSOField::new(QSOFieldName::CALL, DataValue::String("IS0GVH".to_string())),
+            QSOField::new(
+                QSOFieldName::BAND,
+                DataValue::Enumeration(Enumeration::Band(Band::Band20m)),
+            ),
+            QSOField::new(QSOFieldName::QSO_RANDOM, DataValue::Boolean(true)),
+            QSOField::end(),
+        ])
+        .unwrap();
+        let json = serde_json::to_string(&input).unwrap();
+        assert_eq!(json, r#"{"CALL":"IS0GVH","BAND":"20M","QSO_RANDOM":"Y"}"#);
+        let actual: QSO = serde_json::from_str(&json).unwrap();
+        assert_eq!(input, actual);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_qso_serde_native_values() {
+        let actual: QSO =
+            serde_json::from_str(r#"{"call":"IS0GVH","FREQ":14.074,"SRX":12,"SWL":false}"#)
+                .unwrap();
+        assert_eq!(
+            actual.get_value(&QSOFieldName::FREQ),
+            Some(&DataValue::Number(14.074))
+        );
+        assert_eq!(
+            actual.get_value(&QSOFieldName::SRX),
+            Some(&DataValue::Integer(12))
+        );
+        assert_eq!(
+            actual.get_value(&QSOFieldName::SWL),
+            Some(&DataValue::Boolean(false))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_qso_serde_invalid() {
+        assert!(serde_json::from_str::<QSO>(r#"{"INVALID":"IS0GVH"}"#).is_err());
+        assert!(serde_json::from_str::<QSO>(r#"{"BAND":"21M"}"#).is_err());
+        assert!(serde_json::from_str::<QSO>(r#"["IS0GVH"]"#).is_err());
+    }
 }
@@ -18,7 +18,6 @@
 use crate::error::AdifError;
 use crate::error::AdifError::GenericError;
 use crate::result;
-use futures::{stream, StreamExt, TryFutureExt, TryStreamExt};
 use std::fmt::{Display, Formatter};
 
 const SHIFT_0: (f64, f64) = (20.0, 10.0);
@@ -35,6 +34,11 @@ const DELTA_8: (f64, f64) = (SHIFT_3.0 / 2.0, SHIFT_3.1 / 2.0);
 const DELTA_10: (f64, f64) = (SHIFT_4.0 / 2.0, SHIFT_4.1 / 2.0);
 const DELTA_12: (f64, f64) = (SHIFT_5.0 / 2.0, SHIFT_5.1 / 2.0);
 
+const PAIR_SIZES: [(u64, u64); 6] = [(18, 18), (10, 10), (24, 24), (10, 10), (24, 24), (10, 10)];
+const UNITS: (f64, f64) = (1.0 / SHIFT_5.0, 1.0 / SHIFT_5.1);
+
+pub const EARTH_RADIUS: f64 = 6371.291;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Point {
     pub longitude: f64,
@@ -65,9 +69,74 @@ impl Point {
             latitude,
         }
     }
+
+    /// Great-circle distance in kilometres, computed on a sphere with the
+    /// mean Earth radius used by the IARU Region 1 VHF contest rules.
+    pub fn distance(&self, other: &Point) -> f64 {
+        EARTH_RADIUS * self.angular_distance(other)
+    }
+
+    /// Great-circle distance in kilometres the long way round.
+    pub fn long_path_distance(&self, other: &Point) -> f64 {
+        EARTH_RADIUS * (2.0 * std::f64::consts::PI - self.angular_distance(other))
+    }
+
+    /// Initial bearing towards `other`, in degrees clockwise from north.
+    pub fn bearing(&self, other: &Point) -> f64 {
+        let (lat_a, lat_b) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let delta_long = (other.longitude - self.longitude).to_radians();
+
+        let y = delta_long.sin() * lat_b.cos();
+        let x = lat_a.cos() * lat_b.sin() - lat_a.sin() * lat_b.cos() * delta_long.cos();
+
+        y.atan2(x).to_degrees().rem_euclid(360.0)
+    }
+
+    /// Initial bearing of the long path towards `other`.
+    pub fn long_path_bearing(&self, other: &Point) -> f64 {
+        (self.bearing(other) + 180.0).rem_euclid(360.0)
+    }
+
+    /// Bearing from `other` back towards this point.
+    pub fn reverse_bearing(&self, other: &Point) -> f64 {
+        other.bearing(self)
+    }
+
+    /// Point at `fraction` (0 to 1) of the great-circle path towards `other`.
+    pub fn intermediate(&self, other: &Point, fraction: f64) -> Point {
+        let delta = self.angular_distance(other);
+        if delta == 0.0 {
+            return *self;
+        }
+
+        let (lat_a, long_a) = (self.latitude.to_radians(), self.longitude.to_radians());
+        let (lat_b, long_b) = (other.latitude.to_radians(), other.longitude.to_radians());
+        let a = ((1.0 - fraction) * delta).sin() / delta.sin();
+        let b = (fraction * delta).sin() / delta.sin();
+
+        let x = a * lat_a.cos() * long_a.cos() + b * lat_b.cos() * long_b.cos();
+        let y = a * lat_a.cos() * long_a.sin() + b * lat_b.cos() * long_b.sin();
+        let z = a * lat_a.sin() + b * lat_b.sin();
+
+        Point::new(
+            y.atan2(x).to_degrees(),
+            z.atan2((x * x + y * y).sqrt()).to_degrees(),
+        )
+    }
+
+    fn angular_distance(&self, other: &Point) -> f64 {
+        let (lat_a, lat_b) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let delta_lat = lat_b - lat_a;
+        let delta_long = (other.longitude - self.longitude).to_radians();
+
+        let a = (delta_lat / 2.0).sin().powi(2)
+            + lat_a.cos() * lat_b.cos() * (delta_long / 2.0).sin().powi(2);
+
+        2.0 * a.sqrt().min(1.0).asin()
+    }
 }
 
-pub async fn locator_to_coordinates(locator: &str) -> result::Result<Point> {
+pub fn locator_to_coordinates(locator: &str) -> result::Result<Point> {
     let delta_center = match locator.len() {
         2 => DELTA_2,
         4 => DELTA_4,
@@ -82,27 +151,108 @@ pub async fn locator_to_coordinates(locator: &str) -> result::Result<Point> {
         }
     };
 
-    let characters: Vec<(usize, u8, u8)> = locator
+    let (long, lat) = locator_to_digits(locator)?
+        .into_iter()
+        .enumerate()
+        .map(compute_shift)
+        .fold(delta_center, |(long_acc, lat_acc), (long, lat)| {
+            (long_acc + long, lat_acc + lat)
+        });
+
+    (long - 180.0, lat - 90.0).try_into()
+}
+
+/// Locator of `precision` characters (2 to 12, even) containing `point`.
+///
+/// Longitude 180 is the same meridian as -180 and wraps to the `A` field,
+/// while latitude 90 falls in the northernmost row.
+pub fn coordinates_to_locator(point: Point, precision: usize) -> result::Result<String> {
+    if !(2..=12).contains(&precision) || !precision.is_multiple_of(2) {
+        return Err(GenericError(
+            "Locator precision must be 2, 4, 6, 8, 10 or 12 characters".to_string(),
+        ));
+    }
+    if !(-180.0..=180.0).contains(&point.longitude) || !(-90.0..=90.0).contains(&point.latitude) {
+        return Err(GenericError(format!("Coordinates out of range: {}", point)));
+    }
+
+    let total = cell_count(PAIR_SIZES.len());
+    let long = (((point.longitude + 180.0) * UNITS.0) as u64) % total.0;
+    let lat = (((point.latitude + 90.0) * UNITS.1) as u64).min(total.1 - 1);
+    let count = cell_count(precision / 2);
+
+    Ok(indices_to_locator(
+        (long / (total.0 / count.0), lat / (total.1 / count.1)),
+        precision,
+    ))
+}
+
+/// Number of cells along longitude and latitude for a locator of `pairs`
+/// character pairs.
+pub(crate) fn cell_count(pairs: usize) -> (u64, u64) {
+    PAIR_SIZES
+        .iter()
+        .take(pairs)
+        .fold((1, 1), |(long, lat), (long_size, lat_size)| {
+            (long * long_size, lat * lat_size)
+        })
+}
+
+/// Column and row of the locator cell, counted from the south-west corner
+/// of the grid, at the precision of the locator itself.
+pub(crate) fn locator_to_indices(locator: &str) -> result::Result<(u64, u64)> {
+    if !(2..=12).contains(&locator.len()) || !locator.len().is_multiple_of(2) {
+        return Err(GenericError(
+            "Locator length must be 2, 4, 6, 8, 10 or 12 characters".to_string(),
+        ));
+    }
+
+    Ok(locator_to_digits(locator)?
+        .into_iter()
+        .zip(PAIR_SIZES)
+        .fold(
+            (0, 0),
+            |(long_acc, lat_acc), ((long, lat), (long_size, lat_size))| {
+                (
+                    long_acc * long_size + long as u64,
+                    lat_acc * lat_size + lat as u64,
+                )
+            },
+        ))
+}
+
+pub(crate) fn indices_to_locator((long, lat): (u64, u64), precision: usize) -> String {
+    let count = cell_count(precision / 2);
+
+    (0..precision / 2)
+        .map(|index| {
+            let digits = cell_count(index + 1);
+            let (long_size, lat_size) = PAIR_SIZES[index];
+            let long_digit = (long / (count.0 / digits.0)) % long_size;
+            let lat_digit = (lat / (count.1 / digits.1)) % lat_size;
+
+            let base = if index % 2 == 0 { b'A' } else { b'0' };
+            format!(
+                "{}{}",
+                (base + long_digit as u8) as char,
+                (base + lat_digit as u8) as char
+            )
+        })
+        .collect()
+}
+
+fn locator_to_digits(locator: &str) -> result::Result<Vec<(u8, u8)>> {
+    locator
         .to_ascii_uppercase()
         .as_bytes()
         .chunks(2)
         .enumerate()
         .map(|(index, item)| (index, item[0], item[1]))
-        .collect();
-
-    stream::iter(characters)
-        .then(validate_characters)
-        .and_then(convert_characters_to_numbers)
-        .and_then(compute_shift)
-        .try_fold(delta_center, sum_coordinates)
-        .and_then(half_divide)
-        .and_then(|x| async move { x.try_into() })
-        .await
+        .map(|item| validate_characters(item).map(convert_characters_to_numbers))
+        .collect()
 }
 
-async fn validate_characters(
-    (index, long, lat): (usize, u8, u8),
-) -> result::Result<(usize, u8, u8)> {
+fn validate_characters((index, long, lat): (usize, u8, u8)) -> result::Result<(usize, u8, u8)> {
     match index {
         0 => {
             if lat < b'A' || lat > b'R' || long < b'A' || long > b'R' {
@@ -123,18 +273,17 @@ async fn validate_characters(
     Ok((index, long, lat))
 }
 
-async fn convert_characters_to_numbers(
-    (index, long, lat): (usize, u8, u8),
-) -> result::Result<(usize, f64, f64)> {
-    Ok(if index % 2 == 0 {
-        (index, (long - b'A') as f64, (lat - b'A') as f64)
+fn convert_characters_to_numbers((index, long, lat): (usize, u8, u8)) -> (u8, u8) {
+    if index % 2 == 0 {
+        (long - b'A', lat - b'A')
     } else {
-        (index, (long - b'0') as f64, (lat - b'0') as f64)
-    })
+        (long - b'0', lat - b'0')
+    }
 }
 
-async fn compute_shift((index, long, lat): (usize, f64, f64)) -> result::Result<(f64, f64)> {
-    let (long_shift, lat_shift) = match index {
+fn compute_shift((index, (long, lat)): (usize, (u8, u8))) -> (f64, f64) {
+    let (long, lat) = (long as f64, lat as f64);
+    match index {
         0 => (long * SHIFT_0.0, lat * SHIFT_0.1),
         1 => (long * SHIFT_1.0, lat * SHIFT_1.1),
         2 => (long * SHIFT_2.0, lat * SHIFT_2.1),
@@ -142,77 +291,188 @@ async fn compute_shift((index, long, lat): (usize, f64, f64)) -> result::Result<
         4 => (long * SHIFT_4.0, lat * SHIFT_4.1),
         5 => (long * SHIFT_5.0, lat * SHIFT_5.1),
         _ => (0.0, 0.0),
-    };
-    Ok((long_shift, lat_shift))
-}
-
-async fn sum_coordinates(
-    (long_acc, lat_acc): (f64, f64),
-    (long, lat): (f64, f64),
-) -> result::Result<(f64, f64)> {
-    Ok((long_acc + long, lat_acc + lat))
-}
-
-async fn half_divide((long, lat): (f64, f64)) -> result::Result<(f64, f64)> {
-    Ok((long - 180.0, lat - 90.0))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use futures::executor::block_on;
+    use proptest::prelude::*;
 
     #[test]
     fn test_valid_two() {
-        let result = block_on(locator_to_coordinates("JM")).unwrap();
+        let result = locator_to_coordinates("JM").unwrap();
         assert_eq!(result, Point::new(10.0, 35.0));
     }
 
     #[test]
     fn test_valid_four() {
-        let result = block_on(locator_to_coordinates("JM49")).unwrap();
+        let result = locator_to_coordinates("JM49").unwrap();
         assert_eq!(result, Point::new(9.0, 39.5));
     }
 
     #[test]
     fn test_valid_six() {
-        let result = block_on(locator_to_coordinates("JM49SK")).unwrap();
+        let result = locator_to_coordinates("JM49SK").unwrap();
         assert_eq!(result, Point::new(9.541666666666657, 39.43749999999997));
     }
 
     #[test]
     fn test_valid_eigth() {
-        let result = block_on(locator_to_coordinates("JM49SK46")).unwrap();
+        let result = locator_to_coordinates("JM49SK46").unwrap();
         assert_eq!(result, Point::new(9.537499999999994, 39.443749999999994));
     }
 
     #[test]
     fn test_valid_ten() {
-        let result = block_on(locator_to_coordinates("JM49SK46XV")).unwrap();
+        let result = locator_to_coordinates("JM49SK46XV").unwrap();
         assert_eq!(result, Point::new(9.541493055555549, 39.445399305555554));
     }
 
     #[test]
     fn test_valid_twelve() {
-        let result = block_on(locator_to_coordinates("JM49SK46XV94")).unwrap();
+        let result = locator_to_coordinates("JM49SK46XV94").unwrap();
         assert_eq!(result, Point::new(9.541649305555552, 39.44539062499999));
     }
 
+    #[test]
+    fn test_distance_same_point() {
+        let point = locator_to_coordinates("JM49SK").unwrap();
+        assert_eq!(point.distance(&point), 0.0);
+    }
+
+    #[test]
+    fn test_distance_one_degree_latitude() {
+        let result = Point::new(0.0, 0.0).distance(&Point::new(0.0, 1.0));
+        assert!((result - 111.2).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_long_path_distance() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(0.0, 1.0);
+        let circumference = 2.0 * std::f64::consts::PI * EARTH_RADIUS;
+        assert!((a.distance(&b) + a.long_path_distance(&b) - circumference).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_bearing() {
+        let origin = Point::new(0.0, 0.0);
+        assert!((origin.bearing(&Point::new(0.0, 10.0)) - 0.0).abs() < 1e-9);
+        assert!((origin.bearing(&Point::new(10.0, 0.0)) - 90.0).abs() < 1e-9);
+        assert!((origin.bearing(&Point::new(0.0, -10.0)) - 180.0).abs() < 1e-9);
+        assert!((origin.bearing(&Point::new(-10.0, 0.0)) - 270.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_long_path_and_reverse_bearing() {
+        let a = Point::new(9.0, 39.5);
+        let b = Point::new(-74.0, 40.7);
+        assert!((a.long_path_bearing(&b) - (a.bearing(&b) + 180.0) % 360.0).abs() < 1e-9);
+        assert!((a.reverse_bearing(&b) - b.bearing(&a)).abs() < 1e-9);
+        assert!((a.bearing(&b) - 301.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_intermediate_endpoints() {
+        let a = Point::new(9.0, 39.5);
+        let b = Point::new(12.0, 42.0);
+        let start = a.intermediate(&b, 0.0);
+        let end = a.intermediate(&b, 1.0);
+        assert!((start.longitude - a.longitude).abs() < 1e-9);
+        assert!((start.latitude - a.latitude).abs() < 1e-9);
+        assert!((end.longitude - b.longitude).abs() < 1e-9);
+        assert!((end.latitude - b.latitude).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_intermediate_equator() {
+        let result = Point::new(0.0, 0.0).intermediate(&Point::new(90.0, 0.0), 0.5);
+        assert!((result.longitude - 45.0).abs() < 1e-9);
+        assert!(result.latitude.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_coordinates_to_locator() {
+        let point = Point::new(9.541649305555552, 39.44539062499999);
+        let result = coordinates_to_locator(point, 12).unwrap();
+        assert_eq!(result, "JM49SK46XV94");
+        let result = coordinates_to_locator(point, 6).unwrap();
+        assert_eq!(result, "JM49SK");
+    }
+
+    #[test]
+    fn test_coordinates_to_locator_edges() {
+        let result = coordinates_to_locator(Point::new(-180.0, -90.0), 6).unwrap();
+        assert_eq!(result, "AA00AA");
+        let result = coordinates_to_locator(Point::new(180.0, 90.0), 6).unwrap();
+        assert_eq!(result, "AR09AX");
+        let result = coordinates_to_locator(Point::new(179.9999, 0.0), 4).unwrap();
+        assert_eq!(result, "RJ90");
+    }
+
+    #[test]
+    fn test_coordinates_to_locator_invalid() {
+        let point = Point::new(9.0, 39.5);
+        assert!(coordinates_to_locator(point, 0).is_err());
+        assert!(coordinates_to_locator(point, 7).is_err());
+        assert!(coordinates_to_locator(point, 14).is_err());
+        assert!(coordinates_to_locator(Point::new(180.5, 0.0), 4).is_err());
+        assert!(coordinates_to_locator(Point::new(0.0, -90.5), 4).is_err());
+        assert!(coordinates_to_locator(Point::new(f64::NAN, 0.0), 4).is_err());
+    }
+
+    fn locator_strategy() -> impl Strategy<Value = String> {
+        (
+            "[A-R]{2}",
+            "[0-9]{2}",
+            "[A-X]{2}",
+            "[0-9]{2}",
+            "[A-X]{2}",
+            "[0-9]{2}",
+            1..=6usize,
+        )
+            .prop_map(|(a, b, c, d, e, f, pairs)| [a, b, c, d, e, f][..pairs].concat())
+    }
+
+    proptest! {
+        #[test]
+        fn test_coordinates_to_locator_inverts_center(locator in locator_strategy()) {
+            let point = locator_to_coordinates(&locator).unwrap();
+            let result = coordinates_to_locator(point, locator.len()).unwrap();
+            prop_assert_eq!(result, locator);
+        }
+
+        #[test]
+        fn test_coordinates_to_locator_contains_point(
+            longitude in -180.0..180.0f64,
+            latitude in -90.0..90.0f64,
+            precision in (1..=6usize).prop_map(|pairs| pairs * 2),
+        ) {
+            let point = Point::new(longitude, latitude);
+            let locator = coordinates_to_locator(point, precision).unwrap();
+            let center = locator_to_coordinates(&locator).unwrap();
+            let half = [DELTA_2, DELTA_4, DELTA_6, DELTA_8, DELTA_10, DELTA_12][precision / 2 - 1];
+            prop_assert!((center.longitude - longitude).abs() <= half.0 + 1e-9);
+            prop_assert!((center.latitude - latitude).abs() <= half.1 + 1e-9);
+        }
+    }
+
     #[test]
     fn test_invalid_empty() {
-        let result = block_on(locator_to_coordinates(""));
+        let result = locator_to_coordinates("");
         assert!(result.is_err());
     }
 
     #[test]
     fn test_invalid_locator_length() {
-        let result = block_on(locator_to_coordinates("JO1"));
+        let result = locator_to_coordinates("JO1");
         assert!(result.is_err());
     }
 
     #[test]
     fn test_invalid_locator_characters() {
-        let result = block_on(locator_to_coordinates("ZZ00"));
+        let result = locator_to_coordinates("ZZ00");
         assert!(result.is_err());
     }
 }
@@ -15,4 +15,4 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
-mod maidenhead_locator;
+pub mod maidenhead_locator;
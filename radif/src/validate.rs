@@ -0,0 +1,531 @@
+/*
+ * radif
+ * Copyright (C) 2025 - Luca Cireddu (IS0GVH) <sardylan@gmail.com>
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use crate::enumerations::Enumeration;
+use crate::fields::data::DataValue;
+use crate::fields::qso::QSOFieldName;
+use crate::formats::counties::CountyTable;
+use crate::qso::QSO;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// Check of a single QSO, returning the offending fields with a message.
+pub trait Rule {
+    fn name(&self) -> &'static str;
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check(&self, qso: &QSO) -> Vec<(QSOFieldName, String)>;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Violation {
+    pub record: usize,
+    pub field: QSOFieldName,
+    pub rule: String,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "#{} {} {}: {} ({})",
+            self.record, self.field, self.severity, self.message, self.rule
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidationReport {
+    violations: Vec<Violation>,
+}
+
+impl ValidationReport {
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+
+    pub fn len(&self) -> usize {
+        self.violations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Whether no violation has `Severity::Error`.
+    pub fn is_valid(&self) -> bool {
+        self.violations
+            .iter()
+            .all(|violation| violation.severity < Severity::Error)
+    }
+
+    pub fn with_severity(&self, severity: Severity) -> impl Iterator<Item = &Violation> {
+        self.violations
+            .iter()
+            .filter(move |violation| violation.severity == severity)
+    }
+
+    pub fn for_record(&self, record: usize) -> impl Iterator<Item = &Violation> {
+        self.violations
+            .iter()
+            .filter(move |violation| violation.record == record)
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.violations
+            .iter()
+            .try_for_each(|violation| writeln!(f, "{}", violation))
+    }
+}
+
+/// Set of rules run against every QSO; `Validator::default()` holds the
+/// rules derived from the ADIF specification.
+pub struct Validator {
+    rules: Vec<Box<dyn Rule>>,
+    severities: HashMap<String, Severity>,
+}
+
+impl Validator {
+    pub fn new() -> Self {
+        Self {
+            rules: vec![],
+            severities: HashMap::new(),
+        }
+    }
+
+    pub fn with_rule(mut self, rule: impl Rule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    pub fn without_rule(mut self, name: &str) -> Self {
+        self.rules.retain(|rule| rule.name() != name);
+        self
+    }
+
+    /// Overrides the severity reported for the rule with the given name.
+    pub fn with_severity(mut self, name: &str, severity: Severity) -> Self {
+        self.severities.insert(name.to_string(), severity);
+        self
+    }
+
+    pub fn rule_names(&self) -> Vec<&'static str> {
+        self.rules.iter().map(|rule| rule.name()).collect()
+    }
+
+    pub fn validate_qso(&self, record: usize, qso: &QSO) -> Vec<Violation> {
+        self.rules
+            .iter()
+            .flat_map(|rule| {
+                let severity = self
+                    .severities
+                    .get(rule.name())
+                    .copied()
+                    .unwrap_or_else(|| rule.default_severity());
+                rule.check(qso)
+                    .into_iter()
+                    .map(move |(field, message)| Violation {
+                        record,
+                        field,
+                        rule: rule.name().to_string(),
+                        severity,
+                        message,
+                    })
+            })
+            .collect()
+    }
+
+    pub fn validate(&self, qsos: &[QSO]) -> ValidationReport {
+        ValidationReport {
+            violations: qsos
+                .iter()
+                .enumerate()
+                .flat_map(|(record, qso)| self.validate_qso(record, qso))
+                .collect(),
+        }
+    }
+}
+
+impl Default for Validator {
+    fn default() -> Self {
+        Self::new()
+            .with_rule(FrequencyBandRule)
+            .with_rule(SubmodeRule)
+            .with_rule(DateOffRule)
+            .with_rule(ContinentRule)
+            .with_rule(StateRule)
+            .with_rule(CountyRule::default())
+            .with_rule(VuccGridsRule)
+    }
+}
+
+/// `BAND` and `BAND_RX` must contain `FREQ` and `FREQ_RX`.
+pub struct FrequencyBandRule;
+
+impl Rule for FrequencyBandRule {
+    fn name(&self) -> &'static str {
+        "frequency-band"
+    }
+
+    fn check(&self, qso: &QSO) -> Vec<(QSOFieldName, String)> {
+        qso.band_mismatches()
+            .into_iter()
+            .map(|band| {
+                let freq = match band {
+                    QSOFieldName::BAND_RX => QSOFieldName::FREQ_RX,
+                    _ => QSOFieldName::FREQ,
+                };
+                let message = format!("{} is outside {}", freq, band);
+                (band, message)
+            })
+            .collect()
+    }
+}
+
+/// `SUBMODE` must belong to `MODE`.
+pub struct SubmodeRule;
+
+impl Rule for SubmodeRule {
+    fn name(&self) -> &'static str {
+        "submode"
+    }
+
+    fn check(&self, qso: &QSO) -> Vec<(QSOFieldName, String)> {
+        qso.check_mode()
+            .err()
+            .map(|e| (QSOFieldName::SUBMODE, e.to_string()))
+            .into_iter()
+            .collect()
+    }
+}
+
+/// `QSO_DATE_OFF` and `TIME_OFF` must not precede `QSO_DATE` and `TIME_ON`.
+pub struct DateOffRule;
+
+impl Rule for DateOffRule {
+    fn name(&self) -> &'static str {
+        "date-off"
+    }
+
+    fn check(&self, qso: &QSO) -> Vec<(QSOFieldName, String)> {
+        let (date_on, date_off) = match (
+            qso.get_value(&QSOFieldName::QSO_DATE),
+            qso.get_value(&QSOFieldName::QSO_DATE_OFF),
+        ) {
+            (Some(DataValue::Date(on)), Some(DataValue::Date(off))) => (on, off),
+            _ => return vec![],
+        };
+        let before = match (
+            qso.get_value(&QSOFieldName::TIME_ON),
+            qso.get_value(&QSOFieldName::TIME_OFF),
+        ) {
+            (Some(DataValue::Time(on)), Some(DataValue::Time(off))) => {
+                date_off.and_time(*off) < date_on.and_time(*on)
+            }
+            _ => date_off < date_on,
+        };
+        if before {
+            vec![(
+                QSOFieldName::QSO_DATE_OFF,
+                "QSO ends before it starts".to_string(),
+            )]
+        } else {
+            vec![]
+        }
+    }
+}
+
+/// `CONT` must be a continent of `DXCC`.
+pub struct ContinentRule;
+
+impl Rule for ContinentRule {
+    fn name(&self) -> &'static str {
+        "continent"
+    }
+
+    fn check(&self, qso: &QSO) -> Vec<(QSOFieldName, String)> {
+        match (
+            qso.get_value(&QSOFieldName::CONT),
+            qso.get_value(&QSOFieldName::DXCC),
+        ) {
+            (
+                Some(DataValue::Enumeration(Enumeration::Continent(continent))),
+                Some(DataValue::Enumeration(Enumeration::DxccEntityCode(dxcc))),
+            ) if !dxcc.info().continents.is_empty()
+                && !dxcc.info().continents.contains(continent) =>
+            {
+                vec![(
+                    QSOFieldName::CONT,
+                    format!("{} is not a continent of {}", continent, dxcc.info().name),
+                )]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// `STATE` and `MY_STATE` must be subdivisions of `DXCC` and `MY_DXCC`.
+pub struct StateRule;
+
+impl Rule for StateRule {
+    fn name(&self) -> &'static str {
+        "state"
+    }
+
+    fn check(&self, qso: &QSO) -> Vec<(QSOFieldName, String)> {
+        check_each(
+            qso,
+            &[QSOFieldName::STATE, QSOFieldName::MY_STATE],
+            QSO::check_state,
+        )
+    }
+}
+
+/// County fields must agree with the state and entity of the QSO; counties
+/// are looked up in the loaded ADIF tables, if any, then in the built-in
+/// ones.
+#[derive(Default)]
+pub struct CountyRule {
+    table: CountyTable,
+}
+
+impl CountyRule {
+    pub fn new(table: CountyTable) -> Self {
+        Self { table }
+    }
+}
+
+impl Rule for CountyRule {
+    fn name(&self) -> &'static str {
+        "county"
+    }
+
+    fn check(&self, qso: &QSO) -> Vec<(QSOFieldName, String)> {
+        check_each(
+            qso,
+            &[
+                QSOFieldName::CNTY,
+                QSOFieldName::MY_CNTY,
+                QSOFieldName::USACA_COUNTIES,
+                QSOFieldName::MY_USACA_COUNTIES,
+            ],
+            |qso| qso.check_county_with(|county, dxcc| self.table.is_valid(county, dxcc)),
+        )
+    }
+}
+
+/// `VUCC_GRIDS` and `MY_VUCC_GRIDS` must list two or four squares.
+pub struct VuccGridsRule;
+
+impl Rule for VuccGridsRule {
+    fn name(&self) -> &'static str {
+        "vucc-grids"
+    }
+
+    fn check(&self, qso: &QSO) -> Vec<(QSOFieldName, String)> {
+        [QSOFieldName::VUCC_GRIDS, QSOFieldName::MY_VUCC_GRIDS]
+            .into_iter()
+            .filter_map(|field| match qso.get_value(&field) {
+                Some(DataValue::GridSquareList(grids)) if grids.len() != 2 && grids.len() != 4 => {
+                    let message = format!("{} squares, expected 2 or 4", grids.len());
+                    Some((field, message))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Runs `check` once per present field, with the other fields removed, so
+/// each failure is attributed to its field.
+fn check_each(
+    qso: &QSO,
+    fields: &[QSOFieldName],
+    check: impl Fn(&QSO) -> crate::result::Result<()>,
+) -> Vec<(QSOFieldName, String)> {
+    fields
+        .iter()
+        .filter(|field| qso.get_value(field).is_some())
+        .filter_map(|field| {
+            let only = fields
+                .iter()
+                .filter(|other| *other != field)
+                .fold(qso.clone(), |qso, other| qso.remove_field(other));
+            check(&only).err().map(|e| (field.clone(), e.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::AdifData;
+    use crate::qso::QSOField;
+
+    fn violations(qso: &QSO) -> Vec<(String, QSOFieldName)> {
+        Validator::default()
+            .validate_qso(0, qso)
+            .into_iter()
+            .map(|violation| (violation.rule, violation.field))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_qso() {
+        let input = QSO::from_fields(&[
+            "<CALL:6>IS0GVH",
+            "<QSO_DATE:8>20250101",
+            "<TIME_ON:6>235900",
+            "<QSO_DATE_OFF:8>20250102",
+            "<TIME_OFF:6>000100",
+            "<BAND:3>20m",
+            "<FREQ:6>14.074",
+            "<MODE:4>MFSK",
+            "<SUBMODE:3>FT4",
+            "<CONT:2>EU",
+            "<DXCC:3>225",
+        ]);
+        assert!(violations(&input).is_empty());
+    }
+
+    #[test]
+    fn test_default_rules() {
+        let input = QSO::from_fields(&[
+            "<QSO_DATE:8>20250102",
+            "<QSO_DATE_OFF:8>20250101",
+            "<BAND:3>40m",
+            "<FREQ:6>14.074",
+            "<BAND_RX:3>20m",
+            "<FREQ_RX:5>7.074",
+            "<MODE:3>SSB",
+            "<SUBMODE:3>FT4",
+            "<CONT:2>NA",
+            "<DXCC:3>225",
+            "<STATE:2>MA",
+            "<VUCC_GRIDS:24>JN40,JN41,JN50,JN51,JN60",
+        ]);
+        assert_eq!(
+            violations(&input),
+            vec![
+                ("frequency-band".to_string(), QSOFieldName::BAND),
+                ("frequency-band".to_string(), QSOFieldName::BAND_RX),
+                ("submode".to_string(), QSOFieldName::SUBMODE),
+                ("date-off".to_string(), QSOFieldName::QSO_DATE_OFF),
+                ("continent".to_string(), QSOFieldName::CONT),
+                ("state".to_string(), QSOFieldName::STATE),
+                ("vucc-grids".to_string(), QSOFieldName::VUCC_GRIDS),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_date_off_same_day() {
+        let input = QSO::from_fields(&[
+            "<QSO_DATE:8>20250101",
+            "<TIME_ON:6>120000",
+            "<QSO_DATE_OFF:8>20250101",
+            "<TIME_OFF:6>115900",
+        ]);
+        assert_eq!(DateOffRule.check(&input).len(), 1);
+        let input = input.remove_field(&QSOFieldName::TIME_OFF);
+        assert!(DateOffRule.check(&input).is_empty());
+    }
+
+    #[test]
+    fn test_state_rule_reports_names() {
+        let input = QSO::from_fields(&["<STATE:8>New York", "<MY_STATE:2>ny", "<MY_DXCC:3>291"]);
+        let actual = StateRule.check(&input);
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].0, QSOFieldName::STATE);
+    }
+
+    #[test]
+    fn test_county_rule_attributes_field() {
+        let input = QSO::from_fields(&[
+            "<CNTY:12>MA,Middlesex",
+            "<MY_CNTY:7>HI,Maui",
+            "<DXCC:3>291",
+            "<MY_DXCC:3>110",
+        ]);
+        assert!(CountyRule::default().check(&input).is_empty());
+        let input = input
+            .set_field(&QSOField::deserialize("<MY_DXCC:3>291").unwrap())
+            .unwrap();
+        let actual = CountyRule::default().check(&input);
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].0, QSOFieldName::MY_CNTY);
+
+        let input = QSO::from_fields(&["<CNTY:9>TX,Nowhere", "<DXCC:3>291"]);
+        assert!(CountyRule::default().check(&input).is_empty());
+        let table = CountyTable::from_csv("Code,DXCC Entity Code\n\"TX,Harris\",291\n").unwrap();
+        assert_eq!(CountyRule::new(table).check(&input).len(), 1);
+    }
+
+    #[test]
+    fn test_report() {
+        let qsos = vec![
+            QSO::from_fields(&["<CALL:6>IS0GVH"]),
+            QSO::from_fields(&["<BAND:3>40m", "<FREQ:6>14.074", "<CONT:2>NA", "<DXCC:3>225"]),
+        ];
+        let validator = Validator::default()
+            .with_severity("continent", Severity::Warning)
+            .without_rule("vucc-grids");
+        assert!(!validator.rule_names().contains(&"vucc-grids"));
+
+        let report = validator.validate(&qsos);
+        assert_eq!(report.len(), 2);
+        assert_eq!(report.for_record(0).count(), 0);
+        assert_eq!(report.for_record(1).count(), 2);
+        assert_eq!(report.with_severity(Severity::Warning).count(), 1);
+        assert!(!report.is_valid());
+        assert_eq!(
+            report.to_string().lines().next().unwrap(),
+            "#1 BAND error: FREQ is outside BAND (frequency-band)"
+        );
+
+        let report = validator
+            .with_severity("frequency-band", Severity::Info)
+            .validate(&qsos);
+        assert!(report.is_valid());
+    }
+}
@@ -17,7 +17,8 @@
 
 use proc_macro::TokenStream;
 use quote::{ToTokens, format_ident, quote};
-use syn::{Data, DataEnum, DeriveInput, Lit, Variant, parse_macro_input};
+use syn::parse::ParseStream;
+use syn::{Data, DataEnum, DeriveInput, Ident, Lit, Token, Variant, parse_macro_input};
 
 #[proc_macro_derive(AdifData, attributes(adif))]
 pub fn adif_enum_derive(input: TokenStream) -> TokenStream {
@@ -50,6 +51,24 @@ pub fn adif_enum_derive(input: TokenStream) -> TokenStream {
         }
     });
 
+    let import_only_arms = variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let import_only = has_adif_flag(variant, "import_only");
+
+        quote! {
+            Self::#variant_name => #import_only,
+        }
+    });
+
+    let deleted_arms = variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let deleted = has_adif_flag(variant, "deleted");
+
+        quote! {
+            Self::#variant_name => #deleted,
+        }
+    });
+
     let expanded = quote! {
         impl AdifData for #name {
             fn serialize(&self) -> String {
@@ -65,6 +84,23 @@ pub fn adif_enum_derive(input: TokenStream) -> TokenStream {
                 }
             }
         }
+
+        impl #name {
+            /// Whether the ADIF specification only allows this value when
+            /// importing, as it has been replaced by a newer one.
+            pub fn is_import_only(&self) -> bool {
+                match self {
+                    #(#import_only_arms)*
+                }
+            }
+
+            /// Whether the ADIF specification marks this value as deleted.
+            pub fn is_deleted(&self) -> bool {
+                match self {
+                    #(#deleted_arms)*
+                }
+            }
+        }
     };
 
     TokenStream::from(expanded)
@@ -141,6 +177,35 @@ pub fn auto_from_str_derive(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+#[proc_macro_derive(AdifSerde)]
+pub fn adif_serde_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident;
+
+    let expanded = quote! {
+        impl serde::Serialize for #name {
+            fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(&crate::data::AdifData::serialize(self))
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for #name {
+            fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+                <Self as crate::data::AdifData>::deserialize(&value).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
 #[proc_macro_derive(AutoTestEnum)]
 pub fn auto_test_enum(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -189,7 +254,14 @@ pub fn auto_test_enum(input: TokenStream) -> TokenStream {
 fn get_adif_value(variant: &Variant) -> Option<String> {
     for attr in &variant.attrs {
         if attr.path().is_ident("adif") {
-            match attr.parse_args::<Lit>() {
+            match attr.parse_args_with(|input: ParseStream| {
+                let lit = input.parse::<Lit>()?;
+                while !input.is_empty() {
+                    input.parse::<Token![,]>()?;
+                    input.parse::<Ident>()?;
+                }
+                Ok(lit)
+            }) {
                 Ok(Lit::Str(lit_str)) => {
                     return Some(lit_str.value());
                 }
@@ -207,3 +279,28 @@ fn get_adif_value(variant: &Variant) -> Option<String> {
     }
     Some(variant.ident.to_string())
 }
+
+/// Checks for a flag following the value, as in `#[adif("C4FM", import_only)]`.
+fn has_adif_flag(variant: &Variant, flag: &str) -> bool {
+    variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("adif"))
+        .any(|attr| {
+            attr.parse_args_with(|input: ParseStream| {
+                input.parse::<Lit>()?;
+                let mut flags = vec![];
+                while !input.is_empty() {
+                    input.parse::<Token![,]>()?;
+                    let ident = input.parse::<Ident>()?;
+                    if ident != "import_only" && ident != "deleted" {
+                        return Err(syn::Error::new(ident.span(), "Unknown `adif` flag"));
+                    }
+                    flags.push(ident.to_string());
+                }
+                Ok(flags)
+            })
+            .map(|flags| flags.iter().any(|item| item == flag))
+            .unwrap_or_else(|e| panic!("Failed to parse `adif` attribute: {}", e))
+        })
+}